@@ -0,0 +1,103 @@
+//! Counts the heap-allocations of registering one closure for each of many
+//! event-variants, the common case of one handler per input-event.
+//! Compares the [`Dispatcher`] against a map laid out like its storage
+//! used to be, with a `Vec` for the listeners and the closures each.
+//!
+//! Run with `cargo bench --bench listener_storage`.
+//!
+//! [`Dispatcher`]: ../hey_listen/sync/struct.Dispatcher.html
+use hey_listen::sync::{Dispatcher, SyncDispatcherRequest};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+
+const EVENTS: u32 = 10_000;
+
+type SharedFn = Arc<dyn Fn(&u32) -> Option<SyncDispatcherRequest> + Send + Sync>;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Runs `work` and returns the allocations it made and how long it took.
+fn measure<F: FnOnce()>(work: F) -> (usize, u128) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    work();
+
+    (
+        ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        start.elapsed().as_micros(),
+    )
+}
+
+fn report(name: &str, (allocations, micros): (usize, u128)) {
+    println!(
+        "{:<28} {:>8} allocations ({:.2} per event) {:>8} µs",
+        name,
+        allocations,
+        allocations as f64 / f64::from(EVENTS),
+        micros
+    );
+}
+
+fn main() {
+    report(
+        "sync::Dispatcher",
+        measure(|| {
+            let mut dispatcher: Dispatcher<u32> = Dispatcher::default();
+
+            for event in 0..EVENTS {
+                dispatcher.add_fn(event, Box::new(|_| None));
+            }
+
+            for event in 0..EVENTS {
+                dispatcher.dispatch_event(&event);
+            }
+        }),
+    );
+
+    report(
+        "Vec-backed baseline",
+        measure(|| {
+            let mut events: HashMap<u32, (Vec<SharedFn>, Vec<SharedFn>)> = HashMap::new();
+
+            for event in 0..EVENTS {
+                let function: SharedFn = Arc::new(|_| None);
+                events
+                    .entry(event)
+                    .or_insert_with(|| (Vec::new(), Vec::new()))
+                    .1
+                    .push(function);
+            }
+
+            for event in 0..EVENTS {
+                if let Some((_, fns)) = events.get(&event) {
+                    for function in fns {
+                        function(&event);
+                    }
+                }
+            }
+        }),
+    );
+}
@@ -0,0 +1,108 @@
+//! Times dispatching to weakly referenced listeners spread over many
+//! priority-levels, with one listener registered on every level.
+//! Compares the [`PriorityDispatcher`], upgrading every registration once
+//! per dispatch, against a baseline caching the upgraded listeners by
+//! address for the duration of one dispatch.
+//!
+//! Run with `cargo bench --bench priority_weak_upgrades`.
+//!
+//! [`PriorityDispatcher`]: ../hey_listen/sync/struct.PriorityDispatcher.html
+use hey_listen::{
+    sync::{Listener, PriorityDispatcher, SyncDispatcherRequest},
+    RwLock,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Weak},
+    time::Instant,
+};
+
+const TIERS: u32 = 64;
+const DISPATCHES: u32 = 10_000;
+
+type WeakListener = Weak<RwLock<dyn Listener<u32> + Send + Sync>>;
+type SharedListener = Arc<RwLock<dyn Listener<u32> + Send + Sync>>;
+
+#[derive(Default)]
+struct Counter {
+    calls: usize,
+}
+
+impl Listener<u32> for Counter {
+    fn on_event(&mut self, _event: &u32) -> Option<SyncDispatcherRequest> {
+        self.calls += 1;
+
+        None
+    }
+}
+
+/// Runs `work` and returns how long it took.
+fn measure<F: FnOnce()>(work: F) -> u128 {
+    let start = Instant::now();
+    work();
+
+    start.elapsed().as_micros()
+}
+
+fn report(name: &str, micros: u128, calls: usize) {
+    println!(
+        "{:<28} {:>8} µs ({:.3} µs per call, {} calls)",
+        name,
+        micros,
+        micros as f64 / calls as f64,
+        calls
+    );
+}
+
+fn main() {
+    let shared = Arc::new(RwLock::new(Counter::default()));
+    let per_tier: Vec<_> = (0..TIERS)
+        .map(|_| Arc::new(RwLock::new(Counter::default())))
+        .collect();
+
+    let mut dispatcher: PriorityDispatcher<u32, u32> = PriorityDispatcher::default();
+
+    for (tier, listener) in per_tier.iter().enumerate() {
+        dispatcher.add_listener(0, &shared, tier as u32);
+        dispatcher.add_listener(0, listener, tier as u32);
+    }
+
+    let micros = measure(|| {
+        for _ in 0..DISPATCHES {
+            dispatcher.dispatch_event(&0);
+        }
+    });
+    report("sync::PriorityDispatcher", micros, shared.read().calls * 2);
+
+    let cached_shared = Arc::new(RwLock::new(Counter::default()));
+    let mut tiers: BTreeMap<u32, Vec<WeakListener>> = BTreeMap::new();
+
+    for (tier, listener) in per_tier.iter().enumerate() {
+        let shared = Arc::clone(&cached_shared) as SharedListener;
+        let listener = Arc::clone(listener) as SharedListener;
+        let level = tiers.entry(tier as u32).or_default();
+        level.push(Arc::downgrade(&shared));
+        level.push(Arc::downgrade(&listener));
+    }
+
+    let micros = measure(|| {
+        for _ in 0..DISPATCHES {
+            let mut upgraded: HashMap<*const (), SharedListener> = HashMap::new();
+
+            for level in tiers.values() {
+                for weak in level {
+                    let listener = upgraded
+                        .entry(Weak::as_ptr(weak) as *const ())
+                        .or_insert_with(|| weak.upgrade().expect("listeners are kept alive"));
+
+                    listener.write().on_event(&0);
+                }
+            }
+        }
+    });
+    report(
+        "Upgrade-cache baseline",
+        micros,
+        cached_shared.read().calls * 2,
+    );
+}
@@ -0,0 +1,67 @@
+//! Compares repeated small dispatches of a `ParallelDispatcher` building a
+//! new thread-pool per dispatch against dispatchers reusing one persistent
+//! pool via `ParallelDispatcher::with_pool`.
+//!
+//! Run with `cargo bench --bench shared_thread_pool`.
+
+use hey_listen::sync::ParallelDispatcher;
+use rayon::ThreadPoolBuilder;
+use std::{sync::Arc, time::Instant};
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum Event {
+    Tick,
+}
+
+const DISPATCHES: usize = 1_000;
+const THREADS: usize = 4;
+
+fn new_dispatcher(pool: Arc<rayon::ThreadPool>) -> ParallelDispatcher<Event> {
+    let mut dispatcher = ParallelDispatcher::with_pool(pool);
+
+    for _ in 0..8 {
+        dispatcher.add_fn(Event::Tick, Box::new(|_| None));
+    }
+
+    dispatcher
+}
+
+fn new_pool() -> Arc<rayon::ThreadPool> {
+    Arc::new(
+        ThreadPoolBuilder::new()
+            .num_threads(THREADS)
+            .build()
+            .expect("Failed to build thread-pool."),
+    )
+}
+
+fn main() {
+    // Building a new pool for every dispatch spawns and joins
+    // `THREADS` threads each time.
+    let start = Instant::now();
+
+    for _ in 0..DISPATCHES {
+        new_dispatcher(new_pool()).dispatch_event(&Event::Tick);
+    }
+
+    let pool_per_dispatch = start.elapsed();
+
+    // Our dispatchers share one pool, threads are spawned only once.
+    let shared_pool = new_pool();
+    let mut first_dispatcher = new_dispatcher(Arc::clone(&shared_pool));
+    let mut second_dispatcher = new_dispatcher(shared_pool);
+    let start = Instant::now();
+
+    for _ in 0..DISPATCHES / 2 {
+        first_dispatcher.dispatch_event(&Event::Tick);
+        second_dispatcher.dispatch_event(&Event::Tick);
+    }
+
+    let shared = start.elapsed();
+
+    println!(
+        "{} dispatches with a new pool each: {:?}",
+        DISPATCHES, pool_per_dispatch
+    );
+    println!("{} dispatches on a shared pool: {:?}", DISPATCHES, shared);
+}
@@ -1,5 +0,0 @@
-use skeptic;
-
-fn main() {
-    skeptic::generate_doc_tests(&["README.md"]);
-}
@@ -10,7 +10,7 @@
 //! closures can also become a listener.
 
 use hey_listen::{
-    sync::{ParallelDispatcherRequest, ParallelDispatcher, ParallelListener},
+    sync::{ParallelDispatcher, ParallelDispatcherRequest, ParallelListener},
     RwLock,
 };
 use std::sync::Arc;
@@ -32,13 +32,15 @@ struct ListenerStruct {
 // This implements the `ParallelListener`-trait, enabling the struct above (`ListenerStruct`)
 // to become a trait-object when starting listening.
 impl ParallelListener<Event> for ListenerStruct {
-    fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest> {
+    fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest<Event>> {
         println!("{}", self.number);
 
         // At the end, we have to return an `Option<SyncDispatcherRequest>` request back to
         // the dispatcher.
         // This request gives an instruction back to the dispatcher, here are the variants:
         // - `ParallelDispatcherRequest::StopListening` to automatically stop listening.
+        // - `ParallelDispatcherRequest::DispatchEvent` to dispatch a follow-up event once all
+        //   listeners are done.
         None
     }
 }
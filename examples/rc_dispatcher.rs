@@ -59,7 +59,7 @@ fn main() {
     // Create your dispatcher and define the generic type what the dispatcher
     // shall accept as dispatchable type, it's our declared `EventEnum` in this
     // example.
-    let mut dispatcher: Dispatcher<EventEnum> = Dispatcher::default();
+    let dispatcher: Dispatcher<EventEnum> = Dispatcher::default();
 
     // Make your listener start listening.
     dispatcher.add_listener(EventEnum::EventVariantA, &listener);
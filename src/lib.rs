@@ -49,9 +49,11 @@
 //! [`examples`]: https://github.com/Lakelezz/hey_listen/tree/master/examples
 #![deny(rust_2018_idioms)]
 
-use failure;
-
 pub mod rc;
 pub mod sync;
 
 pub use parking_lot::RwLock;
+
+#[cfg(doctest)]
+#[doc = include_str!("../README.md")]
+struct ReadmeDoctests;
@@ -0,0 +1,220 @@
+use super::{
+    execute_sync_dispatcher_requests, ContextListener, DispatchResult, ExecuteRequestsResult,
+    ListenerHandle, Registered, RwLock, SyncDispatcherRequest,
+};
+use crate::sync::remove_registered;
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    rc::{Rc, Weak},
+};
+
+type ContextFn<T, C> = Box<dyn FnMut(&T, &mut C) -> Option<SyncDispatcherRequest>>;
+type ContextListeners<T, C> = Vec<Registered<ContextEntry<T, C>>>;
+
+/// Either a [`Weak`]-reference to a [`ContextListener`] or an owned closure.
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/rc/struct.Weak.html
+/// [`ContextListener`]: trait.ContextListener.html
+enum ContextEntry<T, C>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    Trait(Weak<RwLock<dyn ContextListener<T, C> + 'static>>),
+    Fn(ContextFn<T, C>),
+}
+
+/// In charge of !Sync dispatching to listeners borrowing a context `C`.
+/// Owns a map event-variants and [`Weak`]-references to their
+/// [`ContextListener`]s and/or owns [`FnMut`]s.
+///
+/// Opposed to [`Dispatcher`], listeners do not need to store shared
+/// state themselves, instead the context is lent to every listener
+/// while dispatching via [`dispatch_event_with_context`].
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/rc/struct.Weak.html
+/// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+/// [`ContextListener`]: trait.ContextListener.html
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`dispatch_event_with_context`]: #method.dispatch_event_with_context
+pub struct ContextDispatcher<T, C>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    events: HashMap<T, ContextListeners<T, C>>,
+}
+
+impl<T, C> Default for ContextDispatcher<T, C>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    fn default() -> ContextDispatcher<T, C> {
+        ContextDispatcher {
+            events: HashMap::new(),
+        }
+    }
+}
+
+impl<T, C> ContextDispatcher<T, C>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    /// Adds a [`ContextListener`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// [`ContextListener`]: trait.ContextListener.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_listener<D>(
+        &mut self,
+        event_identifier: T,
+        listener: &Rc<RwLock<D>>,
+    ) -> ListenerHandle
+    where
+        D: ContextListener<T, C> + 'static,
+    {
+        self.register(
+            event_identifier,
+            ContextEntry::Trait(Rc::downgrade(
+                &(Rc::clone(listener) as Rc<RwLock<dyn ContextListener<T, C> + 'static>>),
+            )),
+        )
+    }
+
+    /// Adds a [`FnMut`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_fn<F>(&mut self, event_identifier: T, function: F) -> ListenerHandle
+    where
+        F: FnMut(&T, &mut C) -> Option<SyncDispatcherRequest> + 'static,
+    {
+        self.register(event_identifier, ContextEntry::Fn(Box::new(function)))
+    }
+
+    /// Removes the listener or [`FnMut`] registered with `handle`,
+    /// the order of all other listeners stays untouched.
+    /// Returns `false` if `handle` is not registered on this dispatcher.
+    ///
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    pub fn remove_listener(&mut self, handle: ListenerHandle) -> bool {
+        self.events
+            .values_mut()
+            .any(|listeners| remove_registered(listeners, handle))
+    }
+
+    /// All [`ContextListener`]s and [`FnMut`]s listening to a passed
+    /// `event_identifier` will be called in the order they have been
+    /// added, each borrowing `context` mutably in turn.
+    /// Hence a listener observes all changes to `context` made by
+    /// the listeners called before it.
+    /// A returned [`SyncDispatcherRequest`] is honoured no matter whether
+    /// it stems from a [`ContextListener`] or a [`FnMut`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::ContextDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     KeyPress,
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct AppState {
+    ///     typed: String,
+    ///     saved: bool,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: ContextDispatcher<Event, AppState> = ContextDispatcher::default();
+    ///     let mut app_state = AppState::default();
+    ///
+    ///     dispatcher.add_fn(Event::KeyPress, |_, app_state| {
+    ///         app_state.typed.push('x');
+    ///
+    ///         None
+    ///     });
+    ///     dispatcher.add_fn(Event::KeyPress, |_, app_state| {
+    ///         app_state.saved = !app_state.typed.is_empty();
+    ///
+    ///         None
+    ///     });
+    ///
+    ///     dispatcher.dispatch_event_with_context(&Event::KeyPress, &mut app_state);
+    ///
+    ///     assert_eq!(app_state.typed, "x");
+    ///     assert!(app_state.saved);
+    /// }
+    /// ```
+    ///
+    /// [`ContextListener`]: trait.ContextListener.html
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    /// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
+    pub fn dispatch_event_with_context(
+        &mut self,
+        event_identifier: &T,
+        context: &mut C,
+    ) -> DispatchResult {
+        let mut dispatch_result = DispatchResult::default();
+
+        let listeners = match self.events.get_mut(event_identifier) {
+            Some(listeners) => listeners,
+            None => return dispatch_result,
+        };
+
+        let mut found_invalid_weak_ref = false;
+        let invoked = &mut dispatch_result.invoked;
+
+        let result =
+            execute_sync_dispatcher_requests(listeners, |registered| {
+                match &mut registered.listener {
+                    ContextEntry::Trait(weak_listener) => {
+                        if let Some(listener) = weak_listener.upgrade() {
+                            *invoked += 1;
+                            let mut listener = listener.write();
+                            listener.on_event(event_identifier, context)
+                        } else {
+                            found_invalid_weak_ref = true;
+                            None
+                        }
+                    }
+                    ContextEntry::Fn(callback) => {
+                        *invoked += 1;
+                        callback(event_identifier, context)
+                    }
+                }
+            });
+
+        if found_invalid_weak_ref {
+            listeners.retain(|registered| match &registered.listener {
+                ContextEntry::Trait(weak_listener) => weak_listener.strong_count() > 0,
+                ContextEntry::Fn(_) => true,
+            });
+        }
+
+        if let ExecuteRequestsResult::Stopped = result {
+            dispatch_result.stopped = true;
+        }
+
+        dispatch_result
+    }
+
+    /// Appends `listener` to the listeners of `event_identifier`.
+    fn register(&mut self, event_identifier: T, listener: ContextEntry<T, C>) -> ListenerHandle {
+        let registered = Registered::new(listener);
+        let handle = registered.handle;
+
+        self.events
+            .entry(event_identifier)
+            .or_default()
+            .push(registered);
+
+        handle
+    }
+}
@@ -1,23 +1,108 @@
 use super::{
-    execute_sync_dispatcher_requests, FnsAndTraits, Listener, ListenerMap, RwLock,
-    SyncDispatcherRequest,
+    dispatch_mut_to_listeners, dispatch_to_listeners, DispatchError, DispatchResult, Listener,
+    ListenerHandle, ListenerMap, Listeners, MutListener, MutListenerMap, RcFilter, RcListener,
+    RcMutListener, Registered, RwLock, SyncDispatcherRequest, DEFAULT_MAX_DISPATCH_DEPTH,
 };
+use crate::sync::take_registered;
 use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, VecDeque},
     hash::Hash,
-    rc::{Rc, Weak},
+    iter::FromIterator,
+    mem,
+    rc::Rc,
 };
 
 /// In charge of !Sync dispatching to all listeners.
 /// Owns a map event-variants and
-/// [`Weak`]-references to their listeners and/or owns [`Fn`]s.
+/// [`Weak`]-references to their listeners and/or owns [`FnMut`]s.
 ///
-/// [`Weak`]: https://doc.rust-lang.org/std/!Sync/struct.Weak.html
-/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+/// All methods take `&self`, the listeners are kept in a [`RefCell`]
+/// borrowed while dispatching.
+/// Hence the dispatcher can be shared via [`Rc`] and listeners may use it
+/// while being dispatched to:
+/// A dispatch started by a listener is queued and dispatched once the
+/// current event has reached all of its remaining listeners, listeners
+/// added or removed by a listener are added or removed once the current
+/// event has been dispatched.
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/rc/struct.Weak.html
+/// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+/// [`RefCell`]: https://doc.rust-lang.org/std/cell/struct.RefCell.html
+/// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
 pub struct Dispatcher<T>
 where
     T: PartialEq + Eq + Hash + Clone + 'static,
 {
-    events: ListenerMap<T>,
+    state: RefCell<DispatcherState<T>>,
+    pending: RefCell<Vec<PendingChange<T>>>,
+    queue: Rc<EventQueue<T>>,
+}
+
+/// What a [`Dispatcher`] created via [`Dispatcher::with_queue_capacity`]
+/// does with an event queued while its queue is full.
+///
+/// `DropNewest`: Drops the event, the queued events stay untouched.
+/// `DropOldest`: Drops the oldest queued event of the lowest priority to
+/// make room, the front-most one unless priorities are used, see
+/// [`Dispatcher::enqueue_event_with_priority`]. An event of a lower
+/// priority than all queued events is dropped itself instead.
+/// `ReturnError`: Like `DropNewest`, but [`Dispatcher::try_enqueue_event`]
+/// hands the event back via [`EnqueueError::QueueFull`].
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`Dispatcher::with_queue_capacity`]: struct.Dispatcher.html#method.with_queue_capacity
+/// [`Dispatcher::try_enqueue_event`]: struct.Dispatcher.html#method.try_enqueue_event
+/// [`Dispatcher::enqueue_event_with_priority`]: struct.Dispatcher.html#method.enqueue_event_with_priority
+/// [`EnqueueError::QueueFull`]: enum.EnqueueError.html#variant.QueueFull
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OverflowPolicy {
+    DropNewest,
+    DropOldest,
+    ReturnError,
+}
+
+/// How a [`Dispatcher`] coalesces an event set up via
+/// [`Dispatcher::set_coalescing`] with an equal event that is already
+/// queued, so at most one of them stays queued.
+///
+/// `KeepFirst`: Drops the new event, the queued one stays untouched.
+/// `KeepLast`: Replaces the queued event with the new one, which takes
+/// over the queued event's position in FIFO-order and its priority, unless
+/// the new event has a higher priority and is queued anew behind the events
+/// of that priority.
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`Dispatcher::set_coalescing`]: struct.Dispatcher.html#method.set_coalescing
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Coalesce {
+    KeepFirst,
+    KeepLast,
+}
+
+/// An event refused by [`Dispatcher::try_enqueue_event`], handing it back.
+///
+/// `QueueFull`: The queue is full under `OverflowPolicy::ReturnError`, or
+/// the event has been dropped to make room under another policy.
+/// `MaxDepthExceeded`: The event has been queued by a listener whose chain
+/// of queueing events has grown beyond the maximal dispatch-depth, see
+/// [`Dispatcher::set_max_dispatch_depth`].
+///
+/// [`Dispatcher::try_enqueue_event`]: struct.Dispatcher.html#method.try_enqueue_event
+/// [`Dispatcher::set_max_dispatch_depth`]: struct.Dispatcher.html#method.set_max_dispatch_depth
+#[derive(Debug, Eq, PartialEq)]
+pub enum EnqueueError<T> {
+    QueueFull(T),
+    MaxDepthExceeded(T),
+}
+
+impl<T> EnqueueError<T> {
+    /// Returns the refused event.
+    pub fn into_event(self) -> T {
+        match self {
+            EnqueueError::QueueFull(event) | EnqueueError::MaxDepthExceeded(event) => event,
+        }
+    }
 }
 
 impl<T> Default for Dispatcher<T>
@@ -26,17 +111,423 @@ where
 {
     fn default() -> Dispatcher<T> {
         Dispatcher {
+            state: RefCell::new(DispatcherState::default()),
+            pending: RefCell::new(Vec::new()),
+            queue: Rc::new(EventQueue {
+                events: RefCell::new(VecDeque::new()),
+                depth: Cell::new(0),
+                max_depth: Cell::new(DEFAULT_MAX_DISPATCH_DEPTH),
+                capacity: None,
+                overflow_policy: OverflowPolicy::DropNewest,
+                coalescing: RefCell::new(HashMap::new()),
+                immediate: Cell::new(false),
+            }),
+        }
+    }
+}
+
+/// Events queued for dispatching, shared by a [`Dispatcher`] and its
+/// [`Emitter`]s.
+/// Every event is queued along with its dispatch-depth: Events queued
+/// while no event is dispatched have a depth of `0`, events queued while
+/// dispatching an event of depth `n` have a depth of `n + 1`.
+/// Events are kept ordered by descending priority, in FIFO-order within
+/// the same priority.
+/// Events queued while an immediate dispatch is ongoing are tagged, so
+/// the dispatch delivers them and leaves events queued beforehand to
+/// [`Dispatcher::process_queue`].
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`Emitter`]: struct.Emitter.html
+/// [`Dispatcher::process_queue`]: struct.Dispatcher.html#method.process_queue
+struct EventQueue<T> {
+    events: RefCell<VecDeque<Queued<T>>>,
+    depth: Cell<usize>,
+    max_depth: Cell<usize>,
+    capacity: Option<usize>,
+    overflow_policy: OverflowPolicy,
+    coalescing: RefCell<HashMap<T, Coalesce>>,
+    immediate: Cell<bool>,
+}
+
+/// The priority of events queued without one, see
+/// [`Dispatcher::enqueue_event_with_priority`].
+///
+/// [`Dispatcher::enqueue_event_with_priority`]: struct.Dispatcher.html#method.enqueue_event_with_priority
+const DEFAULT_PRIORITY: u8 = 0;
+
+/// A queued event along with its dispatch-depth, priority and whether
+/// it has been queued during an immediate dispatch.
+struct Queued<T> {
+    event: T,
+    depth: usize,
+    priority: u8,
+    immediate: bool,
+}
+
+impl<T: Eq + Hash> EventQueue<T> {
+    /// Queues `event` at the current dispatch-depth, unless it exceeds
+    /// the maximal dispatch-depth.
+    /// A full queue drops an event according to its overflow-policy.
+    fn push(&self, event: T, priority: u8) -> Result<(), DispatchError> {
+        if self.depth.get() > self.max_depth.get() {
+            return Err(DispatchError::MaxDepthExceeded);
+        }
+
+        let _ = self.push_bounded(event, priority);
+
+        Ok(())
+    }
+
+    /// Queues `event` at the current dispatch-depth behind all events of
+    /// the same or a higher priority, making room according to the
+    /// overflow-policy if the queue is full.
+    /// An event coalescing with a queued one does not need room.
+    /// Hands `event` back if it has not been queued.
+    fn push_bounded(&self, event: T, priority: u8) -> Result<(), T> {
+        let mut events = self.events.borrow_mut();
+
+        if let Some(coalesce) = self.coalescing.borrow().get(&event) {
+            if let Some(index) = events.iter().position(|queued| queued.event == event) {
+                match coalesce {
+                    Coalesce::KeepFirst => return Ok(()),
+                    Coalesce::KeepLast if events[index].priority >= priority => {
+                        let queued = &mut events[index];
+                        queued.event = event;
+                        queued.depth = self.depth.get();
+                        queued.immediate = self.immediate.get();
+
+                        return Ok(());
+                    }
+                    // A higher priority moves the event ahead, queue it anew.
+                    Coalesce::KeepLast => {
+                        events.remove(index);
+                    }
+                }
+            }
+        }
+
+        if self
+            .capacity
+            .is_some_and(|capacity| events.len() >= capacity)
+        {
+            let lowest = events.back().map(|queued| queued.priority);
+
+            match (self.overflow_policy, lowest) {
+                // Events below all queued priorities are dropped themselves.
+                (OverflowPolicy::DropOldest, Some(lowest)) if lowest <= priority => {
+                    let oldest = events.partition_point(|queued| queued.priority > lowest);
+                    events.remove(oldest);
+                }
+                _ => return Err(event),
+            }
+        }
+
+        let index = events.partition_point(|queued| queued.priority >= priority);
+        events.insert(
+            index,
+            Queued {
+                event,
+                depth: self.depth.get(),
+                priority,
+                immediate: self.immediate.get(),
+            },
+        );
+
+        Ok(())
+    }
+}
+
+/// The listeners of a [`Dispatcher`], mutably borrowed during a dispatch.
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+struct DispatcherState<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    events: ListenerMap<T>,
+    catch_all: Listeners<T>,
+    mut_events: MutListenerMap<T>,
+}
+
+impl<T> Default for DispatcherState<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    fn default() -> DispatcherState<T> {
+        DispatcherState {
             events: ListenerMap::new(),
+            catch_all: Listeners::new(),
+            mut_events: MutListenerMap::new(),
+        }
+    }
+}
+
+impl<T> DispatcherState<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    /// Appends the registrations of `other` after the registrations of
+    /// the same event-variant.
+    fn append(&mut self, other: DispatcherState<T>) {
+        for (event_identifier, mut listeners) in other.events {
+            self.events
+                .entry(event_identifier)
+                .or_default()
+                .append(&mut listeners);
+        }
+
+        for (event_identifier, mut listeners) in other.mut_events {
+            self.mut_events
+                .entry(event_identifier)
+                .or_default()
+                .append(&mut listeners);
+        }
+
+        let mut catch_all = other.catch_all;
+        self.catch_all.append(&mut catch_all);
+    }
+
+    /// Appends `registered` to the listeners of `event_identifier`,
+    /// or to the catch-all listeners if there is none.
+    fn insert(&mut self, event_identifier: Option<T>, registered: Registered<RcListener<T>>) {
+        match event_identifier {
+            Some(event_identifier) => self
+                .events
+                .entry(event_identifier)
+                .or_default()
+                .push(registered),
+            None => self.catch_all.push(registered),
+        }
+    }
+
+    /// Appends `registered` to the mutable listeners of `event_identifier`.
+    fn insert_mut(&mut self, event_identifier: T, registered: Registered<RcMutListener<T>>) {
+        self.mut_events
+            .entry(event_identifier)
+            .or_default()
+            .push(registered);
+    }
+
+    /// Removes the registration identified by `handle` and returns it.
+    fn remove(&mut self, handle: ListenerHandle) -> Option<Removed<T>> {
+        take_registered(&mut self.catch_all, handle)
+            .or_else(|| {
+                self.events
+                    .values_mut()
+                    .find_map(|listeners| take_registered(listeners, handle))
+            })
+            .map(|registered| Removed::Listener(registered.listener))
+            .or_else(|| {
+                self.mut_events
+                    .values_mut()
+                    .find_map(|listeners| take_registered(listeners, handle))
+                    .map(|registered| Removed::MutListener(registered.listener))
+            })
+    }
+
+    /// Puts the registration identified by `handle` behind `filter`.
+    /// Returns whether `handle` has been found, mutable listeners are
+    /// not considered.
+    fn set_filter(&mut self, handle: ListenerHandle, filter: RcFilter<T>) -> bool {
+        let registered = self
+            .catch_all
+            .iter_mut()
+            .chain(self.events.values_mut().flatten())
+            .find(|registered| registered.handle == handle);
+
+        match registered {
+            Some(registered) => {
+                registered.listener.set_filter(filter);
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Dispatches `event_identifier` to its listeners and the catch-all
+    /// listeners.
+    fn dispatch(&mut self, event_identifier: &T) -> DispatchResult {
+        let mut dispatch_result = DispatchResult::default();
+
+        if let Some(listeners) = self.events.get_mut(event_identifier) {
+            dispatch_to_listeners(listeners, event_identifier, &mut dispatch_result);
+        }
+
+        if !self.catch_all.is_empty() {
+            dispatch_to_listeners(&mut self.catch_all, event_identifier, &mut dispatch_result);
+        }
+
+        dispatch_result
+    }
+}
+
+/// A change requested while the [`Dispatcher`] has been dispatching,
+/// applied once the current event has been dispatched.
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+enum PendingChange<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    Register(Option<T>, Registered<RcListener<T>>),
+    RegisterMut(T, Registered<RcMutListener<T>>),
+    Remove(ListenerHandle),
+    Filter(ListenerHandle, RcFilter<T>),
+    Append(DispatcherState<T>),
+}
+
+/// A listener removed from the [`Dispatcher`], to be unsubscribed once
+/// the listeners are no longer borrowed.
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+enum Removed<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    Listener(RcListener<T>),
+    MutListener(RcMutListener<T>),
+}
+
+impl<T> Removed<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    fn unsubscribe(self) {
+        match self {
+            Removed::Listener(mut listener) => listener.unsubscribe(),
+            Removed::MutListener(mut listener) => listener.unsubscribe(),
+        }
+    }
+}
+
+/// Counts the registrations of `listeners` satisfying `is_alive`.
+fn count_alive<L, F>(listeners: &[Registered<L>], is_alive: F) -> usize
+where
+    F: Fn(&Registered<L>) -> bool,
+{
+    listeners
+        .iter()
+        .filter(|registered| is_alive(registered))
+        .count()
+}
+
+/// Removes all registrations of `listeners` whose handle does not satisfy
+/// `predicate` and returns them, the order of the kept ones stays untouched.
+fn take_rejected<L, F>(listeners: &mut Vec<Registered<L>>, predicate: &mut F) -> Vec<Registered<L>>
+where
+    F: FnMut(ListenerHandle) -> bool,
+{
+    let (kept, rejected) = mem::take(listeners)
+        .into_iter()
+        .partition(|registered| predicate(registered.handle));
+    *listeners = kept;
+
+    rejected
+}
+
+/// Emits events to a [`Dispatcher`] from within its own listeners,
+/// created via [`Dispatcher::emitter`].
+///
+/// A listener cannot borrow the dispatcher currently dispatching to it,
+/// instead emitted events are queued and dispatched once the current
+/// event has reached all its listeners.
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`Dispatcher::emitter`]: struct.Dispatcher.html#method.emitter
+pub struct Emitter<T> {
+    queue: Rc<EventQueue<T>>,
+}
+
+impl<T> Clone for Emitter<T> {
+    fn clone(&self) -> Self {
+        Emitter {
+            queue: Rc::clone(&self.queue),
         }
     }
 }
 
+impl<T: Eq + Hash> Emitter<T> {
+    /// Queues `event` to be dispatched after the event that is
+    /// currently being dispatched, in emission-order.
+    /// Without an ongoing dispatch, `event` is dispatched by the next
+    /// [`Dispatcher::process_queue`].
+    ///
+    /// An `event` exceeding the maximal dispatch-depth is dropped, use
+    /// [`try_emit`] to be told.
+    ///
+    /// [`Dispatcher::process_queue`]: struct.Dispatcher.html#method.process_queue
+    /// [`try_emit`]: #method.try_emit
+    pub fn emit(&self, event: T) {
+        let _ = self.try_emit(event);
+    }
+
+    /// Queues `event` like [`emit`], but returns
+    /// [`DispatchError::MaxDepthExceeded`] instead of dropping `event`
+    /// silently if the chain of events emitting each other has grown
+    /// beyond the maximal dispatch-depth.
+    ///
+    /// [`emit`]: #method.emit
+    /// [`DispatchError::MaxDepthExceeded`]: enum.DispatchError.html#variant.MaxDepthExceeded
+    pub fn try_emit(&self, event: T) -> Result<(), DispatchError> {
+        self.queue.push(event, DEFAULT_PRIORITY)
+    }
+}
+
 impl<T> Dispatcher<T>
 where
     T: PartialEq + Eq + Hash + Clone + 'static,
 {
+    /// Creates a dispatcher whose queue holds at most `capacity` events,
+    /// see [`enqueue_event`].
+    /// Events queued while the queue is full, be it by [`enqueue_event`],
+    /// an [`Emitter`] or a listener dispatching, are dropped according to
+    /// `overflow_policy`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::{Dispatcher, EnqueueError, OverflowPolicy};
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick(u32),
+    /// }
+    ///
+    /// fn main() {
+    ///     let dispatcher = Dispatcher::with_queue_capacity(1, OverflowPolicy::ReturnError);
+    ///
+    ///     assert_eq!(dispatcher.try_enqueue_event(Event::Tick(1)), Ok(()));
+    ///     assert_eq!(
+    ///         dispatcher.try_enqueue_event(Event::Tick(2)),
+    ///         Err(EnqueueError::QueueFull(Event::Tick(2)))
+    ///     );
+    ///     assert_eq!(dispatcher.queued_len(), 1);
+    ///     assert_eq!(dispatcher.capacity(), Some(1));
+    /// }
+    /// ```
+    ///
+    /// [`enqueue_event`]: #method.enqueue_event
+    /// [`Emitter`]: struct.Emitter.html
+    pub fn with_queue_capacity(capacity: usize, overflow_policy: OverflowPolicy) -> Self {
+        Dispatcher {
+            queue: Rc::new(EventQueue {
+                events: RefCell::new(VecDeque::with_capacity(capacity)),
+                depth: Cell::new(0),
+                max_depth: Cell::new(DEFAULT_MAX_DISPATCH_DEPTH),
+                capacity: Some(capacity),
+                overflow_policy,
+                coalescing: RefCell::new(HashMap::new()),
+                immediate: Cell::new(false),
+            }),
+            ..Dispatcher::default()
+        }
+    }
+
     /// Adds a [`Listener`] to listen for an `event_identifier`.
     /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
     ///
     /// **Note**: If your `Enum` owns fields you need to consider implementing
     /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields,
@@ -63,7 +554,7 @@ where
     ///
     /// fn main() {
     ///     let listener = Rc::new(RwLock::new(ListenerStruct {}));
-    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
     ///
     ///     dispatcher.add_listener(Event::EventType, &listener);
     /// }
@@ -98,129 +589,1682 @@ where
     /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
     /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
     /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
     pub fn add_listener<D: Listener<T> + 'static>(
-        &mut self,
+        &self,
         event_identifier: T,
         listener: &Rc<RwLock<D>>,
-    ) {
-        if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
-            listener_collection.traits.push(Rc::downgrade(
+    ) -> ListenerHandle {
+        self.register(
+            Some(event_identifier),
+            RcListener::Trait(Rc::downgrade(
                 &(Rc::clone(listener) as Rc<RwLock<dyn Listener<T> + 'static>>),
-            ));
+            )),
+        )
+    }
 
-            return;
-        }
+    /// Adds a [`Listener`] to listen for an `event_identifier` like
+    /// [`add_listener`], but only for events passing `filter`.
+    /// The filter runs before the listener is locked, events it rejects
+    /// skip the listener entirely and do not count as invoked.
+    ///
+    /// **Note**: Use [`set_filter`] to filter other kinds of registrations,
+    /// e.g. closures or one-shot listeners.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{rc::Dispatcher, rc::Listener, rc::SyncDispatcherRequest, RwLock};
+    /// use std::{cell::Cell, rc::Rc};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct Simulation {
+    ///     ticks: usize,
+    /// }
+    ///
+    /// impl Listener<Event> for Simulation {
+    ///     fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+    ///         self.ticks += 1;
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let simulation = Rc::new(RwLock::new(Simulation::default()));
+    ///     let paused = Rc::new(Cell::new(false));
+    ///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///
+    ///     let is_paused = Rc::clone(&paused);
+    ///     dispatcher.add_listener_filtered(Event::Tick, &simulation, move |_| !is_paused.get());
+    ///
+    ///     dispatcher.dispatch_event(&Event::Tick);
+    ///     paused.set(true);
+    ///     dispatcher.dispatch_event(&Event::Tick);
+    ///
+    ///     assert_eq!(simulation.read().ticks, 1);
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`add_listener`]: #method.add_listener
+    /// [`set_filter`]: #method.set_filter
+    pub fn add_listener_filtered<D, F>(
+        &self,
+        event_identifier: T,
+        listener: &Rc<RwLock<D>>,
+        filter: F,
+    ) -> ListenerHandle
+    where
+        D: Listener<T> + 'static,
+        F: Fn(&T) -> bool + 'static,
+    {
+        let mut listener = RcListener::Trait(Rc::downgrade(
+            &(Rc::clone(listener) as Rc<RwLock<dyn Listener<T> + 'static>>),
+        ));
+        listener.set_filter(Box::new(filter));
 
-        self.events.insert(
-            event_identifier,
-            FnsAndTraits::new_with_traits(vec![Rc::downgrade(
-                &(Rc::clone(listener) as Rc<RwLock<dyn Listener<T> + 'static>>),
-            )]),
-        );
+        self.register(Some(event_identifier), listener)
     }
 
-    /// Adds a [`Fn`] to listen for an `event_identifier`.
-    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Adds a [`Listener`] to listen for every event-variant in
+    /// `event_identifiers` and returns the [`ListenerHandle`]s of all
+    /// registrations in iteration-order.
     ///
-    /// **Note**: If your `Enum` owns fields you need to consider implementing
-    /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields.
+    /// This is the very same as calling [`add_listener`] for each
+    /// event-variant in turn, hence an event-variant occurring twice
+    /// registers `listener` twice for it and it will be called twice.
     ///
     /// # Examples
     ///
-    /// Adding a [`Fn`] to the dispatcher:
-    ///
     /// ```rust
-    /// use hey_listen::{rc::dispatcher::Dispatcher, rc::Listener, RwLock, rc::SyncDispatcherRequest};
+    /// use hey_listen::{rc::Dispatcher, rc::Listener, rc::SyncDispatcherRequest, RwLock};
     /// use std::rc::Rc;
     ///
     /// #[derive(Clone, Eq, Hash, PartialEq)]
     /// enum Event {
-    ///     EventType,
+    ///     Resize,
+    ///     Redraw,
+    ///     Close,
+    /// }
+    ///
+    /// struct Widget;
+    ///
+    /// impl Listener<Event> for Widget {
+    ///     fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> { None }
+    /// }
+    ///
+    /// fn main() {
+    ///     let widget = Rc::new(RwLock::new(Widget));
+    ///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///
+    ///     let handles = dispatcher.add_listener_to_all(
+    ///         vec![Event::Resize, Event::Redraw, Event::Close],
+    ///         &widget,
+    ///     );
+    ///
+    ///     assert_eq!(handles.len(), 3);
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    /// [`add_listener`]: #method.add_listener
+    pub fn add_listener_to_all<I, D>(
+        &self,
+        event_identifiers: I,
+        listener: &Rc<RwLock<D>>,
+    ) -> Vec<ListenerHandle>
+    where
+        I: IntoIterator<Item = T>,
+        D: Listener<T> + 'static,
+    {
+        event_identifiers
+            .into_iter()
+            .map(|event_identifier| self.add_listener(event_identifier, listener))
+            .collect()
+    }
+
+    /// Adds a [`Listener`] owned by the dispatcher to listen for an
+    /// `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// Opposed to [`add_listener`], neither [`Rc`] nor [`RwLock`] are
+    /// involved, `on_event` is called on the [`Box`] directly.
+    /// Therefore, the listener can only be reached via the dispatcher and
+    /// stays registered until removed or until it requests to stop listening.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::{Dispatcher, Listener, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
     /// }
     ///
-    /// struct EventListener {
-    ///     used_method: bool,
+    /// struct Counter {
+    ///     ticks: usize,
     /// }
     ///
-    /// impl EventListener {
-    ///     fn test_method(&mut self, _event: &Event) {
-    ///         self.used_method = true;
+    /// impl Listener<Event> for Counter {
+    ///     fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+    ///         self.ticks += 1;
+    ///
+    ///         None
     ///     }
     /// }
     ///
     /// fn main() {
-    ///     let listener = Rc::new(RwLock::new(EventListener { used_method: false }));
-    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
-    ///     let weak_listener_ref = Rc::downgrade(&Rc::clone(&listener));
+    ///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.add_owned_listener(Event::Tick, Box::new(Counter { ticks: 0 }));
     ///
-    ///     let closure = Box::new(move |event: &Event| -> Option<SyncDispatcherRequest> {
-    ///         if let Some(listener) = weak_listener_ref.upgrade() {
-    ///             listener.write().test_method(&event);
+    ///     dispatcher.dispatch_event(&Event::Tick);
+    /// }
+    /// ```
     ///
-    ///             None
-    ///         } else {
+    /// [`Listener`]: trait.Listener.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    /// [`add_listener`]: #method.add_listener
+    /// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+    /// [`RwLock`]: ../type.RwLock.html
+    /// [`Box`]: https://doc.rust-lang.org/std/boxed/struct.Box.html
+    pub fn add_owned_listener(
+        &self,
+        event_identifier: T,
+        listener: Box<dyn Listener<T>>,
+    ) -> ListenerHandle {
+        self.register(Some(event_identifier), RcListener::Owned(listener))
+    }
+
+    /// Adds a [`FnMut`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// The dispatcher owns `function`, hence it may mutate whatever it
+    /// captures without any [`Rc`] or lock.
+    /// Closures and [`Listener`]s are dispatched together in the order
+    /// they have been added.
+    ///
+    /// **Note**: If your `Enum` owns fields you need to consider implementing
+    /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields.
+    ///
+    /// # Examples
+    ///
+    /// Adding a [`FnMut`] to the dispatcher:
+    ///
+    /// ```rust
+    /// use hey_listen::rc::{Dispatcher, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// fn main() {
+    ///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let mut clicks = 0;
+    ///
+    ///     dispatcher.add_fn(Event::EventType, move |_| {
+    ///         clicks += 1;
+    ///
+    ///         if clicks == 2 {
     ///             Some(SyncDispatcherRequest::StopListening)
+    ///         } else {
+    ///             None
     ///         }
     ///     });
     ///
-    ///     dispatcher.add_fn(Event::EventType, closure);
+    ///     dispatcher.dispatch_event(&Event::EventType);
     /// }
     /// ```
     ///
-    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    /// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+    /// [`Listener`]: trait.Listener.html
     /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
     /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
     /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
-    pub fn add_fn(
-        &mut self,
-        event_identifier: T,
-        function: Box<dyn Fn(&T) -> Option<SyncDispatcherRequest> + 'static>,
-    ) {
-        if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
-            listener_collection.fns.push(function);
-
-            return;
-        }
-
-        self.events
-            .insert(event_identifier, FnsAndTraits::new_with_fns(vec![function]));
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_fn<F>(&self, event_identifier: T, function: F) -> ListenerHandle
+    where
+        F: FnMut(&T) -> Option<SyncDispatcherRequest> + 'static,
+    {
+        self.register(Some(event_identifier), RcListener::Fn(Box::new(function)))
     }
 
-    /// All [`Listener`]s listening to a passed `event_identifier`
-    /// will be called via their implemented [`on_event`]-method.
-    /// [`Fn`]s returning [`Result`] with `Ok(())` will be retained
-    /// and `Err(SyncDispatcherRequest::StopListening)` will cause them to
-    /// be removed from the event-dispatcher.
+    /// Adds a [`Listener`] that is called on the first dispatch of
+    /// `event_identifier` only and removed afterwards, no matter
+    /// which request it returns.
+    /// If propagation stops before it has been reached, it stays registered.
     ///
     /// [`Listener`]: trait.Listener.html
-    /// [`on_event`]: trait.Listener.html#tymethod.on_event
-    /// [`Error`]: enum.Error.html
-    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
-    /// [`Box`]: https://doc.rust-lang.org/std/boxed/struct.Box.html
-    /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
-    pub fn dispatch_event(&mut self, event_identifier: &T) {
-        if let Some(listener_collection) = self.events.get_mut(event_identifier) {
-            let mut found_invalid_weak_ref = false;
-
-            execute_sync_dispatcher_requests(&mut listener_collection.traits, |weak_listener| {
-                if let Some(listener) = weak_listener.upgrade() {
-                    let mut listener = listener.write();
-                    listener.on_event(event_identifier)
-                } else {
-                    found_invalid_weak_ref = true;
-                    None
-                }
-            });
-
-            execute_sync_dispatcher_requests(&mut listener_collection.fns, |callback| {
-                callback(event_identifier)
-            });
-
-            if found_invalid_weak_ref {
-                listener_collection
-                    .traits
-                    .retain(|listener| Weak::clone(listener).upgrade().is_some());
+    pub fn add_listener_once<D: Listener<T> + 'static>(
+        &self,
+        event_identifier: T,
+        listener: &Rc<RwLock<D>>,
+    ) -> ListenerHandle {
+        self.register(
+            Some(event_identifier),
+            RcListener::TraitOnce(Rc::downgrade(
+                &(Rc::clone(listener) as Rc<RwLock<dyn Listener<T> + 'static>>),
+            )),
+        )
+    }
+
+    /// Adds a [`FnOnce`] that is called on the first dispatch of
+    /// `event_identifier` only and removed afterwards.
+    /// As the closure is consumed, it may move captured values out.
+    /// If propagation stops before it has been reached, it stays registered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Confirm,
+    /// }
+    ///
+    /// fn main() {
+    ///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let pending_action = String::from("delete file");
+    ///
+    ///     dispatcher.add_fn_once(Event::Confirm, move |_| {
+    ///         drop(pending_action);
+    ///
+    ///         None
+    ///     });
+    ///
+    ///     dispatcher.dispatch_event(&Event::Confirm);
+    /// }
+    /// ```
+    ///
+    /// [`FnOnce`]: https://doc.rust-lang.org/std/ops/trait.FnOnce.html
+    pub fn add_fn_once<F>(&self, event_identifier: T, function: F) -> ListenerHandle
+    where
+        F: FnOnce(&T) -> Option<SyncDispatcherRequest> + 'static,
+    {
+        self.register(
+            Some(event_identifier),
+            RcListener::FnOnce(Some(Box::new(function))),
+        )
+    }
+
+    /// Adds a [`Listener`] that is called for every dispatched event,
+    /// e.g. to record or log them.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// Catch-all listeners are called after the listeners of the
+    /// dispatched event, in the order they have been added.
+    /// They are called even if one of the event's listeners stopped
+    /// propagation, but can stop propagation among each other.
+    /// Returning [`SyncDispatcherRequest::StopListening`] unsubscribes
+    /// the listener from all events.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{rc::Dispatcher, rc::Listener, rc::SyncDispatcherRequest, RwLock};
+    /// use std::rc::Rc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Insert,
+    ///     Delete,
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct History {
+    ///     events: Vec<Event>,
+    /// }
+    ///
+    /// impl Listener<Event> for History {
+    ///     fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> {
+    ///         self.events.push(event.clone());
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let history = Rc::new(RwLock::new(History::default()));
+    ///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.add_catch_all_listener(&history);
+    ///
+    ///     dispatcher.dispatch_event(&Event::Insert);
+    ///     dispatcher.dispatch_event(&Event::Delete);
+    ///
+    ///     assert_eq!(history.read().events.len(), 2);
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    /// [`SyncDispatcherRequest::StopListening`]: enum.SyncDispatcherRequest.html#variant.StopListening
+    pub fn add_catch_all_listener<D: Listener<T> + 'static>(
+        &self,
+        listener: &Rc<RwLock<D>>,
+    ) -> ListenerHandle {
+        self.register(
+            None,
+            RcListener::Trait(Rc::downgrade(
+                &(Rc::clone(listener) as Rc<RwLock<dyn Listener<T> + 'static>>),
+            )),
+        )
+    }
+
+    /// Adds a [`MutListener`] to listen for mutable dispatches of an
+    /// `event_identifier` via [`dispatch_event_mut`].
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// [`MutListener`]: trait.MutListener.html
+    /// [`dispatch_event_mut`]: #method.dispatch_event_mut
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_mut_listener<D: MutListener<T> + 'static>(
+        &self,
+        event_identifier: T,
+        listener: &Rc<RwLock<D>>,
+    ) -> ListenerHandle {
+        self.register_mut(
+            event_identifier,
+            RcMutListener::Trait(Rc::downgrade(
+                &(Rc::clone(listener) as Rc<RwLock<dyn MutListener<T> + 'static>>),
+            )),
+        )
+    }
+
+    /// Adds a [`FnMut`] to listen for mutable dispatches of an
+    /// `event_identifier` via [`dispatch_event_mut`].
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    /// [`dispatch_event_mut`]: #method.dispatch_event_mut
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_mut_fn<F>(&self, event_identifier: T, function: F) -> ListenerHandle
+    where
+        F: FnMut(&mut T) -> Option<SyncDispatcherRequest> + 'static,
+    {
+        self.register_mut(event_identifier, RcMutListener::Fn(Box::new(function)))
+    }
+
+    /// Appends `listener` to the mutable listeners of `event_identifier`.
+    /// While dispatching, the registration is deferred.
+    fn register_mut(&self, event_identifier: T, listener: RcMutListener<T>) -> ListenerHandle {
+        let registered = Registered::new(listener);
+        let handle = registered.handle;
+
+        if self.is_dispatching() {
+            self.pending
+                .borrow_mut()
+                .push(PendingChange::RegisterMut(event_identifier, registered));
+        } else {
+            self.subscribe_mut(event_identifier, registered);
+        }
+
+        handle
+    }
+
+    /// Appends `listener` to the listeners of `event_identifier`,
+    /// or to the catch-all listeners if there is none.
+    /// While dispatching, the registration is deferred.
+    fn register(&self, event_identifier: Option<T>, listener: RcListener<T>) -> ListenerHandle {
+        let registered = Registered::new(listener);
+        let handle = registered.handle;
+
+        if self.is_dispatching() {
+            self.pending
+                .borrow_mut()
+                .push(PendingChange::Register(event_identifier, registered));
+        } else {
+            self.subscribe(event_identifier, registered);
+        }
+
+        handle
+    }
+
+    /// Calls `on_subscribe` on `registered` and inserts it afterwards,
+    /// leaving the listeners unborrowed while the hook runs.
+    fn subscribe(&self, event_identifier: Option<T>, mut registered: Registered<RcListener<T>>) {
+        registered.listener.subscribe();
+        self.state.borrow_mut().insert(event_identifier, registered);
+    }
+
+    /// Calls `on_subscribe` on `registered` and inserts it afterwards
+    /// as a mutable listener.
+    fn subscribe_mut(&self, event_identifier: T, mut registered: Registered<RcMutListener<T>>) {
+        registered.listener.subscribe();
+        self.state
+            .borrow_mut()
+            .insert_mut(event_identifier, registered);
+    }
+
+    /// Removes the registration identified by `handle` and calls
+    /// `on_unsubscribe` on it once the listeners are no longer borrowed.
+    fn unsubscribe(&self, handle: ListenerHandle) -> bool {
+        let removed = self.state.borrow_mut().remove(handle);
+
+        match removed {
+            Some(removed) => {
+                removed.unsubscribe();
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns whether a dispatch is in progress, i.e. whether the caller
+    /// is a listener currently being dispatched to.
+    /// Listeners can check it to queue an event via [`enqueue_event`]
+    /// instead of dispatching it as nested event, or to skip work that is
+    /// only done outside of dispatches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    /// use std::{cell::Cell, rc::Rc};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Probe,
+    /// }
+    ///
+    /// fn main() {
+    ///     let dispatcher = Rc::new(Dispatcher::<Event>::default());
+    ///     let weak_dispatcher = Rc::downgrade(&dispatcher);
+    ///     let observed = Rc::new(Cell::new(false));
+    ///     let record = Rc::clone(&observed);
+    ///
+    ///     dispatcher.add_fn(Event::Probe, move |_| {
+    ///         record.set(weak_dispatcher.upgrade()?.is_dispatching());
+    ///
+    ///         None
+    ///     });
+    ///
+    ///     assert!(!dispatcher.is_dispatching());
+    ///     dispatcher.dispatch_event(&Event::Probe);
+    ///     assert!(observed.get());
+    ///     assert!(!dispatcher.is_dispatching());
+    /// }
+    /// ```
+    ///
+    /// [`enqueue_event`]: #method.enqueue_event
+    pub fn is_dispatching(&self) -> bool {
+        self.state.try_borrow_mut().is_err()
+    }
+
+    /// Removes the listener or [`FnMut`] registered with `handle`,
+    /// the order of all other listeners stays untouched.
+    /// Returns `false` if `handle` is not registered on this dispatcher,
+    /// e.g. because it has already been removed.
+    /// While dispatching, the removal is deferred until the current event
+    /// has been dispatched and `true` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{rc::Dispatcher, rc::Listener, rc::SyncDispatcherRequest, RwLock};
+    /// use std::rc::Rc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct Widget;
+    ///
+    /// impl Listener<Event> for Widget {
+    ///     fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> { None }
+    /// }
+    ///
+    /// fn main() {
+    ///     let widget = Rc::new(RwLock::new(Widget));
+    ///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let handle = dispatcher.add_listener(Event::EventType, &widget);
+    ///
+    ///     assert!(dispatcher.remove_listener(handle));
+    ///     assert!(!dispatcher.remove_listener(handle));
+    /// }
+    /// ```
+    ///
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    pub fn remove_listener(&self, handle: ListenerHandle) -> bool {
+        if self.is_dispatching() {
+            self.pending
+                .borrow_mut()
+                .push(PendingChange::Remove(handle));
+
+            true
+        } else {
+            self.unsubscribe(handle)
+        }
+    }
+
+    /// Puts the listener or [`FnMut`] registered with `handle` behind
+    /// `filter`, replacing a previous filter: It is only called for events
+    /// passing `filter`, rejected events do not count as invoked.
+    /// Works for every kind of registration but mutable listeners,
+    /// a one-shot listener stays registered until an event passes.
+    /// Returns `false` if `handle` is not registered on this dispatcher.
+    /// While dispatching, the change is deferred until the current event
+    /// has been dispatched and `true` is returned.
+    ///
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    pub fn set_filter<F>(&self, handle: ListenerHandle, filter: F) -> bool
+    where
+        F: Fn(&T) -> bool + 'static,
+    {
+        if self.is_dispatching() {
+            self.pending
+                .borrow_mut()
+                .push(PendingChange::Filter(handle, Box::new(filter)));
+
+            true
+        } else {
+            self.state.borrow_mut().set_filter(handle, Box::new(filter))
+        }
+    }
+
+    /// Removes all listeners and [`FnMut`]s, including catch-all
+    /// and mutable listeners.
+    /// Queued events stay queued.
+    ///
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    pub fn clear(&mut self) {
+        let state = self.state.get_mut();
+
+        let listeners = mem::take(&mut state.events)
+            .into_values()
+            .flatten()
+            .chain(mem::take(&mut state.catch_all));
+
+        for mut registered in listeners {
+            registered.listener.unsubscribe();
+        }
+
+        for listeners in mem::take(&mut state.mut_events).into_values() {
+            for mut registered in listeners {
+                registered.listener.unsubscribe();
+            }
+        }
+    }
+
+    /// Removes all listeners and [`FnMut`]s listening to `event_identifier`
+    /// and returns how many have been removed, including mutable listeners.
+    /// Catch-all listeners are kept.
+    ///
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    pub fn remove_all_listeners_for(&mut self, event_identifier: &T) -> usize {
+        let state = self.state.get_mut();
+        let mut removed = 0;
+
+        for mut registered in state.events.remove(event_identifier).unwrap_or_default() {
+            registered.listener.unsubscribe();
+            removed += 1;
+        }
+
+        for mut registered in state
+            .mut_events
+            .remove(event_identifier)
+            .unwrap_or_default()
+        {
+            registered.listener.unsubscribe();
+            removed += 1;
+        }
+
+        removed
+    }
+
+    /// Keeps only the listeners and [`FnMut`]s listening to
+    /// `event_identifier` whose [`ListenerHandle`] satisfies `predicate`,
+    /// the order of the kept listeners stays untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Redraw,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let toolbar = dispatcher.add_fn(Event::Redraw, |_| None);
+    ///     let document_view = dispatcher.add_fn(Event::Redraw, |_| None);
+    ///
+    ///     dispatcher.retain_listeners(&Event::Redraw, |handle| handle != document_view);
+    ///
+    ///     assert_eq!(dispatcher.dispatch_event_with_result(&Event::Redraw).invoked, 1);
+    ///     assert!(!dispatcher.remove_listener(document_view));
+    ///     assert!(dispatcher.remove_listener(toolbar));
+    /// }
+    /// ```
+    ///
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn retain_listeners<F>(&mut self, event_identifier: &T, mut predicate: F)
+    where
+        F: FnMut(ListenerHandle) -> bool,
+    {
+        let state = self.state.get_mut();
+
+        if let Some(listeners) = state.events.get_mut(event_identifier) {
+            for mut registered in take_rejected(listeners, &mut predicate) {
+                registered.listener.unsubscribe();
+            }
+        }
+
+        if let Some(listeners) = state.mut_events.get_mut(event_identifier) {
+            for mut registered in take_rejected(listeners, &mut predicate) {
+                registered.listener.unsubscribe();
+            }
+        }
+    }
+
+    /// Returns all event-variants with at least one live listener or
+    /// [`FnMut`], mutable listeners included, in arbitrary order.
+    ///
+    /// **Note**: The listeners cannot be inspected while dispatching,
+    /// hence no event-variant is returned when called by a listener.
+    ///
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    pub fn keys(&self) -> impl Iterator<Item = T> {
+        let state = match self.state.try_borrow() {
+            Ok(state) => state,
+            Err(_) => return Vec::new().into_iter(),
+        };
+
+        let keys: Vec<T> = state
+            .events
+            .iter()
+            .filter(|(_, listeners)| {
+                listeners
+                    .iter()
+                    .any(|registered| registered.listener.is_alive())
+            })
+            .map(|(event_identifier, _)| event_identifier)
+            .chain(
+                state
+                    .mut_events
+                    .iter()
+                    .filter(|(event_identifier, listeners)| {
+                        !state.events.contains_key(*event_identifier)
+                            && listeners
+                                .iter()
+                                .any(|registered| registered.listener.is_alive())
+                    })
+                    .map(|(event_identifier, _)| event_identifier),
+            )
+            .cloned()
+            .collect();
+
+        keys.into_iter()
+    }
+
+    /// Returns how many live listeners and [`FnMut`]s listen to
+    /// `event_identifier`, mutable listeners included, catch-all
+    /// listeners excluded.
+    /// Listeners that have been dropped are not counted, even if they
+    /// have not been removed by a dispatch yet.
+    ///
+    /// **Note**: The listeners cannot be inspected while dispatching,
+    /// hence `0` is returned when called by a listener.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{rc::Dispatcher, rc::Listener, rc::SyncDispatcherRequest, RwLock};
+    /// use std::rc::Rc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Redraw,
+    /// }
+    ///
+    /// struct DocumentWindow;
+    ///
+    /// impl Listener<Event> for DocumentWindow {
+    ///     fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let window = Rc::new(RwLock::new(DocumentWindow));
+    ///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.add_listener(Event::Redraw, &window);
+    ///     assert_eq!(dispatcher.listener_count(&Event::Redraw), 1);
+    ///
+    ///     drop(window);
+    ///     assert_eq!(dispatcher.listener_count(&Event::Redraw), 0);
+    ///     assert!(dispatcher.is_empty());
+    /// }
+    /// ```
+    ///
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    pub fn listener_count(&self, event_identifier: &T) -> usize {
+        let state = match self.state.try_borrow() {
+            Ok(state) => state,
+            Err(_) => return 0,
+        };
+
+        state.events.get(event_identifier).map_or(0, |listeners| {
+            count_alive(listeners, |registered| registered.listener.is_alive())
+        }) + state
+            .mut_events
+            .get(event_identifier)
+            .map_or(0, |listeners| {
+                count_alive(listeners, |registered| registered.listener.is_alive())
+            })
+    }
+
+    /// Returns how many live listeners and [`FnMut`]s are registered in
+    /// total, catch-all and mutable listeners included.
+    /// Like [`listener_count`], returns `0` when called by a listener.
+    ///
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    /// [`listener_count`]: #method.listener_count
+    pub fn total_listener_count(&self) -> usize {
+        let state = match self.state.try_borrow() {
+            Ok(state) => state,
+            Err(_) => return 0,
+        };
+
+        state
+            .events
+            .values()
+            .chain(Some(&state.catch_all))
+            .map(|listeners| count_alive(listeners, |registered| registered.listener.is_alive()))
+            .sum::<usize>()
+            + state
+                .mut_events
+                .values()
+                .map(|listeners| {
+                    count_alive(listeners, |registered| registered.listener.is_alive())
+                })
+                .sum::<usize>()
+    }
+
+    /// Returns whether no live listener or [`FnMut`] is registered.
+    /// When called by a listener while dispatching, the calling listener
+    /// is registered, hence `false` is returned.
+    ///
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    pub fn is_empty(&self) -> bool {
+        !self.is_dispatching() && self.total_listener_count() == 0
+    }
+
+    /// Moves all registrations of `other` to this dispatcher, leaving
+    /// `other` without listeners.
+    /// Per event-variant, the listeners of `other` are appended after the
+    /// listeners already registered here, keeping their order.
+    /// Their [`ListenerHandle`]s stay valid and identify them on this
+    /// dispatcher, queued events of `other` stay queued on `other`.
+    /// While dispatching, the listeners of `other` join once the current
+    /// event has been dispatched.
+    ///
+    /// Listeners are moved, not removed and added again, hence neither
+    /// `on_unsubscribe` nor `on_subscribe` are called.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Startup,
+    /// }
+    ///
+    /// fn main() {
+    ///     let app: Dispatcher<Event> = Dispatcher::default();
+    ///     let mut plugin: Dispatcher<Event> = Dispatcher::default();
+    ///     app.add_fn(Event::Startup, |_| None);
+    ///     let plugin_handle = plugin.add_fn(Event::Startup, |_| None);
+    ///
+    ///     app.append(&mut plugin);
+    ///
+    ///     assert_eq!(app.dispatch_event_with_result(&Event::Startup).invoked, 2);
+    ///     assert_eq!(plugin.dispatch_event_with_result(&Event::Startup).invoked, 0);
+    ///     assert!(app.remove_listener(plugin_handle));
+    /// }
+    /// ```
+    ///
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn append(&self, other: &mut Dispatcher<T>) {
+        let other_state = mem::take(other.state.get_mut());
+
+        if self.is_dispatching() {
+            self.pending
+                .borrow_mut()
+                .push(PendingChange::Append(other_state));
+        } else {
+            self.state.borrow_mut().append(other_state);
+        }
+    }
+
+    /// Returns an [`Emitter`] that listeners can capture in order to
+    /// dispatch further events while being dispatched to.
+    ///
+    /// Emitted events are delivered after the current event has reached
+    /// all of its remaining listeners, in emission-order.
+    /// Events emitted by their listeners queue up behind them in turn,
+    /// hence a listener emitting the event it listens to every time will
+    /// never let the dispatch finish.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Click,
+    ///     Redraw,
+    /// }
+    ///
+    /// fn main() {
+    ///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let emitter = dispatcher.emitter();
+    ///
+    ///     dispatcher.add_fn(Event::Click, move |_| {
+    ///         emitter.emit(Event::Redraw);
+    ///
+    ///         None
+    ///     });
+    ///
+    ///     dispatcher.dispatch_event(&Event::Click);
+    /// }
+    /// ```
+    ///
+    /// [`Emitter`]: struct.Emitter.html
+    pub fn emitter(&self) -> Emitter<T> {
+        Emitter {
+            queue: Rc::clone(&self.queue),
+        }
+    }
+
+    /// All [`Listener`]s and [`FnMut`]s listening to a passed
+    /// `event_identifier` will be called in the order they have been added.
+    /// A returned [`SyncDispatcherRequest`] is honoured no matter whether
+    /// it stems from a [`Listener`] or a [`FnMut`].
+    /// Afterwards, events queued by these listeners, e.g. via an
+    /// [`Emitter`], are dispatched. Events queued beforehand stay queued
+    /// for [`process_queue`].
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    /// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
+    /// [`Emitter`]: struct.Emitter.html
+    /// [`process_queue`]: #method.process_queue
+    pub fn dispatch_event(&self, event_identifier: &T) {
+        self.dispatch_event_with_result(event_identifier);
+    }
+
+    /// Dispatches like [`dispatch_event_with_result`], but when called by
+    /// a listener, returns [`DispatchError::MaxDepthExceeded`] instead of
+    /// dropping `event_identifier` silently if the chain of events
+    /// dispatching each other has grown beyond the maximal
+    /// dispatch-depth, see [`set_max_dispatch_depth`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::{DispatchError, Dispatcher};
+    /// use std::{cell::RefCell, rc::Rc};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Ping,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.set_max_dispatch_depth(3);
+    ///
+    ///     let dispatcher = Rc::new(dispatcher);
+    ///     let weak_dispatcher = Rc::downgrade(&dispatcher);
+    ///     let errors = Rc::new(RefCell::new(Vec::new()));
+    ///     let listener_errors = Rc::clone(&errors);
+    ///
+    ///     // Every `Ping` dispatches another `Ping`.
+    ///     dispatcher.add_fn(Event::Ping, move |event| {
+    ///         let dispatcher = weak_dispatcher.upgrade()?;
+    ///
+    ///         if let Err(error) = dispatcher.try_dispatch_event(event) {
+    ///             listener_errors.borrow_mut().push(error);
+    ///         }
+    ///
+    ///         None
+    ///     });
+    ///
+    ///     assert!(dispatcher.try_dispatch_event(&Event::Ping).is_ok());
+    ///     assert_eq!(*errors.borrow(), [DispatchError::MaxDepthExceeded]);
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event_with_result`]: #method.dispatch_event_with_result
+    /// [`DispatchError::MaxDepthExceeded`]: enum.DispatchError.html#variant.MaxDepthExceeded
+    /// [`set_max_dispatch_depth`]: #method.set_max_dispatch_depth
+    pub fn try_dispatch_event(
+        &self,
+        event_identifier: &T,
+    ) -> Result<DispatchResult, DispatchError> {
+        if self.is_dispatching() {
+            self.queue
+                .push(event_identifier.clone(), DEFAULT_PRIORITY)?;
+
+            return Ok(DispatchResult::default());
+        }
+
+        self.queue.immediate.set(true);
+        let dispatch_result = self.dispatch_to(event_identifier, 0).unwrap_or_default();
+        self.process_immediate();
+        self.queue.immediate.set(false);
+
+        Ok(dispatch_result)
+    }
+
+    /// Dispatches the events queued while an immediate dispatch is
+    /// ongoing in queue-order, including events they queue in turn.
+    /// Events queued beforehand stay queued.
+    fn process_immediate(&self) {
+        loop {
+            let queued_event = {
+                let mut events = self.queue.events.borrow_mut();
+
+                events
+                    .iter()
+                    .position(|queued| queued.immediate)
+                    .and_then(|index| events.remove(index))
+            };
+
+            match queued_event {
+                Some(queued_event) => {
+                    self.dispatch_to(&queued_event.event, queued_event.depth);
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// Sets how deep events dispatched or emitted by listeners may nest,
+    /// defaulting to [`DEFAULT_MAX_DISPATCH_DEPTH`].
+    /// An event dispatched from outside of a listener has a depth of `0`,
+    /// an event dispatched while dispatching an event of depth `n` has a
+    /// depth of `n + 1`.
+    /// Events exceeding `depth` are refused, stopping listeners that keep
+    /// dispatching each other's events from looping forever.
+    /// The depth starts over once the outermost dispatch returns.
+    ///
+    /// [`DEFAULT_MAX_DISPATCH_DEPTH`]: constant.DEFAULT_MAX_DISPATCH_DEPTH.html
+    pub fn set_max_dispatch_depth(&mut self, depth: usize) {
+        self.queue.max_depth.set(depth);
+    }
+
+    /// Returns the maximal dispatch-depth, see [`set_max_dispatch_depth`].
+    ///
+    /// [`set_max_dispatch_depth`]: #method.set_max_dispatch_depth
+    pub fn max_dispatch_depth(&self) -> usize {
+        self.queue.max_depth.get()
+    }
+
+    /// All [`MutListener`]s and [`FnMut`]s added via [`add_mut_listener`]
+    /// and [`add_mut_fn`] for `event_identifier` will be called in the
+    /// order they have been added, each receiving `event_identifier`
+    /// mutably, e.g. to contribute entries to a list carried by the event.
+    /// Propagation stops and listeners are removed the same way as in
+    /// [`dispatch_event`].
+    /// Listeners added via [`add_listener`] and the like are not called.
+    /// Afterwards, events queued by these listeners, e.g. via an
+    /// [`Emitter`], are dispatched.
+    ///
+    /// **Note**: Listeners are looked up by the event before any of them
+    /// is called, changing the event does not reroute it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// #[derive(Clone)]
+    /// enum Event {
+    ///     ContextMenu(Vec<&'static str>),
+    /// }
+    ///
+    /// impl Hash for Event {
+    ///     fn hash<H: Hasher>(&self, _state: &mut H) {}
+    /// }
+    ///
+    /// impl PartialEq for Event {
+    ///     fn eq(&self, _other: &Event) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// impl Eq for Event {}
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///
+    ///     dispatcher.add_mut_fn(Event::ContextMenu(Vec::new()), |event| {
+    ///         let Event::ContextMenu(entries) = event;
+    ///         entries.push("Copy");
+    ///
+    ///         None
+    ///     });
+    ///
+    ///     let mut event = Event::ContextMenu(Vec::new());
+    ///     dispatcher.dispatch_event_mut(&mut event);
+    ///
+    ///     let Event::ContextMenu(entries) = event;
+    ///     assert_eq!(entries, ["Copy"]);
+    /// }
+    /// ```
+    ///
+    /// [`MutListener`]: trait.MutListener.html
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    /// [`add_mut_listener`]: #method.add_mut_listener
+    /// [`add_mut_fn`]: #method.add_mut_fn
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`add_listener`]: #method.add_listener
+    /// [`Emitter`]: struct.Emitter.html
+    pub fn dispatch_event_mut(&mut self, event_identifier: &mut T) {
+        let state = self.state.get_mut();
+
+        if let Some(listeners) = state.mut_events.get_mut(event_identifier) {
+            let mut dispatch_result = DispatchResult::default();
+
+            self.queue.depth.set(1);
+            self.queue.immediate.set(true);
+            dispatch_mut_to_listeners(listeners, event_identifier, &mut dispatch_result);
+            self.queue.depth.set(0);
+        }
+
+        self.process_immediate();
+        self.queue.immediate.set(false);
+    }
+
+    /// Dispatches like [`dispatch_event`] and returns how many listeners
+    /// have been called and whether one stopped propagation.
+    /// The result only covers `event_identifier`, not emitted events.
+    ///
+    /// **Note**: When called by a listener, `event_identifier` is queued,
+    /// an empty result is returned and `event_identifier` is dropped if
+    /// it exceeds the maximal dispatch-depth, see [`try_dispatch_event`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Routed,
+    ///     Unrouted,
+    /// }
+    ///
+    /// fn main() {
+    ///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.add_fn(Event::Routed, |_| None);
+    ///
+    ///     assert_eq!(dispatcher.dispatch_event_with_result(&Event::Routed).invoked, 1);
+    ///     assert_eq!(dispatcher.dispatch_event_with_result(&Event::Unrouted).invoked, 0);
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`try_dispatch_event`]: #method.try_dispatch_event
+    pub fn dispatch_event_with_result(&self, event_identifier: &T) -> DispatchResult {
+        self.try_dispatch_event(event_identifier)
+            .unwrap_or_default()
+    }
+
+    /// Queues `event` to be dispatched by the next [`process_queue`] or
+    /// [`process_queue_max`].
+    /// Dispatching another event in the meantime leaves `event` queued.
+    /// This is the counterpart of [`Emitter::emit`] for producers
+    /// owning the dispatcher, both share the same queue.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Moved,
+    /// }
+    ///
+    /// fn main() {
+    ///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.add_fn(Event::Moved, |_| None);
+    ///
+    ///     dispatcher.enqueue_event(Event::Moved);
+    ///     dispatcher.enqueue_event(Event::Moved);
+    ///     assert_eq!(dispatcher.queued_len(), 2);
+    ///
+    ///     dispatcher.process_queue();
+    ///     assert_eq!(dispatcher.queued_len(), 0);
+    /// }
+    /// ```
+    ///
+    /// [`process_queue`]: #method.process_queue
+    /// [`process_queue_max`]: #method.process_queue_max
+    /// [`Emitter::emit`]: struct.Emitter.html#method.emit
+    pub fn enqueue_event(&self, event: T) {
+        let _ = self.queue.push(event, DEFAULT_PRIORITY);
+    }
+
+    /// Queues `event` like [`enqueue_event`], but ahead of all queued
+    /// events of a lower `priority`, e.g. for errors that must not wait
+    /// behind routine events.
+    /// The queue is processed by descending priority and in FIFO-order
+    /// among events of the same priority. Events queued without a
+    /// priority, be it by [`enqueue_event`], an [`Emitter`] or a listener
+    /// dispatching, have the lowest priority `0`.
+    ///
+    /// **Note**: Events of a low priority wait as long as events of a
+    /// higher priority keep being queued, they may starve.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    /// use std::{cell::RefCell, rc::Rc};
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    ///     Disconnected,
+    /// }
+    ///
+    /// fn main() {
+    ///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let record = Rc::new(RefCell::new(Vec::new()));
+    ///
+    ///     for event in vec![Event::Tick, Event::Disconnected] {
+    ///         let record = Rc::clone(&record);
+    ///         dispatcher.add_fn(event, move |event| {
+    ///             record.borrow_mut().push(event.clone());
+    ///
+    ///             None
+    ///         });
+    ///     }
+    ///
+    ///     dispatcher.enqueue_event(Event::Tick);
+    ///     dispatcher.enqueue_event_with_priority(Event::Disconnected, 9);
+    ///     dispatcher.process_queue();
+    ///
+    ///     assert_eq!(*record.borrow(), [Event::Disconnected, Event::Tick]);
+    /// }
+    /// ```
+    ///
+    /// [`enqueue_event`]: #method.enqueue_event
+    /// [`Emitter`]: struct.Emitter.html
+    pub fn enqueue_event_with_priority(&self, event: T, priority: u8) {
+        let _ = self.queue.push(event, priority);
+    }
+
+    /// Queues `event` like [`enqueue_event`], but hands it back via
+    /// [`EnqueueError::QueueFull`] instead of dropping it if the queue is
+    /// full under `OverflowPolicy::ReturnError`.
+    /// Under other policies, full queues make room like [`enqueue_event`],
+    /// an `event` dropped nevertheless is handed back as well.
+    /// An `event` exceeding the maximal dispatch-depth is handed back via
+    /// [`EnqueueError::MaxDepthExceeded`].
+    ///
+    /// [`enqueue_event`]: #method.enqueue_event
+    /// [`EnqueueError::QueueFull`]: enum.EnqueueError.html#variant.QueueFull
+    /// [`EnqueueError::MaxDepthExceeded`]: enum.EnqueueError.html#variant.MaxDepthExceeded
+    pub fn try_enqueue_event(&self, event: T) -> Result<(), EnqueueError<T>> {
+        if self.queue.depth.get() > self.queue.max_depth.get() {
+            return Err(EnqueueError::MaxDepthExceeded(event));
+        }
+
+        self.queue
+            .push_bounded(event, DEFAULT_PRIORITY)
+            .map_err(EnqueueError::QueueFull)
+    }
+
+    /// Dispatches queued events in FIFO-order until the queue is empty,
+    /// including events queued by listeners while processing.
+    /// Events queued with a priority are dispatched ahead of lower ones,
+    /// see [`enqueue_event_with_priority`].
+    ///
+    /// [`enqueue_event_with_priority`]: #method.enqueue_event_with_priority
+    pub fn process_queue(&self) {
+        self.process_queue_max(usize::MAX);
+    }
+
+    /// Dispatches up to `max_events` queued events in FIFO-order and
+    /// returns how many have been dispatched.
+    /// Events queued by listeners while processing count towards
+    /// `max_events`, remaining events stay queued.
+    pub fn process_queue_max(&self, max_events: usize) -> usize {
+        let mut processed = 0;
+
+        if self.state.try_borrow_mut().is_err() {
+            return processed;
+        }
+
+        while processed < max_events {
+            let queued_event = self.queue.events.borrow_mut().pop_front();
+
+            match queued_event {
+                Some(queued_event) => {
+                    self.dispatch_to(&queued_event.event, queued_event.depth);
+                    processed += 1;
+                }
+                None => break,
+            }
+        }
+
+        processed
+    }
+
+    /// Coalesces events equal to `event_identifier` in the queue according
+    /// to `coalesce`, replacing a previous setting for it.
+    /// Events are compared like listeners are looked up, by [`PartialEq`],
+    /// when they are queued, be it by [`enqueue_event`], an [`Emitter`]
+    /// or a listener dispatching. Hence at most one such event is queued
+    /// at a time, all other events queue as usual.
+    ///
+    /// **Note**: The coalesced event keeps the position of the first
+    /// queued one, even if [`Coalesce::KeepLast`] replaces it, unless the
+    /// replacing event has a higher priority.
+    /// Queueing a coalescing event searches the queue for an equal one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::{Coalesce, Dispatcher};
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// #[derive(Clone, Debug)]
+    /// enum Event {
+    ///     Resized(u32),
+    ///     Clicked,
+    /// }
+    ///
+    /// impl Hash for Event {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         std::mem::discriminant(self).hash(state);
+    ///     }
+    /// }
+    ///
+    /// impl PartialEq for Event {
+    ///     fn eq(&self, other: &Event) -> bool {
+    ///         std::mem::discriminant(self) == std::mem::discriminant(other)
+    ///     }
+    /// }
+    ///
+    /// impl Eq for Event {}
+    ///
+    /// fn main() {
+    ///     let dispatcher = Dispatcher::default();
+    ///     dispatcher.set_coalescing(Event::Resized(0), Coalesce::KeepLast);
+    ///
+    ///     for width in 1..=300 {
+    ///         dispatcher.enqueue_event(Event::Resized(width));
+    ///     }
+    ///     dispatcher.enqueue_event(Event::Clicked);
+    ///
+    ///     assert_eq!(dispatcher.queued_len(), 2);
+    /// }
+    /// ```
+    ///
+    /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
+    /// [`enqueue_event`]: #method.enqueue_event
+    /// [`Emitter`]: struct.Emitter.html
+    /// [`Coalesce::KeepLast`]: enum.Coalesce.html#variant.KeepLast
+    pub fn set_coalescing(&self, event_identifier: T, coalesce: Coalesce) {
+        self.queue
+            .coalescing
+            .borrow_mut()
+            .insert(event_identifier, coalesce);
+    }
+
+    /// Stops coalescing events equal to `event_identifier` and returns how
+    /// they have been coalesced, `None` if they have not been.
+    /// Already queued events stay queued.
+    pub fn remove_coalescing(&self, event_identifier: &T) -> Option<Coalesce> {
+        self.queue.coalescing.borrow_mut().remove(event_identifier)
+    }
+
+    /// Returns how many events are queued.
+    pub fn queued_len(&self) -> usize {
+        self.queue.events.borrow().len()
+    }
+
+    /// Returns how many events the queue holds at most,
+    /// `None` if it is unbounded.
+    pub fn capacity(&self) -> Option<usize> {
+        self.queue.capacity
+    }
+
+    /// Drops all queued events without dispatching them.
+    pub fn clear_queue(&self) {
+        self.queue.events.borrow_mut().clear();
+    }
+
+    /// Removes all queued events and returns them in dispatch-order, e.g. to
+    /// persist them and put them back in place later via [`set_queue`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Autosave,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.enqueue_event(Event::Autosave);
+    ///
+    ///     let saved_queue = dispatcher.take_queue();
+    ///     assert_eq!(dispatcher.queued_len(), 0);
+    ///
+    ///     let mut loaded: Dispatcher<Event> = Dispatcher::default();
+    ///     loaded.set_queue(saved_queue);
+    ///     assert_eq!(loaded.queued_len(), 1);
+    /// }
+    /// ```
+    ///
+    /// [`set_queue`]: #method.set_queue
+    pub fn take_queue(&mut self) -> VecDeque<T> {
+        mem::take(&mut *self.queue.events.borrow_mut())
+            .into_iter()
+            .map(|queued| queued.event)
+            .collect()
+    }
+
+    /// Replaces all queued events with `queue`, to be dispatched in
+    /// FIFO-order by the next [`process_queue`] or
+    /// [`process_queue_max`].
+    /// The events start over at a dispatch-depth of `0` and the lowest
+    /// priority.
+    /// Events of `queue` beyond the capacity are dropped according to
+    /// the overflow-policy, see [`with_queue_capacity`].
+    ///
+    /// [`process_queue`]: #method.process_queue
+    /// [`process_queue_max`]: #method.process_queue_max
+    /// [`with_queue_capacity`]: #method.with_queue_capacity
+    pub fn set_queue(&mut self, queue: VecDeque<T>) {
+        self.queue.events.borrow_mut().clear();
+
+        for event in queue {
+            let _ = self.queue.push_bounded(event, DEFAULT_PRIORITY);
+        }
+    }
+
+    /// Treats the queue as a double-buffer, e.g. for a game loop: Dispatches
+    /// exactly the events queued so far in FIFO-order, the front buffer,
+    /// while events queued meanwhile, be it by [`enqueue_event`], an
+    /// [`Emitter`] or a listener dispatching, wait in the back buffer for
+    /// the next call.
+    /// Hence listeners never observe the consequences of an event within
+    /// the same frame. Returns how many events have been dispatched.
+    ///
+    /// Every frame starts over at a dispatch-depth of `0`, an event
+    /// re-emitting itself each frame is not refused for being too deep.
+    ///
+    /// **Note**: Returns `0` without dispatching anything when called by
+    /// a listener.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Collision,
+    ///     Explosion,
+    /// }
+    ///
+    /// fn main() {
+    ///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let emitter = dispatcher.emitter();
+    ///
+    ///     dispatcher.add_fn(Event::Collision, move |_| {
+    ///         emitter.emit(Event::Explosion);
+    ///
+    ///         None
+    ///     });
+    ///
+    ///     dispatcher.enqueue_event(Event::Collision);
+    ///
+    ///     assert_eq!(dispatcher.swap_and_dispatch(), 1);
+    ///     assert_eq!(dispatcher.queued_len(), 1);
+    ///     assert_eq!(dispatcher.swap_and_dispatch(), 1);
+    ///     assert_eq!(dispatcher.queued_len(), 0);
+    /// }
+    /// ```
+    ///
+    /// [`enqueue_event`]: #method.enqueue_event
+    /// [`Emitter`]: struct.Emitter.html
+    pub fn swap_and_dispatch(&self) -> usize {
+        let (dispatched, _) = self.dispatch_front_buffer();
+
+        dispatched
+    }
+
+    /// Takes all events queued so far and dispatches them in FIFO-order
+    /// like [`swap_and_dispatch`], but returns how many listeners have
+    /// been invoked in total.
+    /// Events queued meanwhile wait for the next call, hence a listener
+    /// queuing events cannot keep a single call busy forever.
+    ///
+    /// **Note**: Returns `0` without dispatching anything when called by
+    /// a listener.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// fn main() {
+    ///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let emitter = dispatcher.emitter();
+    ///
+    ///     dispatcher.add_fn(Event::Tick, move |_| {
+    ///         emitter.emit(Event::Tick);
+    ///
+    ///         None
+    ///     });
+    ///     dispatcher.add_fn(Event::Tick, |_| None);
+    ///
+    ///     dispatcher.enqueue_event(Event::Tick);
+    ///
+    ///     assert_eq!(dispatcher.drain_and_dispatch(), 2);
+    ///     assert_eq!(dispatcher.queued_len(), 1);
+    /// }
+    /// ```
+    ///
+    /// [`swap_and_dispatch`]: #method.swap_and_dispatch
+    pub fn drain_and_dispatch(&self) -> usize {
+        let (_, invoked) = self.dispatch_front_buffer();
+
+        invoked
+    }
+
+    /// Dispatches the events queued so far at a dispatch-depth of `0`,
+    /// leaving events queued meanwhile in place.
+    /// Returns how many events have been dispatched and how many
+    /// listeners have been invoked in total.
+    fn dispatch_front_buffer(&self) -> (usize, usize) {
+        if self.is_dispatching() {
+            return (0, 0);
+        }
+
+        let front_buffer = mem::take(&mut *self.queue.events.borrow_mut());
+        let mut invoked = 0;
+
+        for queued in &front_buffer {
+            if let Some(dispatch_result) = self.dispatch_to(&queued.event, 0) {
+                invoked += dispatch_result.invoked;
+            }
+        }
+
+        (front_buffer.len(), invoked)
+    }
+
+    /// Dispatches every event of `log` at a dispatch-depth of `0`, dropping
+    /// events queued meanwhile and keeping the events queued before.
+    /// Returns how many events have been dispatched.
+    pub(crate) fn replay<'a, I>(&self, log: I) -> usize
+    where
+        I: IntoIterator<Item = &'a T>,
+    {
+        if self.is_dispatching() {
+            return 0;
+        }
+
+        let queued = mem::take(&mut *self.queue.events.borrow_mut());
+        let mut replayed = 0;
+
+        for event_identifier in log {
+            self.dispatch_to(event_identifier, 0);
+            self.queue.events.borrow_mut().clear();
+            replayed += 1;
+        }
+
+        *self.queue.events.borrow_mut() = queued;
+
+        replayed
+    }
+
+    /// Dispatches `event_identifier` at dispatch-`depth`, ignoring the
+    /// queue, and applies changes requested by listeners meanwhile.
+    /// Returns `None` if the dispatcher is already dispatching.
+    fn dispatch_to(&self, event_identifier: &T, depth: usize) -> Option<DispatchResult> {
+        let mut state = self.state.try_borrow_mut().ok()?;
+
+        self.queue.depth.set(depth + 1);
+        let dispatch_result = state.dispatch(event_identifier);
+        self.queue.depth.set(0);
+        drop(state);
+
+        let pending_changes = mem::take(&mut *self.pending.borrow_mut());
+
+        for change in pending_changes {
+            match change {
+                PendingChange::Register(event_identifier, registered) => {
+                    self.subscribe(event_identifier, registered)
+                }
+                PendingChange::RegisterMut(event_identifier, registered) => {
+                    self.subscribe_mut(event_identifier, registered)
+                }
+                PendingChange::Remove(handle) => {
+                    self.unsubscribe(handle);
+                }
+                PendingChange::Filter(handle, filter) => {
+                    self.state.borrow_mut().set_filter(handle, filter);
+                }
+                PendingChange::Append(other_state) => {
+                    self.state.borrow_mut().append(other_state);
+                }
             }
         }
+
+        Some(dispatch_result)
+    }
+}
+
+/// Registers every `(event_identifier, listener)`-pair in iteration order,
+/// as if [`add_listener`] was called for each of them.
+///
+/// **Note**: The dispatcher only keeps [`Weak`]-references, the listeners
+/// need to be owned elsewhere in order to be dispatched to.
+///
+/// [`add_listener`]: struct.Dispatcher.html#method.add_listener
+/// [`Weak`]: https://doc.rust-lang.org/std/rc/struct.Weak.html
+impl<T> Extend<(T, Rc<RwLock<dyn Listener<T> + 'static>>)> for Dispatcher<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (T, Rc<RwLock<dyn Listener<T> + 'static>>)>,
+    {
+        for (event_identifier, listener) in iter {
+            self.register(
+                Some(event_identifier),
+                RcListener::Trait(Rc::downgrade(&listener)),
+            );
+        }
+    }
+}
+
+/// Creates a dispatcher from `(event_identifier, listener)`-pairs,
+/// registered in iteration order like via `Extend`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::{rc::Dispatcher, rc::Listener, rc::SyncDispatcherRequest, RwLock};
+/// use std::rc::Rc;
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Resize,
+///     Redraw,
+/// }
+///
+/// struct Widget;
+///
+/// impl Listener<Event> for Widget {
+///     fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+///         None
+///     }
+/// }
+///
+/// fn main() {
+///     let widget: Rc<RwLock<dyn Listener<Event>>> = Rc::new(RwLock::new(Widget));
+///     let plan = vec![
+///         (Event::Resize, Rc::clone(&widget)),
+///         (Event::Redraw, Rc::clone(&widget)),
+///     ];
+///
+///     let dispatcher: Dispatcher<Event> = plan.into_iter().collect();
+///
+///     assert_eq!(dispatcher.dispatch_event_with_result(&Event::Redraw).invoked, 1);
+/// }
+/// ```
+impl<T> FromIterator<(T, Rc<RwLock<dyn Listener<T> + 'static>>)> for Dispatcher<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (T, Rc<RwLock<dyn Listener<T> + 'static>>)>,
+    {
+        let mut dispatcher = Dispatcher::default();
+        dispatcher.extend(iter);
+
+        dispatcher
     }
 }
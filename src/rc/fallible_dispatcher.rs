@@ -0,0 +1,214 @@
+use super::{
+    execute_sync_dispatcher_requests, FallibleListener, ListenerFailure, ListenerHandle,
+    Registered, RwLock, SyncDispatcherRequest,
+};
+use crate::sync::remove_registered;
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    rc::{Rc, Weak},
+};
+
+type FallibleFn<T, E> =
+    Box<dyn FnMut(&T) -> Result<Option<SyncDispatcherRequest>, ListenerFailure<E>>>;
+type FallibleListeners<T, E> = Vec<Registered<FallibleEntry<T, E>>>;
+
+/// Either a [`Weak`]-reference to a [`FallibleListener`] or an owned closure.
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/rc/struct.Weak.html
+/// [`FallibleListener`]: trait.FallibleListener.html
+enum FallibleEntry<T, E>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    Trait(Weak<RwLock<dyn FallibleListener<T, E> + 'static>>),
+    Fn(FallibleFn<T, E>),
+}
+
+/// In charge of !Sync dispatching to listeners that may fail with an
+/// error `E`.
+/// Owns a map event-variants and [`Weak`]-references to their
+/// [`FallibleListener`]s and/or owns [`FnMut`]s.
+///
+/// Opposed to [`Dispatcher`], a failing listener does not end the
+/// dispatch, instead all errors are collected and handed back by
+/// [`dispatch_event_fallible`].
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/rc/struct.Weak.html
+/// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+/// [`FallibleListener`]: trait.FallibleListener.html
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`dispatch_event_fallible`]: #method.dispatch_event_fallible
+pub struct FallibleDispatcher<T, E>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    events: HashMap<T, FallibleListeners<T, E>>,
+}
+
+impl<T, E> Default for FallibleDispatcher<T, E>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    fn default() -> FallibleDispatcher<T, E> {
+        FallibleDispatcher {
+            events: HashMap::new(),
+        }
+    }
+}
+
+impl<T, E> FallibleDispatcher<T, E>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    /// Adds a [`FallibleListener`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// [`FallibleListener`]: trait.FallibleListener.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_listener<D>(
+        &mut self,
+        event_identifier: T,
+        listener: &Rc<RwLock<D>>,
+    ) -> ListenerHandle
+    where
+        D: FallibleListener<T, E> + 'static,
+    {
+        self.register(
+            event_identifier,
+            FallibleEntry::Trait(Rc::downgrade(
+                &(Rc::clone(listener) as Rc<RwLock<dyn FallibleListener<T, E> + 'static>>),
+            )),
+        )
+    }
+
+    /// Adds a [`FnMut`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_fn<F>(&mut self, event_identifier: T, function: F) -> ListenerHandle
+    where
+        F: FnMut(&T) -> Result<Option<SyncDispatcherRequest>, ListenerFailure<E>> + 'static,
+    {
+        self.register(event_identifier, FallibleEntry::Fn(Box::new(function)))
+    }
+
+    /// Removes the listener or [`FnMut`] registered with `handle`,
+    /// the order of all other listeners stays untouched.
+    /// Returns `false` if `handle` is not registered on this dispatcher.
+    ///
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    pub fn remove_listener(&mut self, handle: ListenerHandle) -> bool {
+        self.events
+            .values_mut()
+            .any(|listeners| remove_registered(listeners, handle))
+    }
+
+    /// All [`FallibleListener`]s and [`FnMut`]s listening to a passed
+    /// `event_identifier` will be called in the order they have been
+    /// added, no matter whether listeners before them failed.
+    /// Returns the errors of all failed listeners in calling-order.
+    ///
+    /// A request is honoured no matter whether the listener succeeded or
+    /// failed along with it via [`ListenerFailure::with_request`], hence a
+    /// failing listener stopping propagation does end the dispatch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::{FallibleDispatcher, ListenerFailure, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Command {
+    ///     Save,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: FallibleDispatcher<Command, String> = FallibleDispatcher::default();
+    ///
+    ///     dispatcher.add_fn(Command::Save, |_| Err("title is empty".to_string().into()));
+    ///     dispatcher.add_fn(Command::Save, |_| {
+    ///         Err(ListenerFailure::with_request(
+    ///             "plugin crashed".to_string(),
+    ///             SyncDispatcherRequest::StopListening,
+    ///         ))
+    ///     });
+    ///
+    ///     assert_eq!(
+    ///         dispatcher.dispatch_event_fallible(&Command::Save),
+    ///         Err(vec!["title is empty".to_string(), "plugin crashed".to_string()])
+    ///     );
+    ///     assert_eq!(
+    ///         dispatcher.dispatch_event_fallible(&Command::Save),
+    ///         Err(vec!["title is empty".to_string()])
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// [`FallibleListener`]: trait.FallibleListener.html
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    /// [`ListenerFailure::with_request`]: struct.ListenerFailure.html#method.with_request
+    pub fn dispatch_event_fallible(&mut self, event_identifier: &T) -> Result<(), Vec<E>> {
+        let mut errors = Vec::new();
+
+        if let Some(listeners) = self.events.get_mut(event_identifier) {
+            let mut found_invalid_weak_ref = false;
+
+            execute_sync_dispatcher_requests(listeners, |registered| {
+                let outcome = match &mut registered.listener {
+                    FallibleEntry::Trait(weak_listener) => {
+                        if let Some(listener) = weak_listener.upgrade() {
+                            let mut listener = listener.write();
+                            listener.on_event(event_identifier)
+                        } else {
+                            found_invalid_weak_ref = true;
+
+                            return None;
+                        }
+                    }
+                    FallibleEntry::Fn(callback) => callback(event_identifier),
+                };
+
+                match outcome {
+                    Ok(request) => request,
+                    Err(failure) => {
+                        errors.push(failure.error);
+
+                        failure.request
+                    }
+                }
+            });
+
+            if found_invalid_weak_ref {
+                listeners.retain(|registered| match &registered.listener {
+                    FallibleEntry::Trait(weak_listener) => weak_listener.strong_count() > 0,
+                    FallibleEntry::Fn(_) => true,
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Appends `listener` to the listeners of `event_identifier`.
+    fn register(&mut self, event_identifier: T, listener: FallibleEntry<T, E>) -> ListenerHandle {
+        let registered = Registered::new(listener);
+        let handle = registered.handle;
+
+        self.events
+            .entry(event_identifier)
+            .or_default()
+            .push(registered);
+
+        handle
+    }
+}
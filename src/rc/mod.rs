@@ -1,25 +1,135 @@
-pub use super::{sync::Listener, sync::SyncDispatcherRequest};
-use std::{collections::HashMap, hash::Hash, rc::Weak};
+pub use super::sync::{
+    DispatchError, DispatchResult, Listener, ListenerHandle, SyncDispatcherRequest,
+    DEFAULT_MAX_DISPATCH_DEPTH,
+};
+use std::{collections::HashMap, hash::Hash, mem, rc::Weak};
 
-use super::RwLock;
+use super::{
+    sync::{removes_listener, ExecuteRequestsResult, Registered},
+    RwLock,
+};
+pub mod context_dispatcher;
 pub mod dispatcher;
+pub mod fallible_dispatcher;
 pub mod priority_dispatcher;
+pub mod recording;
+pub mod scheduled_dispatcher;
 
-pub use dispatcher::Dispatcher;
+pub use context_dispatcher::ContextDispatcher;
+pub use dispatcher::{Coalesce, Dispatcher, Emitter, EnqueueError, OverflowPolicy};
+pub use fallible_dispatcher::FallibleDispatcher;
 pub use priority_dispatcher::PriorityDispatcher;
+pub use recording::{replay_filtered_into, replay_into, RecordEntry, Recorder};
+pub use scheduled_dispatcher::{ScheduleHandle, ScheduledDispatcher};
 
-type EventFunction<T> = Vec<Box<dyn Fn(&T) -> Option<SyncDispatcherRequest>>>;
-type ListenerMap<T> = HashMap<T, FnsAndTraits<T>>;
+/// The requests an `rc` listener can return to its dispatcher, the very
+/// same as [`SyncDispatcherRequest`] and honoured the same way by
+/// [`Dispatcher`] and [`PriorityDispatcher`]:
+/// `StopListening`, `StopPropagation`, `StopListeningAndPropagation`,
+/// and `Handled`.
+///
+/// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
+/// [`Dispatcher`]: dispatcher/struct.Dispatcher.html
+/// [`PriorityDispatcher`]: priority_dispatcher/struct.PriorityDispatcher.html
+pub type RcDispatcherRequest = SyncDispatcherRequest;
+
+/// Every event-receiver of a [`ContextDispatcher`] needs to implement
+/// this trait, `C` being the context lent to the listener while
+/// dispatching, e.g. the application's state.
+///
+/// [`ContextDispatcher`]: context_dispatcher/struct.ContextDispatcher.html
+pub trait ContextListener<T, C>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    /// This function will be called once a listened
+    /// event-type `T` has been dispatched along with `context`.
+    fn on_event(&mut self, event: &T, context: &mut C) -> Option<SyncDispatcherRequest>;
+}
 
-/// When `execute_sync_dispatcher_requests` returns,
-/// this `enum` informs on whether the return is early
-/// and thus forcefully stopped or finished on its own.
+/// Every event-receiver of a [`FallibleDispatcher`] needs to implement
+/// this trait, failing with a [`ListenerFailure`] carrying an error `E`.
+///
+/// [`FallibleDispatcher`]: fallible_dispatcher/struct.FallibleDispatcher.html
+/// [`ListenerFailure`]: struct.ListenerFailure.html
+pub trait FallibleListener<T, E>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    /// This function will be called once a listened
+    /// event-type `T` has been dispatched.
+    fn on_event(&mut self, event: &T) -> Result<Option<SyncDispatcherRequest>, ListenerFailure<E>>;
+}
+
+/// The failure of a [`FallibleListener`], an `error` along with the
+/// `request` the listener has for its dispatcher nonetheless, e.g. to
+/// stop listening.
+///
+/// Any `E` converts into a failure without request, hence `?` can be
+/// used on `E` within [`FallibleListener::on_event`].
+///
+/// [`FallibleListener`]: trait.FallibleListener.html
+/// [`FallibleListener::on_event`]: trait.FallibleListener.html#tymethod.on_event
 #[derive(Debug)]
-pub(crate) enum ExecuteRequestsResult {
-    Finished,
-    Stopped,
+pub struct ListenerFailure<E> {
+    pub error: E,
+    pub request: Option<SyncDispatcherRequest>,
+}
+
+impl<E> ListenerFailure<E> {
+    /// Creates a failure carrying `error` and `request`.
+    pub fn with_request(error: E, request: SyncDispatcherRequest) -> Self {
+        ListenerFailure {
+            error,
+            request: Some(request),
+        }
+    }
+}
+
+impl<E> From<E> for ListenerFailure<E> {
+    fn from(error: E) -> Self {
+        ListenerFailure {
+            error,
+            request: None,
+        }
+    }
+}
+
+/// Every event-receiver of [`Dispatcher::dispatch_event_mut`] needs to
+/// implement this trait, receiving the event mutably in order to write
+/// results into it.
+///
+/// [`Dispatcher::dispatch_event_mut`]: dispatcher/struct.Dispatcher.html#method.dispatch_event_mut
+pub trait MutListener<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    /// This function will be called once a listened
+    /// event-type `T` has been dispatched mutably.
+    fn on_event_mut(&mut self, event: &mut T) -> Option<SyncDispatcherRequest>;
+
+    /// Called once the listener has been registered, see
+    /// [`Listener::on_subscribe`].
+    ///
+    /// [`Listener::on_subscribe`]: trait.Listener.html#method.on_subscribe
+    fn on_subscribe(&mut self) {}
+
+    /// Called once a registration of the listener has been removed, see
+    /// [`Listener::on_unsubscribe`].
+    ///
+    /// [`Listener::on_unsubscribe`]: trait.Listener.html#method.on_unsubscribe
+    fn on_unsubscribe(&mut self) {}
 }
 
+type RcFn<T> = Box<dyn FnMut(&T) -> Option<SyncDispatcherRequest>>;
+type RcFnOnce<T> = Box<dyn FnOnce(&T) -> Option<SyncDispatcherRequest>>;
+type RcMutFn<T> = Box<dyn FnMut(&mut T) -> Option<SyncDispatcherRequest>>;
+type RcFilter<T> = Box<dyn Fn(&T) -> bool>;
+type Listeners<T> = Vec<Registered<RcListener<T>>>;
+type ListenerMap<T> = HashMap<T, Listeners<T>>;
+type MutListeners<T> = Vec<Registered<RcMutListener<T>>>;
+type MutListenerMap<T> = HashMap<T, MutListeners<T>>;
+
 /// Iterates over the passed `vec` and applies `function` to each element.
 /// `function`'s returned [`SyncDispatcherRequest`] will instruct
 /// a procedure depending on its variant:
@@ -29,10 +139,10 @@ pub(crate) enum ExecuteRequestsResult {
 /// in `vec`.
 /// `StopListeningAndPropagation`: Execute `StopListening`,
 /// then execute `StopPropagation`.
+/// `Handled`: Same as `StopPropagation`.
 ///
 /// **Note**: When `StopListening` is being executed,
-/// removal of items from `vec` will result use a swap of elements,
-/// resulting in an alteration of the order items were originally
+/// the remaining items keep the order they were originally
 /// inserted into `vec`.
 ///
 /// **Note**: Unlike [`retain`], `execute_sync_dispatcher_requests`
@@ -46,22 +156,21 @@ pub(crate) fn execute_sync_dispatcher_requests<T, F>(
     mut function: F,
 ) -> ExecuteRequestsResult
 where
-    F: FnMut(&T) -> Option<SyncDispatcherRequest>,
+    F: FnMut(&mut T) -> Option<SyncDispatcherRequest>,
 {
     let mut index = 0;
 
     loop {
         if index < vec.len() {
-            match function(&vec[index]) {
+            match function(&mut vec[index]) {
                 None => index += 1,
                 Some(SyncDispatcherRequest::StopListening) => {
-                    vec.swap_remove(index);
-                }
-                Some(SyncDispatcherRequest::StopPropagation) => {
-                    return ExecuteRequestsResult::Stopped
+                    vec.remove(index);
                 }
+                Some(SyncDispatcherRequest::StopPropagation)
+                | Some(SyncDispatcherRequest::Handled) => return ExecuteRequestsResult::Stopped,
                 Some(SyncDispatcherRequest::StopListeningAndPropagation) => {
-                    vec.swap_remove(index);
+                    vec.remove(index);
                     return ExecuteRequestsResult::Stopped;
                 }
             }
@@ -71,32 +180,291 @@ where
     }
 }
 
-/// Yields closures and trait-objects.
-struct FnsAndTraits<T>
+/// Either a [`Weak`]-reference to a trait-object, an owned trait-object
+/// or an owned closure, or one of them behind a filter.
+/// `Once`-variants are removed after their first call.
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/rc/struct.Weak.html
+enum RcListener<T>
 where
     T: PartialEq + Eq + Hash + Clone + 'static,
 {
-    traits: Vec<Weak<RwLock<dyn Listener<T> + 'static>>>,
-    fns: EventFunction<T>,
+    Trait(Weak<RwLock<dyn Listener<T> + 'static>>),
+    TraitOnce(Weak<RwLock<dyn Listener<T> + 'static>>),
+    Owned(Box<dyn Listener<T>>),
+    Fn(RcFn<T>),
+    FnOnce(Option<RcFnOnce<T>>),
+    Filtered(RcFilter<T>, Box<RcListener<T>>),
 }
 
-impl<T> FnsAndTraits<T>
+impl<T> RcListener<T>
 where
     T: PartialEq + Eq + Hash + Clone + 'static,
 {
-    fn new_with_traits(trait_objects: Vec<Weak<RwLock<dyn Listener<T> + 'static>>>) -> Self {
-        FnsAndTraits {
-            traits: trait_objects,
-            fns: vec![],
+    /// Whether the listener can still be called,
+    /// owned listeners and closures are always alive.
+    fn is_alive(&self) -> bool {
+        match self {
+            RcListener::Trait(weak_listener) | RcListener::TraitOnce(weak_listener) => {
+                weak_listener.strong_count() > 0
+            }
+            RcListener::Owned(_) | RcListener::Fn(_) | RcListener::FnOnce(_) => true,
+            RcListener::Filtered(_, listener) => listener.is_alive(),
+        }
+    }
+
+    /// Calls `on_subscribe` on a trait-object that is still alive.
+    fn subscribe(&mut self) {
+        match self {
+            RcListener::Trait(weak_listener) | RcListener::TraitOnce(weak_listener) => {
+                if let Some(listener) = weak_listener.upgrade() {
+                    listener.write().on_subscribe();
+                }
+            }
+            RcListener::Owned(listener) => listener.on_subscribe(),
+            RcListener::Fn(_) | RcListener::FnOnce(_) => (),
+            RcListener::Filtered(_, listener) => listener.subscribe(),
         }
     }
 
-    fn new_with_fns(fns: EventFunction<T>) -> Self {
-        FnsAndTraits {
-            traits: vec![],
-            fns,
+    /// Calls `on_unsubscribe` on a trait-object that is still alive.
+    fn unsubscribe(&mut self) {
+        match self {
+            RcListener::Trait(weak_listener) | RcListener::TraitOnce(weak_listener) => {
+                if let Some(listener) = weak_listener.upgrade() {
+                    listener.write().on_unsubscribe();
+                }
+            }
+            RcListener::Owned(listener) => listener.on_unsubscribe(),
+            RcListener::Fn(_) | RcListener::FnOnce(_) => (),
+            RcListener::Filtered(_, listener) => listener.unsubscribe(),
         }
     }
+
+    /// Puts the listener behind `filter`, replacing a previous filter.
+    fn set_filter(&mut self, filter: RcFilter<T>) {
+        let listener = match mem::replace(self, RcListener::FnOnce(None)) {
+            RcListener::Filtered(_, listener) => listener,
+            listener => Box::new(listener),
+        };
+
+        *self = RcListener::Filtered(filter, listener);
+    }
+}
+
+/// Either a [`Weak`]-reference to a [`MutListener`] or an owned closure,
+/// both receiving events mutably.
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/rc/struct.Weak.html
+/// [`MutListener`]: trait.MutListener.html
+enum RcMutListener<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    Trait(Weak<RwLock<dyn MutListener<T> + 'static>>),
+    Fn(RcMutFn<T>),
+}
+
+impl<T> RcMutListener<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    /// Whether the listener can still be called, closures are always alive.
+    fn is_alive(&self) -> bool {
+        match self {
+            RcMutListener::Trait(weak_listener) => weak_listener.strong_count() > 0,
+            RcMutListener::Fn(_) => true,
+        }
+    }
+
+    /// Calls `on_subscribe` on a trait-object that is still alive.
+    fn subscribe(&mut self) {
+        if let RcMutListener::Trait(weak_listener) = self {
+            if let Some(listener) = weak_listener.upgrade() {
+                listener.write().on_subscribe();
+            }
+        }
+    }
+
+    /// Calls `on_unsubscribe` on a trait-object that is still alive.
+    fn unsubscribe(&mut self) {
+        if let RcMutListener::Trait(weak_listener) = self {
+            if let Some(listener) = weak_listener.upgrade() {
+                listener.write().on_unsubscribe();
+            }
+        }
+    }
+}
+
+/// Turns the `request` of a one-shot listener into a request
+/// that removes the listener, keeping a stop of propagation.
+fn stop_listening_once(request: Option<SyncDispatcherRequest>) -> Option<SyncDispatcherRequest> {
+    match request {
+        None | Some(SyncDispatcherRequest::StopListening) => {
+            Some(SyncDispatcherRequest::StopListening)
+        }
+        Some(SyncDispatcherRequest::StopPropagation)
+        | Some(SyncDispatcherRequest::StopListeningAndPropagation)
+        | Some(SyncDispatcherRequest::Handled) => {
+            Some(SyncDispatcherRequest::StopListeningAndPropagation)
+        }
+    }
+}
+
+/// Dispatches `event_identifier` to all `listeners` in registration-order
+/// until one of them stops propagation, counting calls in `dispatch_result`.
+/// Trait-objects that have been dropped are skipped and removed afterwards,
+/// trait-objects requesting to be removed are unsubscribed.
+fn dispatch_to_listeners<T>(
+    listeners: &mut Listeners<T>,
+    event_identifier: &T,
+    dispatch_result: &mut DispatchResult,
+) -> ExecuteRequestsResult
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    let mut found_invalid_weak_ref = false;
+    let invoked = &mut dispatch_result.invoked;
+
+    let result = execute_sync_dispatcher_requests(listeners, |registered| {
+        call_listener(
+            &mut registered.listener,
+            event_identifier,
+            invoked,
+            &mut found_invalid_weak_ref,
+        )
+    });
+
+    if found_invalid_weak_ref {
+        listeners.retain(|registered| registered.listener.is_alive());
+    }
+
+    if let ExecuteRequestsResult::Stopped = result {
+        dispatch_result.stopped = true;
+    }
+
+    result
+}
+
+/// Calls `listener` with `event_identifier` unless it has been dropped or
+/// its filter rejects the event, counting the call in `invoked`.
+fn call_listener<T>(
+    listener: &mut RcListener<T>,
+    event_identifier: &T,
+    invoked: &mut usize,
+    found_invalid_weak_ref: &mut bool,
+) -> Option<SyncDispatcherRequest>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    match listener {
+        RcListener::Trait(weak_listener) => {
+            if let Some(listener) = weak_listener.upgrade() {
+                *invoked += 1;
+                let mut listener = listener.write();
+                let request = listener.on_event(event_identifier);
+
+                if removes_listener(&request) {
+                    listener.on_unsubscribe();
+                }
+
+                request
+            } else {
+                *found_invalid_weak_ref = true;
+                None
+            }
+        }
+        RcListener::TraitOnce(weak_listener) => {
+            if let Some(listener) = weak_listener.upgrade() {
+                *invoked += 1;
+                let mut listener = listener.write();
+                let request = stop_listening_once(listener.on_event(event_identifier));
+                listener.on_unsubscribe();
+
+                request
+            } else {
+                *found_invalid_weak_ref = true;
+                None
+            }
+        }
+        RcListener::Owned(listener) => {
+            *invoked += 1;
+            let request = listener.on_event(event_identifier);
+
+            if removes_listener(&request) {
+                listener.on_unsubscribe();
+            }
+
+            request
+        }
+        RcListener::Fn(callback) => {
+            *invoked += 1;
+            callback(event_identifier)
+        }
+        RcListener::FnOnce(callback) => match callback.take() {
+            Some(callback) => {
+                *invoked += 1;
+                stop_listening_once(callback(event_identifier))
+            }
+            None => Some(SyncDispatcherRequest::StopListening),
+        },
+        RcListener::Filtered(filter, listener) => {
+            if filter(event_identifier) {
+                call_listener(listener, event_identifier, invoked, found_invalid_weak_ref)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Dispatches `event` mutably to all `listeners` in registration-order
+/// until one of them stops propagation, counting calls in `dispatch_result`.
+/// Trait-objects that have been dropped are skipped and removed afterwards.
+fn dispatch_mut_to_listeners<T>(
+    listeners: &mut MutListeners<T>,
+    event: &mut T,
+    dispatch_result: &mut DispatchResult,
+) -> ExecuteRequestsResult
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    let mut found_invalid_weak_ref = false;
+    let invoked = &mut dispatch_result.invoked;
+
+    let result =
+        execute_sync_dispatcher_requests(listeners, |registered| match &mut registered.listener {
+            RcMutListener::Trait(weak_listener) => {
+                if let Some(listener) = weak_listener.upgrade() {
+                    *invoked += 1;
+                    let mut listener = listener.write();
+                    let request = listener.on_event_mut(event);
+
+                    if removes_listener(&request) {
+                        listener.on_unsubscribe();
+                    }
+
+                    request
+                } else {
+                    found_invalid_weak_ref = true;
+                    None
+                }
+            }
+            RcMutListener::Fn(callback) => {
+                *invoked += 1;
+                callback(event)
+            }
+        });
+
+    if found_invalid_weak_ref {
+        listeners.retain(|registered| registered.listener.is_alive());
+    }
+
+    if let ExecuteRequestsResult::Stopped = result {
+        dispatch_result.stopped = true;
+    }
+
+    result
 }
 
 #[cfg(test)]
@@ -107,7 +475,7 @@ mod tests {
     mod execute_sync_dispatcher_requests {
         use super::*;
 
-        fn map_usize_to_request(x: &usize) -> Option<SyncDispatcherRequest> {
+        fn map_usize_to_request(x: &mut usize) -> Option<SyncDispatcherRequest> {
             match *x {
                 0 => Some(SyncDispatcherRequest::StopListening),
                 1 => Some(SyncDispatcherRequest::StopPropagation),
@@ -118,10 +486,10 @@ mod tests {
 
         #[test]
         fn stop_listening() {
-            let mut vec = vec![0, 0, 0, 1, 1, 1, 1];
+            let mut vec = vec![0, 3, 0, 0, 1, 1, 1, 1];
             execute_sync_dispatcher_requests(&mut vec, map_usize_to_request);
 
-            assert_eq!(vec, [1, 0, 0, 1, 1, 1]);
+            assert_eq!(vec, [3, 1, 1, 1, 1]);
         }
 
         #[test]
@@ -1,29 +1,30 @@
 use super::{
-    execute_sync_dispatcher_requests, ExecuteRequestsResult, FnsAndTraits, Listener, RwLock,
-    SyncDispatcherRequest,
+    dispatch_to_listeners, DispatchResult, Listener, ListenerHandle, Listeners, RcListener,
+    Registered, RwLock, SyncDispatcherRequest,
 };
+use crate::sync::{dispatch_by_priority, take_registered};
 use std::{
     collections::{BTreeMap, HashMap},
     hash::Hash,
-    rc::{Rc, Weak},
+    rc::Rc,
 };
 
-type PriorityListenerMap<P, T> = HashMap<T, BTreeMap<P, FnsAndTraits<T>>>;
+type PriorityListenerMap<P, T> = HashMap<T, BTreeMap<P, Listeners<T>>>;
 
 /// In charge of prioritised sync dispatching to all listeners.
 /// Owns a map event-variants and [`Weak`]-references to their
-/// listeners and/or owns [`Fn`]s.
+/// listeners and/or owns [`FnMut`]s.
 /// Opposed to [`EventListener`], this structure utilises one [`BTreeMap`] per
 /// event-type to order listeners by a given priority-level.
 ///
 /// **Note**: Consider implementing your own [`Ord`]-trait, if you
 /// want a different kind of order.
 ///
-/// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+/// [`Weak`]: https://doc.rust-lang.org/std/rc/struct.Weak.html
 /// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
 /// [`Ord`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html
 /// [`EventListener`]: struct.Dispatcher.html
-/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+/// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
 pub struct PriorityDispatcher<P, T>
 where
     P: Ord,
@@ -52,6 +53,7 @@ where
     /// Adds a [`Listener`] to listen for an `event_identifier`, considering
     /// a given `priority` implementing the [`Ord`]-trait, to sort dispatch-order.
     /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
     ///
     /// **Note**: If your `Enum` owns fields you need to consider implementing
     /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields,
@@ -118,164 +120,249 @@ where
     /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
     /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
     /// [`Ord`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
     pub fn add_listener<D: Listener<T> + 'static>(
         &mut self,
         event_identifier: T,
         listener: &Rc<RwLock<D>>,
         priority: P,
-    ) {
-        if let Some(prioritised_listener_collection) = self.events.get_mut(&event_identifier) {
-            if let Some(priority_level_collection) =
-                prioritised_listener_collection.get_mut(&priority)
-            {
-                priority_level_collection.traits.push(Rc::downgrade(
-                    &(Rc::clone(listener) as Rc<RwLock<dyn Listener<T> + 'static>>),
-                ));
-
-                return;
-            }
-            prioritised_listener_collection.insert(
-                priority.clone(),
-                FnsAndTraits::new_with_traits(vec![Rc::downgrade(
-                    &(Rc::clone(listener) as Rc<RwLock<dyn Listener<T> + 'static>>),
-                )]),
-            );
-            return;
-        }
-
-        let mut b_tree_map = BTreeMap::new();
-        b_tree_map.insert(
-            priority,
-            FnsAndTraits::new_with_traits(vec![Rc::downgrade(
+    ) -> ListenerHandle {
+        self.register(
+            event_identifier,
+            RcListener::Trait(Rc::downgrade(
                 &(Rc::clone(listener) as Rc<RwLock<dyn Listener<T> + 'static>>),
-            )]),
-        );
-        self.events.insert(event_identifier, b_tree_map);
+            )),
+            priority,
+        )
     }
 
-    /// Adds an [`Fn`] to listen for an `event_identifier`, considering
+    /// Adds a [`FnMut`] to listen for an `event_identifier`, considering
     /// a given `priority` implementing the [`Ord`]-trait in order to sort dispatch-order.
     /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// The dispatcher owns `function`, within a priority-level closures and
+    /// [`Listener`]s are dispatched in the order they have been added.
     ///
     /// # Examples
     ///
-    /// Adding an [`Fn`] to the dispatcher:
+    /// Adding a [`FnMut`] to the dispatcher:
     ///
     /// ```rust
-    /// use hey_listen::{
-    ///    RwLock,
-    ///    rc::{Listener, PriorityDispatcher, SyncDispatcherRequest},
-    /// };
-    /// use std::rc::Rc;
+    /// use hey_listen::rc::{PriorityDispatcher, SyncDispatcherRequest};
     ///
     /// #[derive(Clone, Eq, Hash, PartialEq)]
     /// enum Event {
     ///     EventType,
     /// }
     ///
-    /// struct EventListener {
-    ///     used_method: bool,
-    /// }
-    ///
-    /// impl EventListener {
-    ///     fn test_method(&mut self, _event: &Event) {
-    ///         self.used_method = true;
-    ///     }
-    /// }
-    ///
     /// fn main() {
-    ///     let listener = Rc::new(RwLock::new(EventListener { used_method: false }));
     ///     let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
-    ///     let weak_listener_ref = Rc::downgrade(&Rc::clone(&listener));
+    ///     let mut clicks = 0;
     ///
-    ///     let closure = Box::new(move |event: &Event| -> Option<SyncDispatcherRequest> {
-    ///         if let Some(listener) = weak_listener_ref.upgrade() {
-    ///             listener.write().test_method(&event);
+    ///     dispatcher.add_fn(
+    ///         Event::EventType,
+    ///         move |_| {
+    ///             clicks += 1;
     ///
-    ///             None
-    ///         } else {
-    ///             Some(SyncDispatcherRequest::StopListening)
-    ///         }
-    ///     });
+    ///             if clicks == 2 {
+    ///                 Some(SyncDispatcherRequest::StopListening)
+    ///             } else {
+    ///                 None
+    ///             }
+    ///         },
+    ///         1,
+    ///     );
     ///
-    ///     dispatcher.add_fn(Event::EventType, closure, 1);
+    ///     dispatcher.dispatch_event(&Event::EventType);
     /// }
     /// ```
     ///
-    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
-    /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
-    /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    /// [`Listener`]: trait.Listener.html
+    /// [`Ord`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html
     /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
-    pub fn add_fn(
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_fn<F>(&mut self, event_identifier: T, function: F, priority: P) -> ListenerHandle
+    where
+        F: FnMut(&T) -> Option<SyncDispatcherRequest> + 'static,
+    {
+        self.register(
+            event_identifier,
+            RcListener::Fn(Box::new(function)),
+            priority,
+        )
+    }
+
+    /// Adds a [`Listener`] at `priority` that is called on the first
+    /// dispatch of `event_identifier` only and removed afterwards,
+    /// no matter which request it returns.
+    /// If propagation stops before it has been reached, it stays registered.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    pub fn add_listener_once<D: Listener<T> + 'static>(
+        &mut self,
+        event_identifier: T,
+        listener: &Rc<RwLock<D>>,
+        priority: P,
+    ) -> ListenerHandle {
+        self.register(
+            event_identifier,
+            RcListener::TraitOnce(Rc::downgrade(
+                &(Rc::clone(listener) as Rc<RwLock<dyn Listener<T> + 'static>>),
+            )),
+            priority,
+        )
+    }
+
+    /// Adds a [`FnOnce`] at `priority` that is called on the first
+    /// dispatch of `event_identifier` only and removed afterwards.
+    /// If propagation stops before it has been reached, it stays registered.
+    ///
+    /// [`FnOnce`]: https://doc.rust-lang.org/std/ops/trait.FnOnce.html
+    pub fn add_fn_once<F>(
+        &mut self,
+        event_identifier: T,
+        function: F,
+        priority: P,
+    ) -> ListenerHandle
+    where
+        F: FnOnce(&T) -> Option<SyncDispatcherRequest> + 'static,
+    {
+        self.register(
+            event_identifier,
+            RcListener::FnOnce(Some(Box::new(function))),
+            priority,
+        )
+    }
+
+    /// Appends `listener` to the `priority`-level of `event_identifier`.
+    fn register(
         &mut self,
         event_identifier: T,
-        function: Box<dyn Fn(&T) -> Option<SyncDispatcherRequest>>,
+        listener: RcListener<T>,
         priority: P,
-    ) {
-        if let Some(prioritised_listener_collection) = self.events.get_mut(&event_identifier) {
-            if let Some(priority_level_collection) =
-                prioritised_listener_collection.get_mut(&priority)
-            {
-                priority_level_collection.fns.push(function);
+    ) -> ListenerHandle {
+        let mut registered = Registered::new(listener);
+        let handle = registered.handle;
+        registered.listener.subscribe();
+
+        self.events
+            .entry(event_identifier)
+            .or_default()
+            .entry(priority)
+            .or_default()
+            .push(registered);
+
+        handle
+    }
+
+    /// Removes the listener or [`FnMut`] registered with `handle`,
+    /// the order of all other listeners stays untouched.
+    /// Returns `false` if `handle` is not registered on this dispatcher,
+    /// e.g. because it has already been removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::rc::PriorityDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///     let handle = dispatcher.add_fn(Event::EventType, |_| None, 1);
+    ///
+    ///     assert!(dispatcher.remove_listener(handle));
+    ///     assert!(!dispatcher.remove_listener(handle));
+    /// }
+    /// ```
+    ///
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    pub fn remove_listener(&mut self, handle: ListenerHandle) -> bool {
+        for prioritised_listener_collection in self.events.values_mut() {
+            let removed =
+                prioritised_listener_collection
+                    .iter_mut()
+                    .find_map(|(priority, listeners)| {
+                        take_registered(listeners, handle)
+                            .map(|registered| (registered, priority.clone(), listeners.is_empty()))
+                    });
+
+            if let Some((mut registered, priority, is_empty)) = removed {
+                if is_empty {
+                    prioritised_listener_collection.remove(&priority);
+                }
 
-                return;
+                registered.listener.unsubscribe();
+
+                return true;
             }
-            prioritised_listener_collection
-                .insert(priority.clone(), FnsAndTraits::new_with_fns(vec![function]));
-            return;
         }
 
-        let mut b_tree_map = BTreeMap::new();
-        b_tree_map.insert(priority, FnsAndTraits::new_with_fns(vec![function]));
-        self.events.insert(event_identifier, b_tree_map);
+        false
     }
 
-    /// All [`Listener`]s listening to a passed `event_identifier`
-    /// will be called via their implemented [`on_event`]-method.
-    /// [`Fn`]s returning [`Result`] with `Ok(())` will be retained
-    /// and `Err(SyncDispatcherRequest::StopListening)` will cause them to
-    /// be removed from the event-dispatcher.
+    /// Puts the listener or [`FnMut`] registered with `handle` behind
+    /// `filter`, replacing a previous filter: It is only called for events
+    /// passing `filter`, which runs before the listener is locked.
+    /// Rejected events skip it without counting as invoked, a one-shot
+    /// listener stays registered until an event passes.
+    /// Returns `false` if `handle` is not registered on this dispatcher.
     ///
-    /// **Notice**: [`Listener`]s will called ordered by their priority-level.
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    pub fn set_filter<F>(&mut self, handle: ListenerHandle, filter: F) -> bool
+    where
+        F: Fn(&T) -> bool + 'static,
+    {
+        let registered = self
+            .events
+            .values_mut()
+            .flat_map(|prioritised_listener_collection| {
+                prioritised_listener_collection.values_mut()
+            })
+            .flatten()
+            .find(|registered| registered.handle == handle);
+
+        match registered {
+            Some(registered) => {
+                registered.listener.set_filter(Box::new(filter));
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// All [`Listener`]s and [`FnMut`]s listening to a passed
+    /// `event_identifier` will be called ordered by their priority-level,
+    /// within a level in the order they have been added.
+    /// A returned [`SyncDispatcherRequest`] is honoured no matter whether
+    /// it stems from a [`Listener`] or a [`FnMut`].
     ///
     /// [`Listener`]: trait.Listener.html
-    /// [`on_event`]: trait.Listener.html#tymethod.on_event
-    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
-    /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
+    /// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+    /// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
     pub fn dispatch_event(&mut self, event_identifier: &T) {
-        if let Some(prioritised_listener_collection) = self.events.get_mut(event_identifier) {
-            for (_, listener_collection) in prioritised_listener_collection.iter_mut() {
-                let mut found_invalid_weak_ref = false;
-
-                if let ExecuteRequestsResult::Stopped = execute_sync_dispatcher_requests(
-                    &mut listener_collection.traits,
-                    |weak_listener| {
-                        if let Some(listener_arc) = weak_listener.upgrade() {
-                            let mut listener = listener_arc.write();
-                            listener.on_event(event_identifier)
-                        } else {
-                            found_invalid_weak_ref = true;
-                            None
-                        }
-                    },
-                ) {
-                    break;
-                }
+        self.dispatch_event_with_result(event_identifier);
+    }
 
-                if let ExecuteRequestsResult::Stopped =
-                    execute_sync_dispatcher_requests(&mut listener_collection.fns, |callback| {
-                        callback(event_identifier)
-                    })
-                {
-                    break;
-                }
+    /// Dispatches like [`dispatch_event`] and returns how many listeners
+    /// have been called and whether one stopped propagation.
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    pub fn dispatch_event_with_result(&mut self, event_identifier: &T) -> DispatchResult {
+        let mut dispatch_result = DispatchResult::default();
 
-                if found_invalid_weak_ref {
-                    listener_collection
-                        .traits
-                        .retain(|listener| Weak::clone(listener).upgrade().is_some());
-                }
-            }
+        if let Some(prioritised_listener_collection) = self.events.get_mut(event_identifier) {
+            dispatch_by_priority(prioritised_listener_collection, |listeners| {
+                dispatch_to_listeners(listeners, event_identifier, &mut dispatch_result)
+            });
         }
+
+        dispatch_result
     }
 }
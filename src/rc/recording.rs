@@ -0,0 +1,212 @@
+use super::{Dispatcher, Listener, ListenerHandle, RwLock, SyncDispatcherRequest};
+use std::{hash::Hash, mem, rc::Rc};
+
+/// One event recorded by a [`Recorder`], along with its sequence number.
+///
+/// All fields are public plain data, hence entries can be converted into
+/// any format, e.g. to be shipped along with a bug report, and be built
+/// again from it.
+/// With the `serde`-feature enabled, entries and hence logs of entries
+/// implement `Serialize` and `Deserialize` if the event-type does.
+///
+/// [`Recorder`]: struct.Recorder.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordEntry<T> {
+    /// Counts up from `0` since the recorder has been attached.
+    pub sequence: u64,
+    pub event: T,
+}
+
+/// The catch-all listener of a [`Recorder`], numbering every event.
+///
+/// [`Recorder`]: struct.Recorder.html
+struct RecordLog<T> {
+    entries: Vec<RecordEntry<T>>,
+    next_sequence: u64,
+}
+
+impl<T> Listener<T> for RecordLog<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    fn on_event(&mut self, event: &T) -> Option<SyncDispatcherRequest> {
+        self.entries.push(RecordEntry {
+            sequence: self.next_sequence,
+            event: event.clone(),
+        });
+        self.next_sequence += 1;
+
+        None
+    }
+}
+
+/// Records every event dispatched by a [`Dispatcher`] in the order they
+/// reach listeners, including events dispatched or emitted by listeners,
+/// e.g. to attach the log to a bug report and [`replay_into`] fresh
+/// listeners later.
+///
+/// The recorder listens as catch-all listener, see
+/// [`Dispatcher::add_catch_all_listener`]. Hence it should be attached
+/// before any other catch-all listener that might stop propagation, and
+/// it does not see mutable dispatches.
+/// Dropping the recorder stops recording.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::rc::{
+///     recording::{replay_into, Recorder},
+///     Dispatcher,
+/// };
+/// use std::{cell::RefCell, rc::Rc};
+///
+/// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Save,
+///     Saved,
+/// }
+///
+/// fn main() {
+///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+///     let emitter = dispatcher.emitter();
+///     dispatcher.add_fn(Event::Save, move |_| {
+///         emitter.emit(Event::Saved);
+///
+///         None
+///     });
+///
+///     let recorder = Recorder::attach(&dispatcher);
+///     dispatcher.dispatch_event(&Event::Save);
+///
+///     let log = recorder.take_log();
+///     assert_eq!(log, [Event::Save, Event::Saved]);
+///
+///     let replayed: Dispatcher<Event> = Dispatcher::default();
+///     let calls = Rc::new(RefCell::new(0));
+///     let counter = Rc::clone(&calls);
+///     replayed.add_fn(Event::Saved, move |_| {
+///         *counter.borrow_mut() += 1;
+///
+///         None
+///     });
+///
+///     replay_into(&replayed, &log);
+///     assert_eq!(*calls.borrow(), 1);
+/// }
+/// ```
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`replay_into`]: fn.replay_into.html
+/// [`Dispatcher::add_catch_all_listener`]: struct.Dispatcher.html#method.add_catch_all_listener
+pub struct Recorder<T> {
+    log: Rc<RwLock<RecordLog<T>>>,
+    handle: ListenerHandle,
+}
+
+impl<T> Recorder<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    /// Starts recording every event dispatched by `dispatcher` from now on.
+    pub fn attach(dispatcher: &Dispatcher<T>) -> Self {
+        let log = Rc::new(RwLock::new(RecordLog {
+            entries: Vec::new(),
+            next_sequence: 0,
+        }));
+        let handle = dispatcher.add_catch_all_listener(&log);
+
+        Recorder { log, handle }
+    }
+
+    /// The [`ListenerHandle`] of the recorder's catch-all registration.
+    ///
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn handle(&self) -> ListenerHandle {
+        self.handle
+    }
+
+    /// Returns the recorded events along with their sequence numbers,
+    /// counting up from `0` since the recorder has been attached.
+    pub fn entries(&self) -> Vec<RecordEntry<T>> {
+        self.log.read().entries.clone()
+    }
+
+    /// Removes all recorded events and returns them in dispatch-order,
+    /// along with their sequence numbers.
+    /// Sequence numbers of events recorded later continue counting.
+    pub fn take_entries(&self) -> Vec<RecordEntry<T>> {
+        mem::take(&mut self.log.write().entries)
+    }
+
+    /// Removes all recorded events and returns them in dispatch-order.
+    /// Sequence numbers of events recorded later continue counting.
+    pub fn take_log(&self) -> Vec<T> {
+        self.take_entries()
+            .into_iter()
+            .map(|entry| entry.event)
+            .collect()
+    }
+}
+
+/// Dispatches every event of `log` to `dispatcher`, in order, and returns
+/// how many events have been dispatched.
+///
+/// A recorded log already contains the events listeners have dispatched
+/// or emitted, hence events dispatched or emitted by listeners during the
+/// replay are dropped instead of being dispatched twice. Events queued
+/// before the replay stay queued.
+///
+/// **Note**: Returns `0` without dispatching anything when called by
+/// a listener.
+pub fn replay_into<T>(dispatcher: &Dispatcher<T>, log: &[T]) -> usize
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    dispatcher.replay(log)
+}
+
+/// Dispatches only the events of `log` matching `predicate` to
+/// `dispatcher`, in their original order, e.g. to reproduce the input
+/// events of a session only.
+/// Returns how many events have been dispatched.
+///
+/// Like [`replay_into`], events dispatched or emitted by listeners during
+/// the replay are dropped. Hence events that have been emitted while
+/// recording are only replayed if they match `predicate` as well.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::rc::{recording::replay_filtered_into, Dispatcher};
+///
+/// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// enum Event {
+///     KeyPressed(char),
+///     FrameRendered,
+/// }
+///
+/// fn main() {
+///     let log = [
+///         Event::KeyPressed('a'),
+///         Event::FrameRendered,
+///         Event::KeyPressed('b'),
+///     ];
+///     let dispatcher: Dispatcher<Event> = Dispatcher::default();
+///
+///     let replayed = replay_filtered_into(&dispatcher, &log, |event| {
+///         matches!(event, Event::KeyPressed(_))
+///     });
+///
+///     assert_eq!(replayed, 2);
+/// }
+/// ```
+///
+/// [`replay_into`]: fn.replay_into.html
+pub fn replay_filtered_into<T, P>(dispatcher: &Dispatcher<T>, log: &[T], predicate: P) -> usize
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+    P: Fn(&T) -> bool,
+{
+    dispatcher.replay(log.iter().filter(|event| predicate(event)))
+}
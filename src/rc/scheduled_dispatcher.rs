@@ -0,0 +1,239 @@
+use super::Dispatcher;
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    time::Duration,
+};
+
+/// Identifies one event scheduled on a [`ScheduledDispatcher`], allowing
+/// to cancel it before it is due.
+///
+/// [`ScheduledDispatcher`]: struct.ScheduledDispatcher.html
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ScheduleHandle(u64);
+
+/// A scheduled event, along with its interval if it is repeating.
+struct Scheduled<T> {
+    event: T,
+    every: Option<Duration>,
+}
+
+/// Wraps a [`Dispatcher`] to dispatch events after a delay, e.g. to
+/// respawn a player in five seconds.
+///
+/// The scheduler does not spawn threads or read a clock, instead the
+/// caller drives time via [`tick_with_elapsed`], e.g. once per frame.
+/// Hence scheduling is deterministic and can be tested without waiting.
+///
+/// Repeating events added via [`schedule_repeating`] fire at a fixed rate:
+/// Every next deadline is the previous deadline plus the interval, no
+/// matter how late the tick dispatching it has been, so deadlines do not
+/// drift. A tick that is late by several intervals catches up on the
+/// missed firings, at most [`set_max_firings_per_tick`] times per event,
+/// skipping the remaining missed firings.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::rc::ScheduledDispatcher;
+/// use std::{cell::Cell, rc::Rc, time::Duration};
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Respawn,
+/// }
+///
+/// fn main() {
+///     let mut scheduler: ScheduledDispatcher<Event> = ScheduledDispatcher::default();
+///     let respawned = Rc::new(Cell::new(false));
+///     let flag = Rc::clone(&respawned);
+///
+///     scheduler.dispatcher().add_fn(Event::Respawn, move |_| {
+///         flag.set(true);
+///
+///         None
+///     });
+///
+///     scheduler.schedule(Event::Respawn, Duration::from_secs(5));
+///
+///     assert_eq!(scheduler.tick_with_elapsed(Duration::from_secs(3)), 0);
+///     assert!(!respawned.get());
+///
+///     assert_eq!(scheduler.tick_with_elapsed(Duration::from_secs(2)), 1);
+///     assert!(respawned.get());
+/// }
+/// ```
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`tick_with_elapsed`]: #method.tick_with_elapsed
+/// [`schedule_repeating`]: #method.schedule_repeating
+/// [`set_max_firings_per_tick`]: #method.set_max_firings_per_tick
+pub struct ScheduledDispatcher<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    dispatcher: Dispatcher<T>,
+    scheduled: BTreeMap<(Duration, ScheduleHandle), Scheduled<T>>,
+    deadlines: HashMap<ScheduleHandle, Duration>,
+    elapsed: Duration,
+    next_handle: u64,
+    max_firings_per_tick: usize,
+}
+
+impl<T> Default for ScheduledDispatcher<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    fn default() -> ScheduledDispatcher<T> {
+        ScheduledDispatcher::new(Dispatcher::default())
+    }
+}
+
+impl<T> ScheduledDispatcher<T>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    /// Wraps `dispatcher`, its clock starts at zero.
+    pub fn new(dispatcher: Dispatcher<T>) -> Self {
+        ScheduledDispatcher {
+            dispatcher,
+            scheduled: BTreeMap::new(),
+            deadlines: HashMap::new(),
+            elapsed: Duration::from_secs(0),
+            next_handle: 0,
+            max_firings_per_tick: usize::MAX,
+        }
+    }
+
+    /// The wrapped dispatcher, e.g. to add listeners or to dispatch
+    /// events right away.
+    pub fn dispatcher(&self) -> &Dispatcher<T> {
+        &self.dispatcher
+    }
+
+    /// Unwraps the dispatcher, dropping all scheduled events.
+    pub fn into_inner(self) -> Dispatcher<T> {
+        self.dispatcher
+    }
+
+    /// Schedules `event` to be dispatched by the first tick at least
+    /// `delay` from now.
+    /// Returns a [`ScheduleHandle`] to [`cancel`] it.
+    ///
+    /// [`ScheduleHandle`]: struct.ScheduleHandle.html
+    /// [`cancel`]: #method.cancel
+    pub fn schedule(&mut self, event: T, delay: Duration) -> ScheduleHandle {
+        self.insert(event, delay, None)
+    }
+
+    /// Schedules `event` to be dispatched every `every`, starting `every`
+    /// from now, until it is [`cancel`]led.
+    /// Returns a [`ScheduleHandle`] to cancel it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `every` is zero.
+    ///
+    /// [`cancel`]: #method.cancel
+    /// [`ScheduleHandle`]: struct.ScheduleHandle.html
+    pub fn schedule_repeating(&mut self, event: T, every: Duration) -> ScheduleHandle {
+        assert!(
+            every > Duration::from_secs(0),
+            "repeating events need a non-zero interval"
+        );
+
+        self.insert(event, every, Some(every))
+    }
+
+    /// Sets how many times a single tick dispatches the same repeating
+    /// event at most, when it has missed several firings.
+    /// Further missed firings are skipped, the next deadline stays on
+    /// the event's fixed-rate grid.
+    /// Defaults to no limit, `0` behaves like `1`.
+    pub fn set_max_firings_per_tick(&mut self, max_firings: usize) {
+        self.max_firings_per_tick = max_firings.max(1);
+    }
+
+    /// Removes the event scheduled with `handle` without dispatching it
+    /// again, be it repeating or not.
+    /// Returns `false` if it has already been dispatched or cancelled.
+    pub fn cancel(&mut self, handle: ScheduleHandle) -> bool {
+        match self.deadlines.remove(&handle) {
+            Some(deadline) => self.scheduled.remove(&(deadline, handle)).is_some(),
+            None => false,
+        }
+    }
+
+    /// Advances the clock by `elapsed` and dispatches every event whose
+    /// deadline has passed, earliest deadline first.
+    /// Events sharing a deadline are dispatched in scheduling-order.
+    /// Returns how many events have been dispatched, counting every
+    /// firing of repeating events.
+    pub fn tick_with_elapsed(&mut self, elapsed: Duration) -> usize {
+        self.elapsed += elapsed;
+
+        let mut dispatched = 0;
+        let mut firings = HashMap::new();
+
+        while let Some(&(deadline, handle)) = self.scheduled.keys().next() {
+            if deadline > self.elapsed {
+                break;
+            }
+
+            let scheduled = self
+                .scheduled
+                .remove(&(deadline, handle))
+                .expect("the earliest entry has just been found");
+
+            self.dispatcher.dispatch_event(&scheduled.event);
+            dispatched += 1;
+
+            match scheduled.every {
+                Some(every) => {
+                    let fired = firings.entry(handle).or_insert(0);
+                    *fired += 1;
+
+                    let mut next_deadline = deadline + every;
+
+                    if *fired >= self.max_firings_per_tick && next_deadline <= self.elapsed {
+                        let every_nanos = every.as_nanos();
+                        let skipped = (self.elapsed - next_deadline).as_nanos() / every_nanos + 1;
+                        next_deadline += Duration::from_nanos((skipped * every_nanos) as u64);
+                    }
+
+                    self.scheduled.insert((next_deadline, handle), scheduled);
+                    self.deadlines.insert(handle, next_deadline);
+                }
+                None => {
+                    self.deadlines.remove(&handle);
+                }
+            }
+        }
+
+        dispatched
+    }
+
+    /// Returns how much time has been ticked since creation.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns how many events are scheduled but not yet dispatched,
+    /// repeating events count until they are cancelled.
+    pub fn scheduled_len(&self) -> usize {
+        self.scheduled.len()
+    }
+
+    /// Schedules `event` `delay` from now, repeating it `every` if given.
+    fn insert(&mut self, event: T, delay: Duration, every: Option<Duration>) -> ScheduleHandle {
+        let handle = ScheduleHandle(self.next_handle);
+        self.next_handle += 1;
+
+        let deadline = self.elapsed + delay;
+        self.scheduled
+            .insert((deadline, handle), Scheduled { event, every });
+        self.deadlines.insert(handle, deadline);
+
+        handle
+    }
+}
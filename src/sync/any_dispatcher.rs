@@ -0,0 +1,199 @@
+use super::{
+    DispatchResult, ExecuteRequestsResult, FnsAndTraits, Listener, ListenerHandle, Registered,
+    RwLock, SyncFn,
+};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    hash::Hash,
+    sync::Arc,
+};
+
+/// The listeners of one event-type, with the event-type erased.
+trait TypedListeners: Send + Sync {
+    fn remove(&mut self, handle: ListenerHandle) -> bool;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<E> TypedListeners for FnsAndTraits<E>
+where
+    E: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn remove(&mut self, handle: ListenerHandle) -> bool {
+        FnsAndTraits::remove(self, handle)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// In charge of sync dispatching to listeners by the type of events,
+/// instead of by an event-variant.
+/// Owns a map of event-types and [`Weak`]-references to their listeners
+/// and/or owns [`Fn`]s.
+///
+/// Every event-type `E` is dispatched to the [`Listener<E>`]s registered
+/// for it, regardless of the event's value. Hence events of unrelated
+/// crates do not need to share one `Enum`, each crate brings its own
+/// event-types instead.
+///
+/// **Note**: Event-types need to satisfy the same bounds as for all other
+/// dispatchers, as listeners implement [`Listener<E>`].
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::{
+///     sync::{AnyDispatcher, Listener, SyncDispatcherRequest},
+///     RwLock,
+/// };
+/// use std::sync::Arc;
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// struct PlayerDied {
+///     id: u32,
+/// }
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// struct LevelCleared;
+///
+/// #[derive(Default)]
+/// struct Scoreboard {
+///     deaths: Vec<u32>,
+///     levels: usize,
+/// }
+///
+/// impl Listener<PlayerDied> for Scoreboard {
+///     fn on_event(&mut self, event: &PlayerDied) -> Option<SyncDispatcherRequest> {
+///         self.deaths.push(event.id);
+///
+///         None
+///     }
+/// }
+///
+/// impl Listener<LevelCleared> for Scoreboard {
+///     fn on_event(&mut self, _event: &LevelCleared) -> Option<SyncDispatcherRequest> {
+///         self.levels += 1;
+///
+///         None
+///     }
+/// }
+///
+/// fn main() {
+///     let scoreboard = Arc::new(RwLock::new(Scoreboard::default()));
+///     let mut dispatcher = AnyDispatcher::default();
+///
+///     dispatcher.add_listener::<PlayerDied, _>(&scoreboard);
+///     dispatcher.add_listener::<LevelCleared, _>(&scoreboard);
+///
+///     dispatcher.dispatch_event(&PlayerDied { id: 7 });
+///     dispatcher.dispatch_event(&LevelCleared);
+///
+///     assert_eq!(scoreboard.read().deaths, [7]);
+///     assert_eq!(scoreboard.read().levels, 1);
+/// }
+/// ```
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+/// [`Listener<E>`]: trait.Listener.html
+#[derive(Default)]
+pub struct AnyDispatcher {
+    events: HashMap<TypeId, Box<dyn TypedListeners>>,
+}
+
+impl AnyDispatcher {
+    /// Adds a [`Listener`] to listen for events of type `E`.
+    /// A listener implementing [`Listener`] for several event-types can be
+    /// added once per event-type.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_listener<E, D>(&mut self, listener: &Arc<RwLock<D>>) -> ListenerHandle
+    where
+        E: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+        D: Listener<E> + Send + Sync + 'static,
+    {
+        let listener = Arc::clone(listener) as Arc<RwLock<dyn Listener<E> + Send + Sync>>;
+        let registered = Registered::subscribe(&listener);
+        let handle = registered.handle;
+
+        self.listeners_mut::<E>().traits.push(registered);
+
+        handle
+    }
+
+    /// Adds a [`Fn`] to listen for events of type `E`.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_fn<E>(&mut self, function: SyncFn<E>) -> ListenerHandle
+    where
+        E: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    {
+        let registered = Registered::new(Arc::from(function));
+        let handle = registered.handle;
+
+        self.listeners_mut::<E>().fns.push(registered);
+
+        handle
+    }
+
+    /// Removes the listener or [`Fn`] registered with `handle`,
+    /// no matter its event-type.
+    /// Returns `false` if `handle` is not registered on this dispatcher.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn remove_listener(&mut self, handle: ListenerHandle) -> bool {
+        self.events
+            .values_mut()
+            .any(|listeners| listeners.remove(handle))
+    }
+
+    /// All [`Listener`]s and [`Fn`]s listening to events of type `E` will
+    /// be called with `event`, listeners before closures, each in the order
+    /// they have been added.
+    /// Returned requests are honoured like on a [`Dispatcher`], an
+    /// event-type without listeners returns right away.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Dispatcher`]: struct.Dispatcher.html
+    pub fn dispatch_event<E>(&mut self, event: &E) -> DispatchResult
+    where
+        E: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    {
+        let mut dispatch_result = DispatchResult::default();
+
+        if let Some(listeners) = self.events.get_mut(&TypeId::of::<E>()) {
+            let invoked = &mut dispatch_result.invoked;
+
+            let result = listeners
+                .as_any_mut()
+                .downcast_mut::<FnsAndTraits<E>>()
+                .expect("listeners are stored by the `TypeId` of their event-type")
+                .dispatch(event, None, &mut |_| *invoked += 1);
+
+            dispatch_result.stopped = matches!(result, ExecuteRequestsResult::Stopped);
+        }
+
+        dispatch_result
+    }
+
+    /// Returns the listeners of event-type `E`, adding them if missing.
+    fn listeners_mut<E>(&mut self) -> &mut FnsAndTraits<E>
+    where
+        E: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    {
+        self.events
+            .entry(TypeId::of::<E>())
+            .or_insert_with(|| Box::new(FnsAndTraits::<E>::new()))
+            .as_any_mut()
+            .downcast_mut()
+            .expect("listeners are stored by the `TypeId` of their event-type")
+    }
+}
@@ -0,0 +1,276 @@
+use super::{remove_registered, ListenerHandle, Registered};
+use parking_lot::RwLock;
+use std::{
+    collections::HashMap,
+    future::Future,
+    hash::Hash,
+    mem,
+    pin::Pin,
+    sync::{Arc, Weak},
+    task::{Context, Poll},
+};
+
+/// The boxed [`Future`] an [`AsyncListener`] returns for every event.
+///
+/// [`Future`]: https://doc.rust-lang.org/std/future/trait.Future.html
+/// [`AsyncListener`]: trait.AsyncListener.html
+pub type AsyncListenerFuture<'a> =
+    Pin<Box<dyn Future<Output = Option<AsyncDispatcherRequest>> + Send + 'a>>;
+
+type AsyncListeners<T> = Vec<Registered<Weak<dyn AsyncListener<T> + Send + Sync + 'static>>>;
+
+/// An `enum` returning a request from an [`AsyncListener`] to its
+/// [`AsyncDispatcher`].
+///
+/// `StopListening` will remove your listener from the event-dispatcher
+/// once the current dispatch has completed.
+///
+/// `StopPropagation` will stop a sequential dispatch of the current
+/// `Event` instance, a concurrent dispatch has already polled all
+/// listeners and ignores it.
+///
+/// `StopListeningAndPropagation` a combination of first `StopListening`
+/// and then `StopPropagation`.
+///
+/// [`AsyncListener`]: trait.AsyncListener.html
+/// [`AsyncDispatcher`]: struct.AsyncDispatcher.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AsyncDispatcherRequest {
+    StopListening,
+    StopPropagation,
+    StopListeningAndPropagation,
+}
+
+/// Every event-receiver of an [`AsyncDispatcher`] needs to implement this
+/// trait, `T` being the type you use for events, e.g. an `Enum`.
+///
+/// Listeners are shared by all futures of a concurrent dispatch, hence
+/// `on_event` borrows `self` immutably, keep mutable state behind a lock
+/// that is not held across an `.await`.
+///
+/// [`AsyncDispatcher`]: struct.AsyncDispatcher.html
+pub trait AsyncListener<T> {
+    /// This function will be called once a listened event-type `T` has
+    /// been dispatched, the dispatcher awaits the returned future.
+    fn on_event<'a>(&'a self, event: &'a T) -> AsyncListenerFuture<'a>;
+}
+
+/// In charge of dispatching to listeners that need to `.await`.
+/// Owns a map event-variants and [`Weak`]-references to their
+/// [`AsyncListener`]s.
+///
+/// The dispatcher does not depend on any executor, the futures returned
+/// by [`dispatch_event`] and [`dispatch_event_concurrent`] can be spawned
+/// on or blocked upon by whichever runtime drives your application.
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+/// [`AsyncListener`]: trait.AsyncListener.html
+/// [`dispatch_event`]: #method.dispatch_event
+/// [`dispatch_event_concurrent`]: #method.dispatch_event_concurrent
+pub struct AsyncDispatcher<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    events: RwLock<HashMap<T, AsyncListeners<T>>>,
+}
+
+impl<T> Default for AsyncDispatcher<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn default() -> AsyncDispatcher<T> {
+        AsyncDispatcher {
+            events: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl<T> AsyncDispatcher<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Adds an [`AsyncListener`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// [`AsyncListener`]: trait.AsyncListener.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_listener<D>(&self, event_identifier: T, listener: &Arc<D>) -> ListenerHandle
+    where
+        D: AsyncListener<T> + Send + Sync + 'static,
+    {
+        let listener = Arc::clone(listener) as Arc<dyn AsyncListener<T> + Send + Sync + 'static>;
+        let registered = Registered::new(Arc::downgrade(&listener));
+        let handle = registered.handle;
+
+        self.events
+            .write()
+            .entry(event_identifier)
+            .or_default()
+            .push(registered);
+
+        handle
+    }
+
+    /// Removes the listener registered with `handle`, the order of all
+    /// other listeners stays untouched.
+    /// Returns `false` if `handle` is not registered on this dispatcher.
+    pub fn remove_listener(&self, handle: ListenerHandle) -> bool {
+        self.events
+            .write()
+            .values_mut()
+            .any(|listeners| remove_registered(listeners, handle))
+    }
+
+    /// All [`AsyncListener`]s listening to a passed `event_identifier`
+    /// will be called and awaited one after another in the order they
+    /// have been added.
+    ///
+    /// Listeners requesting to stop listening are removed once all
+    /// listeners have been awaited, a listener added while the returned
+    /// future is pending will not receive the current event.
+    ///
+    /// [`AsyncListener`]: trait.AsyncListener.html
+    pub async fn dispatch_event(&self, event_identifier: &T) {
+        let listeners = self.live_listeners(event_identifier);
+        let mut stopped_listening = Vec::new();
+
+        for (handle, listener) in &listeners {
+            match listener.on_event(event_identifier).await {
+                None => (),
+                Some(AsyncDispatcherRequest::StopListening) => stopped_listening.push(*handle),
+                Some(AsyncDispatcherRequest::StopPropagation) => break,
+                Some(AsyncDispatcherRequest::StopListeningAndPropagation) => {
+                    stopped_listening.push(*handle);
+                    break;
+                }
+            }
+        }
+
+        self.remove_stopped(event_identifier, &stopped_listening);
+    }
+
+    /// All [`AsyncListener`]s listening to a passed `event_identifier`
+    /// will be called at once and their futures polled concurrently until
+    /// all of them have completed, favouring throughput of I/O-bound
+    /// listeners over the ordering of [`dispatch_event`].
+    ///
+    /// **Note**: Listeners must tolerate running concurrently with each
+    /// other. They are started in registration-order, but from their first
+    /// `.await` on, they progress and complete in no guaranteed order.
+    /// All futures are polled on the task awaiting this dispatch, use
+    /// your executor's spawning to run listeners in parallel instead.
+    ///
+    /// As every listener has been started already, requests to stop
+    /// propagation are ignored, requests to stop listening are honoured
+    /// once all listeners have completed.
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`AsyncListener`]: trait.AsyncListener.html
+    pub async fn dispatch_event_concurrent(&self, event_identifier: &T) {
+        let listeners = self.live_listeners(event_identifier);
+
+        let requests = JoinAll::new(
+            listeners
+                .iter()
+                .map(|(_, listener)| listener.on_event(event_identifier))
+                .collect(),
+        )
+        .await;
+
+        let stopped_listening: Vec<ListenerHandle> = listeners
+            .iter()
+            .zip(requests)
+            .filter_map(|((handle, _), request)| match request {
+                Some(AsyncDispatcherRequest::StopListening)
+                | Some(AsyncDispatcherRequest::StopListeningAndPropagation) => Some(*handle),
+                Some(AsyncDispatcherRequest::StopPropagation) | None => None,
+            })
+            .collect();
+
+        self.remove_stopped(event_identifier, &stopped_listening);
+    }
+
+    /// Upgrades all listeners of `event_identifier`, so no lock is held
+    /// while awaiting them, and drops the registrations of dead ones.
+    fn live_listeners(
+        &self,
+        event_identifier: &T,
+    ) -> Vec<(
+        ListenerHandle,
+        Arc<dyn AsyncListener<T> + Send + Sync + 'static>,
+    )> {
+        let mut events = self.events.write();
+
+        let registrations = match events.get_mut(event_identifier) {
+            Some(registrations) => registrations,
+            None => return Vec::new(),
+        };
+
+        registrations.retain(|registered| registered.listener.strong_count() > 0);
+
+        registrations
+            .iter()
+            .filter_map(|registered| {
+                registered
+                    .listener
+                    .upgrade()
+                    .map(|listener| (registered.handle, listener))
+            })
+            .collect()
+    }
+
+    fn remove_stopped(&self, event_identifier: &T, handles: &[ListenerHandle]) {
+        if handles.is_empty() {
+            return;
+        }
+
+        if let Some(registrations) = self.events.write().get_mut(event_identifier) {
+            registrations.retain(|registered| !handles.contains(&registered.handle));
+        }
+    }
+}
+
+/// Polls all `futures` until every one of them has completed and yields
+/// their outputs in the order of `futures`.
+struct JoinAll<'a> {
+    futures: Vec<Option<AsyncListenerFuture<'a>>>,
+    outputs: Vec<Option<AsyncDispatcherRequest>>,
+}
+
+impl<'a> JoinAll<'a> {
+    fn new(futures: Vec<AsyncListenerFuture<'a>>) -> Self {
+        JoinAll {
+            outputs: vec![None; futures.len()],
+            futures: futures.into_iter().map(Some).collect(),
+        }
+    }
+}
+
+impl<'a> Future for JoinAll<'a> {
+    type Output = Vec<Option<AsyncDispatcherRequest>>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+        let join = self.get_mut();
+        let mut pending = false;
+
+        for (future_slot, output) in join.futures.iter_mut().zip(join.outputs.iter_mut()) {
+            if let Some(future) = future_slot {
+                match future.as_mut().poll(context) {
+                    Poll::Ready(request) => {
+                        *output = request;
+                        *future_slot = None;
+                    }
+                    Poll::Pending => pending = true,
+                }
+            }
+        }
+
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(mem::take(&mut join.outputs))
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use super::{Listener, ParallelDispatcherRequest, ParallelListener, SyncDispatcherRequest};
+use std::hash::Hash;
+use tokio::sync::broadcast::{self, Receiver, Sender};
+
+/// A [`Listener`] and [`ParallelListener`] sending a clone of every event
+/// it receives into a [`tokio::sync::broadcast`]-channel, e.g. to hand
+/// events over to any number of tasks, each receiving via its own
+/// [`Receiver`] obtained from [`subscribe`].
+/// Only available with the `tokio`-feature enabled.
+///
+/// The bridge keeps listening even without any receiver left, events
+/// sent meanwhile are dropped.
+/// Receivers lagging behind by more than the channel's capacity miss the
+/// oldest events, see [`tokio::sync::broadcast`].
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::{
+///     sync::{BroadcastBridge, Dispatcher},
+///     RwLock,
+/// };
+/// use std::sync::Arc;
+///
+/// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Save,
+/// }
+///
+/// fn main() {
+///     let bridge = Arc::new(RwLock::new(BroadcastBridge::new(16)));
+///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+///     dispatcher.add_listener(Event::Save, &bridge);
+///
+///     let mut first = bridge.read().subscribe();
+///     let mut second = bridge.read().subscribe();
+///     dispatcher.dispatch_event(&Event::Save);
+///
+///     assert_eq!(first.try_recv(), Ok(Event::Save));
+///     assert_eq!(second.try_recv(), Ok(Event::Save));
+/// }
+/// ```
+///
+/// [`Listener`]: trait.Listener.html
+/// [`ParallelListener`]: trait.ParallelListener.html
+/// [`tokio::sync::broadcast`]: https://docs.rs/tokio/1/tokio/sync/broadcast/index.html
+/// [`Receiver`]: https://docs.rs/tokio/1/tokio/sync/broadcast/struct.Receiver.html
+/// [`subscribe`]: #method.subscribe
+#[derive(Debug)]
+pub struct BroadcastBridge<T> {
+    sender: Sender<T>,
+}
+
+impl<T: Clone> BroadcastBridge<T> {
+    /// Creates a bridge into a new channel buffering up to `capacity`
+    /// events per receiver.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`, like [`tokio::sync::broadcast::channel`].
+    ///
+    /// [`tokio::sync::broadcast::channel`]: https://docs.rs/tokio/1/tokio/sync/broadcast/fn.channel.html
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+
+        BroadcastBridge { sender }
+    }
+
+    /// Creates a bridge into the channel of `sender`, e.g. to share one
+    /// channel between several bridges or other producers.
+    pub fn from_sender(sender: Sender<T>) -> Self {
+        BroadcastBridge { sender }
+    }
+
+    /// Returns a [`Receiver`] for all events the bridge receives from now on.
+    ///
+    /// [`Receiver`]: https://docs.rs/tokio/1/tokio/sync/broadcast/struct.Receiver.html
+    pub fn subscribe(&self) -> Receiver<T> {
+        self.sender.subscribe()
+    }
+
+    /// Returns a [`Sender`] into the bridge's channel.
+    ///
+    /// [`Sender`]: https://docs.rs/tokio/1/tokio/sync/broadcast/struct.Sender.html
+    pub fn sender(&self) -> Sender<T> {
+        self.sender.clone()
+    }
+
+    /// Returns how many receivers the bridge broadcasts to.
+    pub fn receiver_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+
+    /// Sends `event` to every receiver, dropping it if there is none.
+    fn broadcast(&self, event: &T) {
+        let _ = self.sender.send(event.clone());
+    }
+}
+
+impl<T> Listener<T> for BroadcastBridge<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + 'static,
+{
+    fn on_event(&mut self, event: &T) -> Option<SyncDispatcherRequest> {
+        self.broadcast(event);
+
+        None
+    }
+}
+
+impl<T> ParallelListener<T> for BroadcastBridge<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + 'static,
+{
+    fn on_event(&mut self, event: &T) -> Option<ParallelDispatcherRequest<T>> {
+        self.broadcast(event);
+
+        None
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    hash::{Hash, Hasher},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// An event that listeners can cancel, similar to `preventDefault` in the
+/// DOM, dispatched via [`Dispatcher::dispatch_cancelable`] or
+/// [`PriorityDispatcher::dispatch_cancelable`].
+///
+/// Listeners cancel it via [`cancel`], later listeners can tell via
+/// [`is_cancelled`]. Created via [`new`] with `stop_on_cancel`, the
+/// dispatcher does not call any further listener once it has been
+/// cancelled, otherwise all remaining listeners are still informed.
+///
+/// Hashing and comparing only considers the wrapped event, hence listeners
+/// are registered for a `Cancelable` of the event they listen to.
+/// Clones share their cancellation with the original.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::sync::{Cancelable, Dispatcher};
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     WindowClose,
+/// }
+///
+/// fn main() {
+///     let mut dispatcher: Dispatcher<Cancelable<Event>> = Dispatcher::default();
+///     let close = Cancelable::new(Event::WindowClose, true);
+///
+///     dispatcher.add_fn(
+///         close.clone(),
+///         Box::new(|event| {
+///             event.cancel();
+///
+///             None
+///         }),
+///     );
+///     dispatcher.add_fn(close.clone(), Box::new(|_| panic!("cancelled before")));
+///
+///     assert!(dispatcher.dispatch_cancelable(&close));
+/// }
+/// ```
+///
+/// [`Dispatcher::dispatch_cancelable`]: struct.Dispatcher.html#method.dispatch_cancelable
+/// [`PriorityDispatcher::dispatch_cancelable`]: struct.PriorityDispatcher.html#method.dispatch_cancelable
+/// [`cancel`]: #method.cancel
+/// [`is_cancelled`]: #method.is_cancelled
+/// [`new`]: #method.new
+#[derive(Clone, Debug)]
+pub struct Cancelable<E> {
+    event: E,
+    cancelled: Arc<AtomicBool>,
+    stop_on_cancel: bool,
+}
+
+impl<E> Cancelable<E> {
+    /// Wraps `event`, not cancelled yet.
+    /// If `stop_on_cancel` is `true`, cancelling stops propagation,
+    /// otherwise all listeners are called regardless.
+    pub fn new(event: E, stop_on_cancel: bool) -> Self {
+        Cancelable {
+            event,
+            cancelled: Arc::new(AtomicBool::new(false)),
+            stop_on_cancel,
+        }
+    }
+
+    /// Returns the wrapped event.
+    pub fn event(&self) -> &E {
+        &self.event
+    }
+
+    /// Marks the event as cancelled.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns whether a listener has cancelled the event.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Returns whether cancelling stops propagation.
+    pub fn stops_on_cancel(&self) -> bool {
+        self.stop_on_cancel
+    }
+
+    /// Whether the dispatcher must not call any further listener.
+    pub(crate) fn is_halted(&self) -> bool {
+        self.stop_on_cancel && self.is_cancelled()
+    }
+}
+
+impl<E: Hash> Hash for Cancelable<E> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.event.hash(state);
+    }
+}
+
+impl<E: PartialEq> PartialEq for Cancelable<E> {
+    fn eq(&self, other: &Cancelable<E>) -> bool {
+        self.event == other.event
+    }
+}
+
+impl<E: Eq> Eq for Cancelable<E> {}
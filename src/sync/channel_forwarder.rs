@@ -0,0 +1,158 @@
+use super::{Listener, ParallelDispatcherRequest, ParallelListener, SyncDispatcherRequest};
+use std::{
+    hash::Hash,
+    sync::{
+        mpsc::{Sender, SyncSender},
+        Arc,
+    },
+};
+
+/// Either side of a standard channel events are forwarded into.
+#[derive(Clone, Debug)]
+enum ForwardingSender<T> {
+    Unbounded(Sender<T>),
+    Bounded(SyncSender<T>),
+}
+
+/// A [`Listener`] and [`ParallelListener`] sending a clone of every
+/// event it receives into a channel, e.g. to hand events over to another
+/// thread owning the [`Receiver`].
+///
+/// Once the [`Receiver`] has been dropped, the forwarder requests to stop
+/// listening, hence dead forwarders unregister themselves on their next
+/// event.
+///
+/// A forwarder into a channel of [`Arc<T>`] listens to events of type `T`
+/// as well, wrapping each event into an [`Arc`] instead of sending it on
+/// its own. The event is still cloned once, but receivers can share it
+/// without cloning it again, which pays off for large events that are
+/// passed on or kept around. Receivers only reading events briefly are
+/// better off with the plain events.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::{
+///     sync::{ChannelForwarder, Dispatcher},
+///     RwLock,
+/// };
+/// use std::sync::{mpsc::channel, Arc};
+///
+/// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Save,
+/// }
+///
+/// fn main() {
+///     let (sender, receiver) = channel();
+///     let forwarder = Arc::new(RwLock::new(ChannelForwarder::new(sender)));
+///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+///     dispatcher.add_listener(Event::Save, &forwarder);
+///
+///     dispatcher.dispatch_event(&Event::Save);
+///
+///     assert_eq!(receiver.try_recv(), Ok(Event::Save));
+/// }
+/// ```
+///
+/// [`Listener`]: trait.Listener.html
+/// [`ParallelListener`]: trait.ParallelListener.html
+/// [`Receiver`]: https://doc.rust-lang.org/std/sync/mpsc/struct.Receiver.html
+/// [`Arc<T>`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+/// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+#[derive(Clone, Debug)]
+pub struct ChannelForwarder<T> {
+    sender: ForwardingSender<T>,
+}
+
+impl<T> ChannelForwarder<T> {
+    /// Forwards events into the unbounded channel of `sender`.
+    pub fn new(sender: Sender<T>) -> Self {
+        ChannelForwarder {
+            sender: ForwardingSender::Unbounded(sender),
+        }
+    }
+
+    /// Forwards events into the bounded channel of `sender`.
+    ///
+    /// **Note**: Forwarding blocks the dispatch while the channel is full.
+    pub fn bounded(sender: SyncSender<T>) -> Self {
+        ChannelForwarder {
+            sender: ForwardingSender::Bounded(sender),
+        }
+    }
+
+    /// Sends `event`, requesting to stop listening once the receiving
+    /// side has been dropped.
+    pub(crate) fn forward(&self, event: T) -> Option<SyncDispatcherRequest> {
+        let sent = match &self.sender {
+            ForwardingSender::Unbounded(sender) => sender.send(event).is_ok(),
+            ForwardingSender::Bounded(sender) => sender.send(event).is_ok(),
+        };
+
+        if sent {
+            None
+        } else {
+            Some(SyncDispatcherRequest::StopListening)
+        }
+    }
+}
+
+impl<T> From<Sender<T>> for ChannelForwarder<T> {
+    fn from(sender: Sender<T>) -> Self {
+        ChannelForwarder::new(sender)
+    }
+}
+
+impl<T> From<SyncSender<T>> for ChannelForwarder<T> {
+    fn from(sender: SyncSender<T>) -> Self {
+        ChannelForwarder::bounded(sender)
+    }
+}
+
+/// Translates a request of [`ChannelForwarder::forward`] for the
+/// [`ParallelDispatcher`].
+///
+/// [`ChannelForwarder::forward`]: struct.ChannelForwarder.html#method.forward
+/// [`ParallelDispatcher`]: struct.ParallelDispatcher.html
+fn parallel_request<T>(
+    request: Option<SyncDispatcherRequest>,
+) -> Option<ParallelDispatcherRequest<T>> {
+    request.map(|_| ParallelDispatcherRequest::StopListening)
+}
+
+impl<T> Listener<T> for ChannelForwarder<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + 'static,
+{
+    fn on_event(&mut self, event: &T) -> Option<SyncDispatcherRequest> {
+        self.forward(event.clone())
+    }
+}
+
+impl<T> ParallelListener<T> for ChannelForwarder<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + 'static,
+{
+    fn on_event(&mut self, event: &T) -> Option<ParallelDispatcherRequest<T>> {
+        parallel_request(self.forward(event.clone()))
+    }
+}
+
+impl<T> Listener<T> for ChannelForwarder<Arc<T>>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn on_event(&mut self, event: &T) -> Option<SyncDispatcherRequest> {
+        self.forward(Arc::new(event.clone()))
+    }
+}
+
+impl<T> ParallelListener<T> for ChannelForwarder<Arc<T>>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn on_event(&mut self, event: &T) -> Option<ParallelDispatcherRequest<T>> {
+        parallel_request(self.forward(Arc::new(event.clone())))
+    }
+}
@@ -0,0 +1,89 @@
+use super::{DispatchResult, ListenerHandle, SyncDispatcherRequest};
+use std::hash::Hash;
+
+/// Observes the dispatches of a [`Dispatcher`], set via
+/// [`Dispatcher::set_tracer`], e.g. to open a span per dispatch and log
+/// every listener invocation with a tracing- or logging-framework.
+///
+/// All methods do nothing by default, implementors override the ones they
+/// are interested in. As every method receives the event, implementors
+/// can include its `Debug`-representation whenever `T` offers one.
+/// Only available with the `tracing`-feature enabled, every dispatcher
+/// starts with a [`SpanTracer`] reporting to `tracing`.
+///
+/// A dispatch ended by a panicking listener is still reported to [`exit`]
+/// while unwinding, counting the listeners that returned until then.
+///
+/// **Note**: Only dispatches reaching the listeners are traced, events
+/// dropped by a pre-dispatch hook, debounced or throttled are not.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::sync::{DispatchResult, DispatchTracer, Dispatcher};
+/// use std::sync::{Arc, Mutex};
+///
+/// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Save,
+/// }
+///
+/// struct Log(Arc<Mutex<Vec<String>>>);
+///
+/// impl DispatchTracer<Event> for Log {
+///     fn enter(&self, event: &Event, listeners: usize) {
+///         self.0
+///             .lock()
+///             .unwrap()
+///             .push(format!("dispatch {:?} to {}", event, listeners));
+///     }
+///
+///     fn exit(&self, _event: &Event, result: DispatchResult) {
+///         self.0
+///             .lock()
+///             .unwrap()
+///             .push(format!("invoked {}", result.invoked));
+///     }
+/// }
+///
+/// fn main() {
+///     let log = Arc::new(Mutex::new(Vec::new()));
+///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+///     dispatcher.add_fn(Event::Save, Box::new(|_| None));
+///     dispatcher.set_tracer(Log(Arc::clone(&log)));
+///
+///     dispatcher.dispatch_event(&Event::Save);
+///
+///     assert_eq!(*log.lock().unwrap(), ["dispatch Save to 1", "invoked 1"]);
+/// }
+/// ```
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`Dispatcher::set_tracer`]: struct.Dispatcher.html#method.set_tracer
+/// [`SpanTracer`]: struct.SpanTracer.html
+/// [`exit`]: #method.exit
+pub trait DispatchTracer<T>: Send + Sync
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Called once `event` is about to reach its `listeners` enabled
+    /// registrations, before any of them is called.
+    fn enter(&self, _event: &T, _listeners: usize) {}
+
+    /// Called after the registration of `handle`, named `name` if it has
+    /// been added via [`Dispatcher::add_named_listener`], has been called
+    /// with `event` and returned `request`.
+    ///
+    /// [`Dispatcher::add_named_listener`]: struct.Dispatcher.html#method.add_named_listener
+    fn invoked(
+        &self,
+        _event: &T,
+        _handle: ListenerHandle,
+        _name: Option<&str>,
+        _request: Option<&SyncDispatcherRequest>,
+    ) {
+    }
+
+    /// Called once the dispatch of `event` is done, with its `result`.
+    fn exit(&self, _event: &T, _result: DispatchResult) {}
+}
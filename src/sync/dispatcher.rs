@@ -1,23 +1,261 @@
 use super::{
-    execute_sync_dispatcher_requests, FnsAndTraits, Listener, ListenerMap, RwLock,
-    SyncDispatcherRequest,
+    cancelable::Cancelable, channel_forwarder::ChannelForwarder, event_sender::EventChannel,
+    execute_sync_dispatcher_requests, removes_listener, DispatchResult, DispatcherSnapshot,
+    EventSender, ExecuteRequestsResult, FnsAndTraits, Listener, ListenerHandle, ListenerMap,
+    MappedDispatcher, RegisterError, Registered, RwLock, Subscription, SyncDispatcherRequest,
+    SyncFn, VetoReason, UNNAMED_LISTENER,
 };
+#[cfg(feature = "tracing")]
+use super::{dispatch_tracer::DispatchTracer, span_tracer::SpanTracer};
+use parking_lot::Mutex;
+use smallvec::smallvec;
 use std::{
-    hash::Hash,
-    sync::{Arc, Weak},
+    cell::RefCell,
+    cmp::Ordering,
+    collections::{hash_map::RandomState, HashMap, HashSet, VecDeque},
+    hash::{BuildHasher, Hash},
+    mem,
+    sync::{
+        atomic::{AtomicUsize, Ordering as AtomicOrdering},
+        mpsc::{channel, Sender},
+        Arc, Weak,
+    },
+    time::{Duration, Instant},
 };
 
+type HandleOrdering = Arc<dyn Fn(ListenerHandle, ListenerHandle) -> Ordering + Send + Sync>;
+type PreDispatch<T> = Arc<Mutex<dyn FnMut(T) -> Option<T> + Send + Sync>>;
+type Middleware<T> = Arc<Mutex<dyn FnMut(&T) -> MiddlewareDecision<T> + Send + Sync>>;
+type Validator<T> = Arc<Mutex<dyn FnMut(&T) -> Result<(), VetoReason> + Send + Sync>>;
+type Clock = Arc<dyn Fn() -> Instant + Send + Sync>;
+#[cfg(feature = "tracing")]
+type Tracer<T> = Arc<dyn DispatchTracer<T>>;
+
+thread_local! {
+    /// Addresses of the dispatchers with forwarding targets that are
+    /// calling their listeners on this thread, innermost last.
+    static FORWARDING: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// How a [`Dispatcher`] treats a [`Listener`] added to an event-variant
+/// it is already registered for, compared by their [`Arc`]s.
+/// Defaults to `Allow`.
+///
+/// `Allow`: Registers the listener once more, it will be called once
+/// per registration.
+/// `Ignore`: Keeps the existing registration only and returns its handle.
+/// `Error`: Like `Ignore`, but [`Dispatcher::try_add_listener`] returns
+/// `RegisterError::Duplicate`.
+///
+/// **Note**: [`Fn`]s cannot be compared and are always registered.
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`Listener`]: trait.Listener.html
+/// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+/// [`Dispatcher::try_add_listener`]: struct.Dispatcher.html#method.try_add_listener
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DuplicatePolicy {
+    #[default]
+    Allow,
+    Ignore,
+    Error,
+}
+
+/// What a [`Dispatcher`] does with events of a throttled event-variant
+/// dispatched before its minimal interval has passed,
+/// see [`Dispatcher::set_throttle`].
+///
+/// `Drop`: Discards them.
+/// `Trailing`: Keeps the latest of them, to be delivered by
+/// [`Dispatcher::flush_throttled`] once the interval has passed.
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`Dispatcher::set_throttle`]: struct.Dispatcher.html#method.set_throttle
+/// [`Dispatcher::flush_throttled`]: struct.Dispatcher.html#method.flush_throttled
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ThrottlePolicy {
+    Drop,
+    Trailing,
+}
+
+/// What a middleware added via [`Dispatcher::add_middleware`] decides
+/// about an event before any listener is called.
+///
+/// `Continue`: Passes the event on unchanged.
+/// `ReplaceWith`: Passes the contained event on instead, later middleware
+/// and the listeners of its event-variant receive it.
+/// `Drop`: Discards the event, no further middleware or listener is called.
+///
+/// [`Dispatcher::add_middleware`]: struct.Dispatcher.html#method.add_middleware
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MiddlewareDecision<T> {
+    Continue,
+    ReplaceWith(T),
+    Drop,
+}
+
 /// In charge of sync dispatching to all listeners.
 /// Owns a map event-variants and
 /// [`Weak`]-references to their listeners and/or owns [`Fn`]s.
 ///
+/// The event-variants are hashed with `S`, defaulting to
+/// [`RandomState`] just like [`HashMap`] does.
+///
 /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
 /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
-pub struct Dispatcher<T>
+/// [`RandomState`]: https://doc.rust-lang.org/std/collections/hash_map/struct.RandomState.html
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+pub struct Dispatcher<T, S = RandomState>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    events: ListenerMap<T, S>,
+    ordering: Option<HandleOrdering>,
+    pre_dispatch: Option<PreDispatch<T>>,
+    middleware: Vec<Middleware<T>>,
+    validator: Option<Validator<T>>,
+    duplicate_policy: DuplicatePolicy,
+    channel: Option<EventChannel<T>>,
+    channel_capacity: Option<usize>,
+    sticky: HashMap<T, T>,
+    debounced: HashMap<T, Debounce<T>>,
+    throttled: HashMap<T, Throttle<T>>,
+    clock: Option<Clock>,
+    forwarding: bool,
+    names: HashMap<ListenerHandle, String>,
+    #[cfg(feature = "tracing")]
+    tracer: Option<Tracer<T>>,
+    history: Option<History<T>>,
+}
+
+/// The most recently dispatched events, oldest first.
+#[derive(Clone)]
+struct History<T> {
+    events: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T> History<T> {
+    fn record(&mut self, event: T) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+        }
+
+        self.events.push_back(event);
+    }
+}
+
+/// The debounce-window of an event-variant, along with its latest
+/// undelivered event and when it arrived.
+#[derive(Clone)]
+struct Debounce<T> {
+    window: Duration,
+    pending: Option<(T, Instant)>,
+}
+
+/// The minimal interval of an event-variant, along with when it has been
+/// delivered last and its latest suppressed event under
+/// `ThrottlePolicy::Trailing`.
+#[derive(Clone)]
+struct Throttle<T> {
+    min_interval: Duration,
+    policy: ThrottlePolicy,
+    delivered: Option<Instant>,
+    trailing: Option<T>,
+}
+
+impl<T> Throttle<T> {
+    /// Whether the interval since the last delivery has passed at `now`.
+    fn is_open(&self, now: Instant) -> bool {
+        self.delivered.map_or(true, |delivered| {
+            now.saturating_duration_since(delivered) >= self.min_interval
+        })
+    }
+}
+
+/// Creates a dispatcher sharing all registrations with this one:
+/// Both dispatch to the same [`Listener`]s and [`Fn`]s, which are not
+/// duplicated, as well as share comparator and pre-dispatch hook.
+/// A listener's state is hence observed by both dispatchers, e.g. a
+/// [`Listener`] counting its events counts those of both.
+///
+/// Registrations are copied, however, the [`ListenerHandle`]s handed
+/// out so far identify the registrations on both dispatchers.
+/// From there on, their handle namespaces diverge:
+/// Adding, removing, or a listener requesting to stop listening only
+/// affects the dispatcher it happens on.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::sync::Dispatcher;
+/// use std::sync::{
+///     atomic::{AtomicUsize, Ordering},
+///     Arc,
+/// };
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     EventType,
+/// }
+///
+/// fn main() {
+///     let calls = Arc::new(AtomicUsize::new(0));
+///     let fn_calls = Arc::clone(&calls);
+///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+///     let handle = dispatcher.add_fn(
+///         Event::EventType,
+///         Box::new(move |_| {
+///             fn_calls.fetch_add(1, Ordering::SeqCst);
+///
+///             None
+///         }),
+///     );
+///
+///     let mut fork = dispatcher.clone();
+///     assert!(fork.remove_listener(handle));
+///
+///     dispatcher.dispatch_event(&Event::EventType);
+///     fork.dispatch_event(&Event::EventType);
+///
+///     assert_eq!(calls.load(Ordering::SeqCst), 1);
+/// }
+/// ```
+///
+/// [`Listener`]: trait.Listener.html
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+/// [`ListenerHandle`]: struct.ListenerHandle.html
+impl<T, S> Clone for Dispatcher<T, S>
 where
     T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    S: Clone,
 {
-    events: ListenerMap<T>,
+    fn clone(&self) -> Self {
+        Dispatcher {
+            events: self.events.clone(),
+            ordering: self.ordering.clone(),
+            pre_dispatch: self.pre_dispatch.clone(),
+            middleware: self.middleware.clone(),
+            validator: self.validator.clone(),
+            duplicate_policy: self.duplicate_policy,
+            channel: None,
+            channel_capacity: self.channel_capacity,
+            sticky: self.sticky.clone(),
+            debounced: self.debounced.clone(),
+            throttled: self.throttled.clone(),
+            clock: self.clock.clone(),
+            forwarding: self.forwarding,
+            names: self.names.clone(),
+            #[cfg(feature = "tracing")]
+            tracer: self.tracer.clone(),
+            history: self.history.clone(),
+        }
+    }
 }
 
 impl<T> Default for Dispatcher<T>
@@ -25,18 +263,89 @@ where
     T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
 {
     fn default() -> Dispatcher<T> {
+        Dispatcher::with_hasher(RandomState::new())
+    }
+}
+
+impl<T> Dispatcher<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Creates a dispatcher whose [`EventSender`]s hold at most `capacity`
+    /// events not yet polled, sending more blocks until the dispatcher
+    /// has been polled.
+    ///
+    /// **Note**: Use `Dispatcher::default` for an unbounded channel.
+    ///
+    /// [`EventSender`]: struct.EventSender.html
+    pub fn with_channel_capacity(capacity: usize) -> Self {
         Dispatcher {
-            events: ListenerMap::new(),
+            channel_capacity: Some(capacity),
+            ..Dispatcher::default()
         }
     }
 }
 
-impl<T> Dispatcher<T>
+impl<T, S> Dispatcher<T, S>
 where
     T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    S: BuildHasher,
 {
+    /// Creates a dispatcher hashing event-variants via `hasher`,
+    /// similar to [`HashMap::with_hasher`].
+    /// Faster hashers can speed up dispatching, especially for
+    /// simple event-types.
+    ///
+    /// **Note**: Use `Dispatcher::default` to keep the standard hasher.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::Dispatcher;
+    /// use std::{collections::hash_map::DefaultHasher, hash::BuildHasherDefault};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event, BuildHasherDefault<DefaultHasher>> =
+    ///         Dispatcher::with_hasher(BuildHasherDefault::default());
+    ///
+    ///     dispatcher.dispatch_event(&Event::EventType);
+    /// }
+    /// ```
+    ///
+    /// [`HashMap::with_hasher`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html#method.with_hasher
+    pub fn with_hasher(hasher: S) -> Self {
+        Dispatcher {
+            events: ListenerMap::with_hasher(hasher),
+            ordering: None,
+            pre_dispatch: None,
+            middleware: Vec::new(),
+            validator: None,
+            duplicate_policy: DuplicatePolicy::default(),
+            channel: None,
+            channel_capacity: None,
+            sticky: HashMap::new(),
+            debounced: HashMap::new(),
+            throttled: HashMap::new(),
+            clock: None,
+            forwarding: false,
+            names: HashMap::new(),
+            #[cfg(feature = "tracing")]
+            tracer: Some(Arc::new(SpanTracer::without_payload())),
+            history: None,
+        }
+    }
+
     /// Adds a [`Listener`] to listen for an `event_identifier`.
     /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    /// Unless the [`DuplicatePolicy`] is `Allow`, a listener that is
+    /// already registered for `event_identifier` is not registered again
+    /// and the existing registration's handle is returned.
     ///
     /// **Note**: If your `Enum` owns fields you need to consider implementing
     /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields,
@@ -101,29 +410,287 @@ where
     /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
     /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
     /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    /// [`DuplicatePolicy`]: enum.DuplicatePolicy.html
     pub fn add_listener<D: Listener<T> + Send + Sync + 'static>(
         &mut self,
         event_identifier: T,
         listener: &Arc<RwLock<D>>,
-    ) {
+    ) -> ListenerHandle {
+        self.add_trait_object(
+            event_identifier,
+            Arc::clone(listener) as Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>,
+        )
+    }
+
+    /// Adds a [`Listener`] like [`add_listener`], labelled with `name`.
+    /// The label is kept alongside the registration, listed by
+    /// [`listener_names`] and returned by [`listener_name`], to tell
+    /// listeners apart in diagnostics instead of by their handles.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{
+    ///     sync::{Dispatcher, Listener, SyncDispatcherRequest},
+    ///     RwLock,
+    /// };
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Save,
+    /// }
+    ///
+    /// struct Autosave;
+    ///
+    /// impl Listener<Event> for Autosave {
+    ///     fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let autosave = Arc::new(RwLock::new(Autosave));
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let handle = dispatcher.add_named_listener(Event::Save, "autosave", &autosave);
+    ///
+    ///     assert_eq!(dispatcher.listener_name(handle), Some("autosave"));
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`add_listener`]: #method.add_listener
+    /// [`listener_names`]: #method.listener_names
+    /// [`listener_name`]: #method.listener_name
+    pub fn add_named_listener<D: Listener<T> + Send + Sync + 'static>(
+        &mut self,
+        event_identifier: T,
+        name: impl Into<String>,
+        listener: &Arc<RwLock<D>>,
+    ) -> ListenerHandle {
+        let handle = self.add_listener(event_identifier, listener);
+        self.names.insert(handle, name.into());
+
+        handle
+    }
+
+    /// Returns the name the registration of `handle` has been added with
+    /// via [`add_named_listener`], `None` if it is unnamed or no longer
+    /// registered.
+    ///
+    /// [`add_named_listener`]: #method.add_named_listener
+    pub fn listener_name(&self, handle: ListenerHandle) -> Option<&str> {
+        self.names
+            .get(&handle)
+            .filter(|_| self.has_listener(handle))
+            .map(String::as_str)
+    }
+
+    /// Adds a [`Listener`] like [`add_listener`] and, if a sticky event
+    /// equal to `event_identifier` has been dispatched via
+    /// [`dispatch_sticky`], immediately calls `listener` with the most
+    /// recent one.
+    /// A request to stop listening returned from this call removes the
+    /// registration right away, the returned handle is not registered then.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{
+    ///     sync::{Dispatcher, Listener, SyncDispatcherRequest},
+    ///     RwLock,
+    /// };
+    /// use std::{
+    ///     hash::{Hash, Hasher},
+    ///     sync::Arc,
+    /// };
+    ///
+    /// #[derive(Clone, Debug)]
+    /// struct Resolution(u32, u32);
+    ///
+    /// impl Hash for Resolution {
+    ///     fn hash<H: Hasher>(&self, _state: &mut H) {}
+    /// }
+    ///
+    /// impl PartialEq for Resolution {
+    ///     fn eq(&self, _other: &Resolution) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// impl Eq for Resolution {}
+    ///
+    /// struct Viewport {
+    ///     width: u32,
+    /// }
+    ///
+    /// impl Listener<Resolution> for Viewport {
+    ///     fn on_event(&mut self, event: &Resolution) -> Option<SyncDispatcherRequest> {
+    ///         self.width = event.0;
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Resolution> = Dispatcher::default();
+    ///     dispatcher.dispatch_sticky(Resolution(800, 600));
+    ///     dispatcher.dispatch_sticky(Resolution(1920, 1080));
+    ///
+    ///     let viewport = Arc::new(RwLock::new(Viewport { width: 0 }));
+    ///     dispatcher.add_listener_sticky(Resolution(0, 0), &viewport);
+    ///
+    ///     assert_eq!(viewport.read().width, 1920);
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`add_listener`]: #method.add_listener
+    /// [`dispatch_sticky`]: #method.dispatch_sticky
+    pub fn add_listener_sticky<D: Listener<T> + Send + Sync + 'static>(
+        &mut self,
+        event_identifier: T,
+        listener: &Arc<RwLock<D>>,
+    ) -> ListenerHandle {
+        let handle = self.add_listener(event_identifier.clone(), listener);
+
+        if let Some(sticky_event) = self.sticky.get(&event_identifier) {
+            let request = listener.write().on_event(sticky_event);
+
+            if removes_listener(&request) {
+                self.remove_listener(handle);
+            }
+        }
+
+        handle
+    }
+
+    /// Adds a [`Listener`] like [`add_listener`], but returns
+    /// `RegisterError::Duplicate` if `listener` is already registered for
+    /// `event_identifier` and the [`DuplicatePolicy`] is `Error`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{
+    ///     sync::{Dispatcher, DuplicatePolicy, Listener, RegisterError, SyncDispatcherRequest},
+    ///     RwLock,
+    /// };
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct ListenerStruct;
+    ///
+    /// impl Listener<Event> for ListenerStruct {
+    ///     fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let listener = Arc::new(RwLock::new(ListenerStruct));
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.set_duplicate_policy(DuplicatePolicy::Error);
+    ///
+    ///     let handle = dispatcher.try_add_listener(Event::EventType, &listener).unwrap();
+    ///
+    ///     assert_eq!(
+    ///         dispatcher.try_add_listener(Event::EventType, &listener),
+    ///         Err(RegisterError::Duplicate(handle))
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`add_listener`]: #method.add_listener
+    /// [`DuplicatePolicy`]: enum.DuplicatePolicy.html
+    pub fn try_add_listener<D: Listener<T> + Send + Sync + 'static>(
+        &mut self,
+        event_identifier: T,
+        listener: &Arc<RwLock<D>>,
+    ) -> Result<ListenerHandle, RegisterError> {
+        self.try_add_trait_object(
+            event_identifier,
+            Arc::clone(listener) as Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>,
+        )
+    }
+
+    /// Sets how to treat a [`Listener`] added to an event-variant it is
+    /// already registered for, see [`DuplicatePolicy`].
+    /// Existing registrations are kept as they are.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`DuplicatePolicy`]: enum.DuplicatePolicy.html
+    pub fn set_duplicate_policy(&mut self, policy: DuplicatePolicy) {
+        self.duplicate_policy = policy;
+    }
+
+    /// Registers an already type-erased [`Listener`] for `event_identifier`,
+    /// returning the existing registration's handle for a refused duplicate.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    fn add_trait_object(
+        &mut self,
+        event_identifier: T,
+        listener: Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>,
+    ) -> ListenerHandle {
+        match self.try_add_trait_object(event_identifier, listener) {
+            Ok(handle) | Err(RegisterError::Duplicate(handle)) => handle,
+        }
+    }
+
+    /// Registers an already type-erased [`Listener`] for `event_identifier`
+    /// according to the [`DuplicatePolicy`].
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`DuplicatePolicy`]: enum.DuplicatePolicy.html
+    fn try_add_trait_object(
+        &mut self,
+        event_identifier: T,
+        listener: Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>,
+    ) -> Result<ListenerHandle, RegisterError> {
+        if self.duplicate_policy != DuplicatePolicy::Allow {
+            let weak_listener = Arc::downgrade(&listener);
+            let duplicate = self.events.get(&event_identifier).and_then(|collection| {
+                collection
+                    .traits
+                    .iter()
+                    .find(|registered| Weak::ptr_eq(&registered.listener, &weak_listener))
+            });
+
+            if let Some(duplicate) = duplicate {
+                return match self.duplicate_policy {
+                    DuplicatePolicy::Error => Err(RegisterError::Duplicate(duplicate.handle)),
+                    DuplicatePolicy::Allow | DuplicatePolicy::Ignore => Ok(duplicate.handle),
+                };
+            }
+        }
+
+        let registered = Registered::subscribe(&listener);
+        let handle = registered.handle;
+
         if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
-            listener_collection.traits.push(Arc::downgrade(
-                &(Arc::clone(listener) as Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>),
-            ));
+            listener_collection.traits.push(registered);
 
-            return;
+            return Ok(handle);
         }
 
         self.events.insert(
             event_identifier,
-            FnsAndTraits::new_with_traits(vec![Arc::downgrade(
-                &(Arc::clone(listener) as Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>),
-            )]),
+            FnsAndTraits::new_with_traits(smallvec![registered]),
         );
+
+        Ok(handle)
     }
 
     /// Adds a [`Fn`] to listen for an `event_identifier`.
     /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
     ///
     /// **Note**: If your `Enum` owns fields you need to consider implementing
     /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields.
@@ -177,56 +744,2033 @@ where
     /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
     /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
     /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
-    pub fn add_fn(
-        &mut self,
-        event_identifier: T,
-        function: Box<dyn Fn(&T) -> Option<SyncDispatcherRequest> + Send + Sync + 'static>,
-    ) {
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_fn(&mut self, event_identifier: T, function: SyncFn<T>) -> ListenerHandle {
+        let registered = Registered::new(Arc::from(function));
+        let handle = registered.handle;
+
         if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
-            listener_collection.fns.push(function);
+            listener_collection.fns.push(registered);
 
-            return;
+            return handle;
         }
 
-        self.events
-            .insert(event_identifier, FnsAndTraits::new_with_fns(vec![function]));
+        self.events.insert(
+            event_identifier,
+            FnsAndTraits::new_with_fns(smallvec![registered]),
+        );
+
+        handle
     }
 
-    /// All [`Listener`]s listening to a passed `event_identifier`
-    /// will be called via their implemented [`on_event`]-method.
-    /// [`Fn`]s returning [`Result`] with `Ok(())` will be retained
-    /// and `Err(SyncDispatcherRequest::StopListening)` will cause them to
-    /// be removed from the event-dispatcher.
+    /// Adds a [`Listener`] of another event-type `A` to listen for an
+    /// `event_identifier`, receiving every event converted via `projection`.
+    /// Events projected to `None` skip the listener, requests it returns
+    /// are honoured as if it listened to `T` itself.
+    /// Returns a [`ListenerHandle`] identifying this registration.
     ///
-    /// [`Listener`]: trait.Listener.html
-    /// [`on_event`]: trait.Listener.html#tymethod.on_event
-    /// [`Error`]: enum.Error.html
-    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
-    /// [`Box`]: https://doc.rust-lang.org/std/boxed/struct.Box.html
-    /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
-    pub fn dispatch_event(&mut self, event_identifier: &T) {
-        if let Some(listener_collection) = self.events.get_mut(event_identifier) {
-            let mut found_invalid_weak_ref = false;
-
-            execute_sync_dispatcher_requests(&mut listener_collection.traits, |weak_listener| {
-                if let Some(listener_arc) = weak_listener.upgrade() {
-                    let mut listener = listener_arc.write();
-                    listener.on_event(event_identifier)
-                } else {
-                    found_invalid_weak_ref = true;
-                    None
-                }
-            });
-
-            execute_sync_dispatcher_requests(&mut listener_collection.fns, |callback| {
-                callback(event_identifier)
-            });
-
-            if found_invalid_weak_ref {
-                listener_collection
-                    .traits
-                    .retain(|listener| Weak::clone(listener).upgrade().is_some());
-            }
-        }
+    /// The registration is called among the [`Fn`]s and does not keep
+    /// `listener` alive, it stops listening once `listener` has been
+    /// dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{
+    ///     sync::{Dispatcher, Listener, SyncDispatcherRequest},
+    ///     RwLock,
+    /// };
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum AppEvent {
+    ///     Audio(u8),
+    ///     Quit,
+    /// }
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum AudioEvent {
+    ///     SetVolume(u8),
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct Mixer {
+    ///     volume: u8,
+    /// }
+    ///
+    /// impl Listener<AudioEvent> for Mixer {
+    ///     fn on_event(&mut self, event: &AudioEvent) -> Option<SyncDispatcherRequest> {
+    ///         let AudioEvent::SetVolume(volume) = event;
+    ///         self.volume = *volume;
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let mixer = Arc::new(RwLock::new(Mixer::default()));
+    ///     let mut dispatcher: Dispatcher<AppEvent> = Dispatcher::default();
+    ///
+    ///     dispatcher.add_mapped_listener(AppEvent::Audio(80), &mixer, |event| match event {
+    ///         AppEvent::Audio(volume) => Some(AudioEvent::SetVolume(*volume)),
+    ///         AppEvent::Quit => None,
+    ///     });
+    ///
+    ///     dispatcher.dispatch_event(&AppEvent::Audio(80));
+    ///
+    ///     assert_eq!(mixer.read().volume, 80);
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn add_mapped_listener<A, D, F>(
+        &mut self,
+        event_identifier: T,
+        listener: &Arc<RwLock<D>>,
+        projection: F,
+    ) -> ListenerHandle
+    where
+        A: PartialEq + Eq + Hash + Clone + 'static,
+        D: Listener<A> + Send + Sync + 'static,
+        F: Fn(&T) -> Option<A> + Send + Sync + 'static,
+    {
+        let listener = Arc::downgrade(listener);
+
+        self.add_fn(
+            event_identifier,
+            Box::new(move |event_identifier| {
+                let listener = match listener.upgrade() {
+                    Some(listener) => listener,
+                    None => return Some(SyncDispatcherRequest::StopListening),
+                };
+
+                let event = projection(event_identifier)?;
+                let request = listener.write().on_event(&event);
+
+                request
+            }),
+        )
+    }
+
+    /// Adds a [`Listener`] to listen for `event_identifier`, calling it for
+    /// only every `every`-th dispatch of `event_identifier`, starting with
+    /// the first one, e.g. to process one in ten physics-ticks.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// Opposed to [`set_throttle`], dispatches are counted instead of timed,
+    /// and only this listener skips events, all others receive every one.
+    /// Skipped dispatches still count as invoked in a [`DispatchResult`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `every` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{
+    ///     sync::{Dispatcher, Listener, SyncDispatcherRequest},
+    ///     RwLock,
+    /// };
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     PhysicsTick,
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct Statistics {
+    ///     samples: usize,
+    /// }
+    ///
+    /// impl Listener<Event> for Statistics {
+    ///     fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+    ///         self.samples += 1;
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let statistics = Arc::new(RwLock::new(Statistics::default()));
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.add_sampled_listener(Event::PhysicsTick, 10, &statistics);
+    ///
+    ///     for _ in 0..25 {
+    ///         dispatcher.dispatch_event(&Event::PhysicsTick);
+    ///     }
+    ///
+    ///     assert_eq!(statistics.read().samples, 3);
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    /// [`set_throttle`]: #method.set_throttle
+    /// [`DispatchResult`]: struct.DispatchResult.html
+    pub fn add_sampled_listener<D: Listener<T> + Send + Sync + 'static>(
+        &mut self,
+        event_identifier: T,
+        every: usize,
+        listener: &Arc<RwLock<D>>,
+    ) -> ListenerHandle {
+        assert!(every > 0, "sampling needs `every` to be at least 1");
+
+        let listener = Arc::downgrade(listener);
+        let dispatches = AtomicUsize::new(0);
+
+        self.add_fn(
+            event_identifier,
+            Box::new(move |event_identifier| {
+                let listener = match listener.upgrade() {
+                    Some(listener) => listener,
+                    None => return Some(SyncDispatcherRequest::StopListening),
+                };
+
+                if dispatches.fetch_add(1, AtomicOrdering::Relaxed) % every != 0 {
+                    return None;
+                }
+
+                let request = listener.write().on_event(event_identifier);
+
+                request
+            }),
+        )
+    }
+
+    /// Forwards every `event_identifier` dispatched by this dispatcher,
+    /// unchanged, to `target`, e.g. to let selected events of a subsystem
+    /// bubble up to a global dispatcher.
+    /// Returns a [`ListenerHandle`] identifying this registration, which
+    /// stops listening once `target` has been dropped.
+    ///
+    /// # Panics
+    ///
+    /// Forwarding panics instead of deadlocking if `target` is dispatching
+    /// on the current thread already, i.e. if dispatchers forward to each
+    /// other in a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{sync::Dispatcher, RwLock};
+    /// use std::sync::{
+    ///     atomic::{AtomicUsize, Ordering},
+    ///     Arc,
+    /// };
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     SaveRequested,
+    ///     KeyPressed,
+    /// }
+    ///
+    /// fn main() {
+    ///     let global = Arc::new(RwLock::new(Dispatcher::<Event>::default()));
+    ///     let saves = Arc::new(AtomicUsize::new(0));
+    ///     let counter = Arc::clone(&saves);
+    ///
+    ///     global.write().add_fn(
+    ///         Event::SaveRequested,
+    ///         Box::new(move |_| {
+    ///             counter.fetch_add(1, Ordering::SeqCst);
+    ///
+    ///             None
+    ///         }),
+    ///     );
+    ///
+    ///     let mut editor: Dispatcher<Event> = Dispatcher::default();
+    ///     editor.add_forwarding_target(Event::SaveRequested, &global);
+    ///
+    ///     editor.dispatch_event(&Event::SaveRequested);
+    ///     editor.dispatch_event(&Event::KeyPressed);
+    ///
+    ///     assert_eq!(saves.load(Ordering::SeqCst), 1);
+    /// }
+    /// ```
+    ///
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_forwarding_target(
+        &mut self,
+        event_identifier: T,
+        target: &Arc<RwLock<Dispatcher<T, S>>>,
+    ) -> ListenerHandle
+    where
+        S: Send + Sync + 'static,
+    {
+        let target = Arc::downgrade(target);
+        self.forwarding = true;
+
+        self.add_fn(
+            event_identifier,
+            Box::new(move |event_identifier| {
+                let target = match target.upgrade() {
+                    Some(target) => target,
+                    None => return Some(SyncDispatcherRequest::StopListening),
+                };
+
+                let start = Arc::as_ptr(&target) as usize;
+                let end = start + mem::size_of::<RwLock<Dispatcher<T, S>>>();
+
+                if FORWARDING.with(|forwarding| {
+                    forwarding
+                        .borrow()
+                        .iter()
+                        .any(|address| (start..end).contains(address))
+                }) {
+                    panic!("Forwarding cycle: the target dispatcher is dispatching already");
+                }
+
+                target.write().dispatch_event(event_identifier);
+
+                None
+            }),
+        )
+    }
+
+    /// Adds a [`ChannelForwarder`] owned by the dispatcher, sending a clone
+    /// of every event equal to `event_identifier` via `sender`.
+    /// The registration is removed once the receiving side has been
+    /// dropped.
+    ///
+    /// [`ChannelForwarder`]: struct.ChannelForwarder.html
+    pub fn add_channel_listener(
+        &mut self,
+        event_identifier: T,
+        sender: Sender<T>,
+    ) -> ListenerHandle {
+        let forwarder = ChannelForwarder::new(sender);
+
+        self.add_fn(
+            event_identifier,
+            Box::new(move |event| forwarder.forward(event.clone())),
+        )
+    }
+
+    /// Adds a [`ChannelForwarder`] like [`add_channel_listener`], but sends
+    /// every event wrapped into an [`Arc`].
+    /// Every event is still cloned once, receivers can share it without
+    /// cloning it again though, see [`ChannelForwarder`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::Dispatcher;
+    /// use std::sync::{mpsc::channel, Arc};
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Frame(Vec<u8>),
+    /// }
+    ///
+    /// fn main() {
+    ///     let (sender, receiver) = channel();
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.add_arc_channel_listener(Event::Frame(vec![]), sender);
+    ///
+    ///     dispatcher.dispatch_event(&Event::Frame(vec![]));
+    ///
+    ///     let frame: Arc<Event> = receiver.try_recv().unwrap();
+    ///     let shared = Arc::clone(&frame);
+    ///     assert_eq!(*shared, Event::Frame(vec![]));
+    /// }
+    /// ```
+    ///
+    /// [`ChannelForwarder`]: struct.ChannelForwarder.html
+    /// [`add_channel_listener`]: #method.add_channel_listener
+    /// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+    pub fn add_arc_channel_listener(
+        &mut self,
+        event_identifier: T,
+        sender: Sender<Arc<T>>,
+    ) -> ListenerHandle {
+        let forwarder = ChannelForwarder::new(sender);
+
+        self.add_fn(
+            event_identifier,
+            Box::new(move |event| forwarder.forward(Arc::new(event.clone()))),
+        )
+    }
+
+    /// Returns an [`EventSender`] to enqueue events from other threads,
+    /// which are dispatched on the thread calling [`poll`].
+    /// All senders share one channel, opened by the first call.
+    ///
+    /// **Note**: Cloning the dispatcher does not clone its channel,
+    /// the clone opens a channel of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::Dispatcher;
+    /// use std::thread;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     FileChanged,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let sender = dispatcher.sender();
+    ///
+    ///     thread::spawn(move || sender.send(Event::FileChanged).unwrap())
+    ///         .join()
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(dispatcher.poll(), 1);
+    /// }
+    /// ```
+    ///
+    /// [`EventSender`]: struct.EventSender.html
+    /// [`poll`]: #method.poll
+    pub fn sender(&mut self) -> EventSender<T> {
+        let capacity = self.channel_capacity;
+
+        self.channel
+            .get_or_insert_with(|| EventChannel::new(capacity))
+            .sender()
+    }
+
+    /// Dispatches all events enqueued via [`EventSender`]s so far, in
+    /// sending-order, on the calling thread.
+    /// Events sent while polling are left for the next call.
+    /// Returns how many events have been dispatched.
+    ///
+    /// [`EventSender`]: struct.EventSender.html
+    pub fn poll(&mut self) -> usize {
+        let events = match &self.channel {
+            Some(channel) => channel.drain(),
+            None => return 0,
+        };
+
+        for event in &events {
+            self.dispatch_event(event);
+        }
+
+        events.len()
+    }
+
+    /// Registers a channel for `event_identifier` and returns its receiving
+    /// [`Subscription`], which pulls clones of all events dispatched to
+    /// `event_identifier` from then on.
+    ///
+    /// Once the [`Subscription`] has been dropped, the next dispatch to
+    /// `event_identifier` removes its registration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::Dispatcher;
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let subscription = dispatcher.subscribe(Event::Tick);
+    ///
+    ///     dispatcher.dispatch_event(&Event::Tick);
+    ///     dispatcher.dispatch_event(&Event::Tick);
+    ///
+    ///     assert_eq!(subscription.drain().count(), 2);
+    ///     assert!(subscription.try_recv().is_err());
+    /// }
+    /// ```
+    ///
+    /// [`Subscription`]: struct.Subscription.html
+    pub fn subscribe(&mut self, event_identifier: T) -> Subscription<T> {
+        let (sender, receiver) = channel();
+
+        let handle = self.add_fn(
+            event_identifier,
+            Box::new(move |event| match sender.send(event.clone()) {
+                Ok(()) => None,
+                Err(_) => Some(SyncDispatcherRequest::StopListening),
+            }),
+        );
+
+        Subscription { handle, receiver }
+    }
+
+    /// Removes the listener or [`Fn`] registered with `handle`,
+    /// the order of all other listeners stays untouched.
+    /// Returns `false` if `handle` is not registered on this dispatcher,
+    /// e.g. because it has already been removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let handle = dispatcher.add_fn(Event::EventType, Box::new(|_| None));
+    ///
+    ///     assert!(dispatcher.remove_listener(handle));
+    ///     assert!(!dispatcher.remove_listener(handle));
+    /// }
+    /// ```
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn remove_listener(&mut self, handle: ListenerHandle) -> bool {
+        self.names.remove(&handle);
+
+        self.events
+            .values_mut()
+            .any(|listener_collection| listener_collection.remove(handle))
+    }
+
+    /// Returns the dispatcher to an empty state to be reused, e.g. from an
+    /// object-pool, while keeping the memory it has allocated so far,
+    /// including the storage for the listeners of every event-variant.
+    ///
+    /// Removes all listeners, [`Fn`]s and their names, forwarding targets,
+    /// sticky events, debounced and throttled event-variants along with
+    /// their pending events, the recorded history and events sent but not
+    /// yet polled. Removed listeners are unsubscribed.
+    /// Settings applying to all events are kept, i.e. ordering,
+    /// pre-dispatch hook, middleware, validator, tracer, clock,
+    /// [`DuplicatePolicy`] and whether a history is recorded.
+    /// Handed out [`EventSender`]s stay connected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     LevelLoaded,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let sender = dispatcher.sender();
+    ///     dispatcher.add_fn(Event::LevelLoaded, Box::new(|_| None));
+    ///     sender.send(Event::LevelLoaded).unwrap();
+    ///
+    ///     dispatcher.reset();
+    ///
+    ///     assert_eq!(dispatcher.poll(), 0);
+    ///     assert_eq!(dispatcher.dispatch_event_with_result(&Event::LevelLoaded).invoked, 0);
+    /// }
+    /// ```
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`DuplicatePolicy`]: enum.DuplicatePolicy.html
+    /// [`EventSender`]: struct.EventSender.html
+    pub fn reset(&mut self) {
+        for listener_collection in self.events.values_mut() {
+            listener_collection.clear();
+        }
+
+        self.forwarding = false;
+        self.names.clear();
+        self.sticky.clear();
+        self.debounced.clear();
+        self.throttled.clear();
+        self.clear_history();
+
+        if let Some(channel) = &self.channel {
+            channel.drain();
+        }
+    }
+
+    /// Enables or disables the listener or [`Fn`] registered with `handle`.
+    /// A disabled registration keeps its position but is skipped by every
+    /// dispatch, neither receiving events nor counting as invoked, until
+    /// it is enabled again.
+    /// Returns `false` if `handle` is not registered on this dispatcher.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Redraw,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let debug_overlay = dispatcher.add_fn(Event::Redraw, Box::new(|_| None));
+    ///
+    ///     assert!(dispatcher.set_listener_enabled(debug_overlay, false));
+    ///     assert_eq!(dispatcher.dispatch_event_with_result(&Event::Redraw).invoked, 0);
+    ///
+    ///     assert!(dispatcher.set_listener_enabled(debug_overlay, true));
+    ///     assert_eq!(dispatcher.dispatch_event_with_result(&Event::Redraw).invoked, 1);
+    /// }
+    /// ```
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn set_listener_enabled(&mut self, handle: ListenerHandle, enabled: bool) -> bool {
+        self.events
+            .values_mut()
+            .any(|listener_collection| listener_collection.set_enabled(handle, enabled))
+    }
+
+    /// Returns whether `handle` is registered on this dispatcher.
+    ///
+    /// **Note**: A [`Listener`] is registered until it is removed or
+    /// its dropped [`Weak`] is noticed during a dispatch, use
+    /// [`is_live`] to tell whether it can still be called.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    /// [`is_live`]: #method.is_live
+    pub fn has_listener(&self, handle: ListenerHandle) -> bool {
+        self.events
+            .values()
+            .any(|listener_collection| listener_collection.liveness(handle).is_some())
+    }
+
+    /// Returns whether `handle` is registered and, for a [`Listener`],
+    /// its [`Weak`] can still be upgraded.
+    /// Opposed to [`has_listener`], this tells a dropped listener apart
+    /// from a live one that has not been dispatched to since.
+    /// [`Fn`]s are live as long as they are registered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{
+    ///     sync::{Dispatcher, Listener, SyncDispatcherRequest},
+    ///     RwLock,
+    /// };
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct ListenerStruct;
+    ///
+    /// impl Listener<Event> for ListenerStruct {
+    ///     fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let listener = Arc::new(RwLock::new(ListenerStruct));
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let handle = dispatcher.add_listener(Event::EventType, &listener);
+    ///
+    ///     assert!(dispatcher.is_live(handle));
+    ///
+    ///     drop(listener);
+    ///
+    ///     assert!(dispatcher.has_listener(handle));
+    ///     assert!(!dispatcher.is_live(handle));
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    /// [`has_listener`]: #method.has_listener
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn is_live(&self, handle: ListenerHandle) -> bool {
+        self.events
+            .values()
+            .any(|listener_collection| listener_collection.liveness(handle) == Some(true))
+    }
+
+    /// Returns the [`Listener`]s listening to `event_identifier` in
+    /// registration-order, [`Fn`]s are not included.
+    /// Listeners that have been dropped are skipped.
+    ///
+    /// **Note**: The returned [`Arc`]s are strong references, a listener
+    /// stays alive and hence keeps being dispatched to as long as one of
+    /// them is held, even if all other owners dropped it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{
+    ///     sync::{Dispatcher, Listener, SyncDispatcherRequest},
+    ///     RwLock,
+    /// };
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct ListenerStruct;
+    ///
+    /// impl Listener<Event> for ListenerStruct {
+    ///     fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let listener = Arc::new(RwLock::new(ListenerStruct));
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.add_listener(Event::EventType, &listener);
+    ///     dispatcher.add_fn(Event::EventType, Box::new(|_| None));
+    ///
+    ///     assert_eq!(dispatcher.iter_listeners(&Event::EventType).count(), 1);
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+    pub fn iter_listeners(
+        &self,
+        event_identifier: &T,
+    ) -> impl Iterator<Item = Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>> + '_ {
+        self.events
+            .get(event_identifier)
+            .into_iter()
+            .flat_map(|listener_collection| listener_collection.traits.iter())
+            .filter_map(|registered| registered.listener.upgrade())
+    }
+
+    /// Returns the names of all listeners and [`Fn`]s registered for
+    /// `event_identifier`, listeners first, in the order they are called.
+    /// Registrations not added via [`add_named_listener`] are listed as
+    /// [`UNNAMED_LISTENER`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{Dispatcher, UNNAMED_LISTENER};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Save,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.add_fn(Event::Save, Box::new(|_| None));
+    ///
+    ///     assert_eq!(dispatcher.listener_names(&Event::Save), [UNNAMED_LISTENER]);
+    /// }
+    /// ```
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`add_named_listener`]: #method.add_named_listener
+    /// [`UNNAMED_LISTENER`]: constant.UNNAMED_LISTENER.html
+    pub fn listener_names(&self, event_identifier: &T) -> Vec<&str> {
+        self.events
+            .get(event_identifier)
+            .into_iter()
+            .flat_map(|listener_collection| {
+                let traits = listener_collection
+                    .traits
+                    .iter()
+                    .map(|registered| registered.handle);
+                let fns = listener_collection
+                    .fns
+                    .iter()
+                    .map(|registered| registered.handle);
+
+                traits.chain(fns)
+            })
+            .map(|handle| {
+                self.names
+                    .get(&handle)
+                    .map_or(UNNAMED_LISTENER, String::as_str)
+            })
+            .collect()
+    }
+
+    /// Takes a [`DispatcherSnapshot`] of all registrations, to be put
+    /// back in place via [`restore`].
+    ///
+    /// [`DispatcherSnapshot`]: struct.DispatcherSnapshot.html
+    /// [`restore`]: #method.restore
+    pub fn snapshot(&self) -> DispatcherSnapshot<T> {
+        DispatcherSnapshot {
+            levels: self
+                .events
+                .iter()
+                .map(|(event_identifier, listener_collection)| {
+                    (event_identifier.clone(), (), listener_collection.snapshot())
+                })
+                .collect(),
+            names: self.names.clone(),
+        }
+    }
+
+    /// Replaces all registrations with the ones of `snapshot`,
+    /// keeping their order and names.
+    /// Comparator and pre-dispatch hook stay in place.
+    ///
+    /// **Note**: Restored registrations get new [`ListenerHandle`]s,
+    /// handles handed out before no longer identify them.
+    /// Like [`add_listener`], the dispatcher only holds [`Weak`]-references
+    /// to restored [`Listener`]s, hence listeners only owned by `snapshot`
+    /// live as long as `snapshot` does.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.add_fn(Event::EventType, Box::new(|_| None));
+    ///     let known_good = dispatcher.snapshot();
+    ///
+    ///     dispatcher.add_fn(Event::EventType, Box::new(|_| None));
+    ///     assert_eq!(dispatcher.dispatch_event_with_result(&Event::EventType).invoked, 2);
+    ///
+    ///     dispatcher.restore(&known_good);
+    ///     assert_eq!(dispatcher.dispatch_event_with_result(&Event::EventType).invoked, 1);
+    /// }
+    /// ```
+    ///
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    /// [`add_listener`]: #method.add_listener
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    /// [`Listener`]: trait.Listener.html
+    pub fn restore(&mut self, snapshot: &DispatcherSnapshot<T>) {
+        for listener_collection in self.events.values_mut() {
+            listener_collection.clear();
+        }
+
+        self.events.clear();
+        self.names.clear();
+
+        let names = &mut self.names;
+
+        for (event_identifier, (), level) in &snapshot.levels {
+            let level = FnsAndTraits::from_snapshot(level, |handle, restored_handle| {
+                if let Some(name) = snapshot.names.get(&handle) {
+                    names.insert(restored_handle, name.clone());
+                }
+            });
+
+            self.events.insert(event_identifier.clone(), level);
+        }
+    }
+
+    /// Sets a `comparator` ordering listeners by their [`ListenerHandle`],
+    /// the dispatcher then sorts the listeners of an event-variant right
+    /// before each dispatch, e.g. when the order depends on state
+    /// changing from one dispatch to the next.
+    /// Listeners comparing equal keep their relative order.
+    ///
+    /// **Note**: Sorting costs `O(n log n)` per dispatch for `n` listeners
+    /// of the dispatched event-variant, use [`PriorityDispatcher`] if
+    /// static priorities suffice.
+    /// [`Listener`]s and [`Fn`]s are sorted separately, all [`Listener`]s
+    /// are still dispatched before any [`Fn`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let first = dispatcher.add_fn(Event::EventType, Box::new(|_| None));
+    ///     let second = dispatcher.add_fn(Event::EventType, Box::new(|_| None));
+    ///
+    ///     // Newest registration first.
+    ///     dispatcher.set_ordering(|a, b| b.cmp(&a));
+    ///
+    ///     assert_eq!(
+    ///         dispatcher.dispatch_event_handles(&Event::EventType),
+    ///         [second, first]
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    /// [`PriorityDispatcher`]: struct.PriorityDispatcher.html
+    /// [`Listener`]: trait.Listener.html
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn set_ordering<F>(&mut self, comparator: F)
+    where
+        F: Fn(ListenerHandle, ListenerHandle) -> Ordering + Send + Sync + 'static,
+    {
+        self.ordering = Some(Arc::new(comparator));
+    }
+
+    /// Removes the comparator set via [`set_ordering`], listeners
+    /// will no longer be sorted before dispatching.
+    ///
+    /// [`set_ordering`]: #method.set_ordering
+    pub fn clear_ordering(&mut self) {
+        self.ordering = None;
+    }
+
+    /// Sets a `hook` receiving a clone of every event before it is
+    /// dispatched, replacing a previously set hook.
+    /// Returning `Some` dispatches the returned event, which may differ
+    /// from the passed one, `None` swallows the event.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Legacy,
+    ///     Current,
+    ///     Experimental,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.add_fn(Event::Current, Box::new(|_| None));
+    ///
+    ///     dispatcher.set_pre_dispatch(|event| match event {
+    ///         Event::Legacy => Some(Event::Current),
+    ///         Event::Experimental => None,
+    ///         event => Some(event),
+    ///     });
+    ///
+    ///     assert_eq!(dispatcher.dispatch_event_with_result(&Event::Legacy).invoked, 1);
+    ///     assert_eq!(dispatcher.dispatch_event_with_result(&Event::Experimental).invoked, 0);
+    /// }
+    /// ```
+    pub fn set_pre_dispatch<F>(&mut self, hook: F)
+    where
+        F: FnMut(T) -> Option<T> + Send + Sync + 'static,
+    {
+        self.pre_dispatch = Some(Arc::new(Mutex::new(hook)));
+    }
+
+    /// Removes the hook set via [`set_pre_dispatch`], events will be
+    /// dispatched unaltered again.
+    ///
+    /// [`set_pre_dispatch`]: #method.set_pre_dispatch
+    pub fn clear_pre_dispatch(&mut self) {
+        self.pre_dispatch = None;
+    }
+
+    /// Appends `middleware` to the chain every dispatched event runs
+    /// through before any listener is called, e.g. to filter events by
+    /// permission, rewrite outdated events or mute events globally.
+    ///
+    /// Middleware runs in the order it has been added, after the hook set
+    /// via [`set_pre_dispatch`]. Each one receives the event as decided by
+    /// the previous one, see [`MiddlewareDecision`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{Dispatcher, MiddlewareDecision};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Legacy,
+    ///     Current,
+    ///     Muted,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.add_fn(Event::Current, Box::new(|_| None));
+    ///
+    ///     dispatcher.add_middleware(|event| match event {
+    ///         Event::Legacy => MiddlewareDecision::ReplaceWith(Event::Current),
+    ///         _ => MiddlewareDecision::Continue,
+    ///     });
+    ///     dispatcher.add_middleware(|event| match event {
+    ///         Event::Muted => MiddlewareDecision::Drop,
+    ///         _ => MiddlewareDecision::Continue,
+    ///     });
+    ///
+    ///     assert_eq!(dispatcher.dispatch_event_with_result(&Event::Legacy).invoked, 1);
+    /// }
+    /// ```
+    ///
+    /// [`set_pre_dispatch`]: #method.set_pre_dispatch
+    /// [`MiddlewareDecision`]: enum.MiddlewareDecision.html
+    pub fn add_middleware<F>(&mut self, middleware: F)
+    where
+        F: FnMut(&T) -> MiddlewareDecision<T> + Send + Sync + 'static,
+    {
+        self.middleware.push(Arc::new(Mutex::new(middleware)));
+    }
+
+    /// Removes all middleware added via [`add_middleware`].
+    ///
+    /// [`add_middleware`]: #method.add_middleware
+    pub fn clear_middleware(&mut self) {
+        self.middleware.clear();
+    }
+
+    /// Sets `validator` to check every event before it reaches the
+    /// listeners, after the pre-dispatch hook and middleware but before
+    /// debouncing and throttling, replacing any previous validator.
+    ///
+    /// Vetoed events are not dispatched at all: no listener is called or
+    /// counted as invoked and tracers do not observe them.
+    /// [`dispatch_event_validated`] returns the [`VetoReason`] to the
+    /// caller, all other dispatch-methods discard it.
+    ///
+    /// [`dispatch_event_validated`]: #method.dispatch_event_validated
+    /// [`VetoReason`]: struct.VetoReason.html
+    pub fn set_validator<F>(&mut self, validator: F)
+    where
+        F: FnMut(&T) -> Result<(), VetoReason> + Send + Sync + 'static,
+    {
+        self.validator = Some(Arc::new(Mutex::new(validator)));
+    }
+
+    /// Removes the validator set via [`set_validator`], events will no
+    /// longer be vetoed.
+    ///
+    /// [`set_validator`]: #method.set_validator
+    pub fn clear_validator(&mut self) {
+        self.validator = None;
+    }
+
+    /// Sets a [`DispatchTracer`] observing every dispatch reaching the
+    /// listeners, replacing any previous one.
+    /// Only available with the `tracing`-feature enabled.
+    ///
+    /// Dispatchers start with a [`SpanTracer`] leaving out the event,
+    /// setting [`SpanTracer::new`] adds the event to the spans.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{Dispatcher, SpanTracer};
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Save,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.set_tracer(SpanTracer::new());
+    ///
+    ///     dispatcher.dispatch_event(&Event::Save);
+    /// }
+    /// ```
+    ///
+    /// [`DispatchTracer`]: trait.DispatchTracer.html
+    /// [`SpanTracer`]: struct.SpanTracer.html
+    /// [`SpanTracer::new`]: struct.SpanTracer.html#method.new
+    #[cfg(feature = "tracing")]
+    pub fn set_tracer<D>(&mut self, tracer: D)
+    where
+        D: DispatchTracer<T> + 'static,
+    {
+        self.tracer = Some(Arc::new(tracer));
+    }
+
+    /// Removes the tracer, including the default [`SpanTracer`], hence
+    /// dispatching does not trace anything until [`set_tracer`] is called.
+    /// Only available with the `tracing`-feature enabled.
+    ///
+    /// [`SpanTracer`]: struct.SpanTracer.html
+    /// [`set_tracer`]: #method.set_tracer
+    #[cfg(feature = "tracing")]
+    pub fn clear_tracer(&mut self) {
+        self.tracer = None;
+    }
+
+    /// Starts recording the last `capacity` events passed to the
+    /// dispatch-methods, e.g. to print them when an assertion fails.
+    /// Events nobody listens to or dropped by a pre-dispatch hook are
+    /// recorded as well. Once full, the oldest event is overwritten.
+    ///
+    /// Enabling the history again discards the recorded events.
+    /// Without a history, dispatching does not record anything.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::Dispatcher;
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Opened,
+    ///     Saved,
+    ///     Closed,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.enable_history(2);
+    ///
+    ///     dispatcher.dispatch_event(&Event::Opened);
+    ///     dispatcher.dispatch_event(&Event::Saved);
+    ///     dispatcher.dispatch_event(&Event::Closed);
+    ///
+    ///     assert!(dispatcher.history().eq(&[Event::Saved, Event::Closed]));
+    /// }
+    /// ```
+    pub fn enable_history(&mut self, capacity: usize) {
+        self.history = Some(History {
+            events: VecDeque::with_capacity(capacity),
+            capacity,
+        });
+    }
+
+    /// Stops recording and discards the history enabled via
+    /// [`enable_history`].
+    ///
+    /// [`enable_history`]: #method.enable_history
+    pub fn disable_history(&mut self) {
+        self.history = None;
+    }
+
+    /// Returns the recorded events, oldest first.
+    /// Yields nothing unless [`enable_history`] has been called.
+    ///
+    /// [`enable_history`]: #method.enable_history
+    pub fn history(&self) -> impl Iterator<Item = &T> {
+        self.history
+            .iter()
+            .flat_map(|history| history.events.iter())
+    }
+
+    /// Forgets the recorded events but keeps recording.
+    pub fn clear_history(&mut self) {
+        if let Some(history) = &mut self.history {
+            history.events.clear();
+        }
+    }
+
+    /// All [`Listener`]s listening to a passed `event_identifier`
+    /// will be called via their implemented [`on_event`]-method.
+    /// [`Fn`]s returning [`Result`] with `Ok(())` will be retained
+    /// and `Err(SyncDispatcherRequest::StopListening)` will cause them to
+    /// be removed from the event-dispatcher.
+    /// An `event_identifier` nobody listens to is silently ignored.
+    ///
+    /// [`Listener`]s are called before [`Fn`]s, a [`Listener`] stopping
+    /// propagation skips all [`Fn`]s as well.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`on_event`]: trait.Listener.html#tymethod.on_event
+    /// [`Error`]: enum.Error.html
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Box`]: https://doc.rust-lang.org/std/boxed/struct.Box.html
+    /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
+    pub fn dispatch_event(&mut self, event_identifier: &T) {
+        self.dispatch_with(event_identifier, |_, _| true);
+    }
+
+    /// Dispatches like [`dispatch_event`] but returns the handles of all
+    /// listeners and [`Fn`]s that have been called, in calling-order.
+    ///
+    /// **Note**: Listeners removing themselves during this dispatch are
+    /// part of the returned handles, as they have been called.
+    /// Listeners that have been dropped already are not.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{Dispatcher, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let handle = dispatcher.add_fn(Event::EventType, Box::new(|_| None));
+    ///
+    ///     assert_eq!(dispatcher.dispatch_event_handles(&Event::EventType), [handle]);
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn dispatch_event_handles(&mut self, event_identifier: &T) -> Vec<ListenerHandle> {
+        let mut handles = Vec::new();
+        self.dispatch_with(event_identifier, |handle, _| {
+            handles.push(handle);
+
+            true
+        });
+
+        handles
+    }
+
+    /// Dispatches like [`dispatch_event`] and returns how many listeners
+    /// have been called and whether one stopped propagation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::Dispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///
+    ///     assert_eq!(dispatcher.dispatch_event_with_result(&Event::EventType).invoked, 0);
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    pub fn dispatch_event_with_result(&mut self, event_identifier: &T) -> DispatchResult {
+        let mut invoked = 0;
+        let result = self.dispatch_with(event_identifier, |_, _| {
+            invoked += 1;
+
+            true
+        });
+
+        DispatchResult {
+            invoked,
+            stopped: matches!(result, ExecuteRequestsResult::Stopped),
+        }
+    }
+
+    /// Dispatches like [`dispatch_event_with_result`], unless the validator
+    /// set via [`set_validator`] vetoes the event, in which case no listener
+    /// is called and its [`VetoReason`] is returned instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{Dispatcher, VetoReason};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Message {
+    ///     Chat(String),
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Message> = Dispatcher::default();
+    ///     dispatcher.add_fn(Message::Chat(String::new()), Box::new(|_| None));
+    ///     dispatcher.set_validator(|message| match message {
+    ///         Message::Chat(text) if text.is_empty() => Err(VetoReason("empty".to_string())),
+    ///         Message::Chat(_) => Ok(()),
+    ///     });
+    ///
+    ///     assert_eq!(
+    ///         dispatcher.dispatch_event_validated(&Message::Chat(String::new())),
+    ///         Err(VetoReason("empty".to_string()))
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event_with_result`]: #method.dispatch_event_with_result
+    /// [`set_validator`]: #method.set_validator
+    /// [`VetoReason`]: struct.VetoReason.html
+    pub fn dispatch_event_validated(
+        &mut self,
+        event_identifier: &T,
+    ) -> Result<DispatchResult, VetoReason> {
+        let mut invoked = 0;
+        let result = self.try_dispatch_with(event_identifier, |_, _| {
+            invoked += 1;
+
+            true
+        })?;
+
+        Ok(DispatchResult {
+            invoked,
+            stopped: matches!(result, ExecuteRequestsResult::Stopped),
+        })
+    }
+
+    /// Dispatches every event of `events` in order like [`dispatch_event`],
+    /// but calls each listener at most once for the entire batch.
+    /// Returns how many listeners and [`Fn`]s have been called.
+    ///
+    /// Listeners are told apart by identity, not by value: a [`Listener`]
+    /// added under several event-variants via the same [`Arc`] is one
+    /// listener and only receives the first event of `events` it listens
+    /// to. Every [`Fn`] is its own listener, as it is owned by its
+    /// registration.
+    ///
+    /// A skipped listener neither receives the event nor can it stop
+    /// listening or stop propagation. Stopping propagation only ends the
+    /// dispatch of the current event, the batch continues with the next one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{
+    ///     RwLock,
+    ///     sync::{Dispatcher, Listener, SyncDispatcherRequest},
+    /// };
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Resized,
+    ///     Moved,
+    /// }
+    ///
+    /// struct Redraw {
+    ///     redraws: usize,
+    /// }
+    ///
+    /// impl Listener<Event> for Redraw {
+    ///     fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+    ///         self.redraws += 1;
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let listener = Arc::new(RwLock::new(Redraw { redraws: 0 }));
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///
+    ///     dispatcher.add_listener(Event::Resized, &listener);
+    ///     dispatcher.add_listener(Event::Moved, &listener);
+    ///
+    ///     assert_eq!(dispatcher.dispatch_events_dedup(&[Event::Resized, Event::Moved]), 1);
+    ///     assert_eq!(listener.read().redraws, 1);
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Listener`]: trait.Listener.html
+    /// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+    pub fn dispatch_events_dedup(&mut self, events: &[T]) -> usize {
+        let mut fired = HashSet::new();
+
+        for event_identifier in events {
+            self.dispatch_with(event_identifier, |_, identity| fired.insert(identity));
+        }
+
+        fired.len()
+    }
+
+    /// Dispatches `event_identifier` like [`dispatch_event`] and retains it
+    /// as sticky event, replacing the sticky event equal to it, if any.
+    /// Listeners added via [`add_listener_sticky`] later on receive the
+    /// retained event right away.
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`add_listener_sticky`]: #method.add_listener_sticky
+    pub fn dispatch_sticky(&mut self, event_identifier: T) {
+        self.dispatch_event(&event_identifier);
+        self.sticky.remove(&event_identifier);
+        self.sticky
+            .insert(event_identifier.clone(), event_identifier);
+    }
+
+    /// Removes and returns the sticky event equal to `event_identifier`,
+    /// later listeners are not called with it anymore.
+    pub fn clear_sticky(&mut self, event_identifier: &T) -> Option<T> {
+        self.sticky.remove(event_identifier)
+    }
+
+    /// Maps a foreign `event`, e.g. from a windowing event-loop such as
+    /// `winit`'s, to an event-variant via `mapping` and dispatches it.
+    /// Returns `false` if `mapping` returned `None` and nothing has been
+    /// dispatched.
+    ///
+    /// The dispatcher does not depend on any event-loop, `mapping` is the
+    /// only place that needs to know the foreign event-type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::Dispatcher;
+    ///
+    /// // Stands in for e.g. `winit::event::Event<()>`.
+    /// enum WindowEvent {
+    ///     Resized(u32, u32),
+    ///     CursorMoved,
+    /// }
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Relayout,
+    /// }
+    ///
+    /// fn map_window_event(event: &WindowEvent) -> Option<Event> {
+    ///     match event {
+    ///         WindowEvent::Resized(..) => Some(Event::Relayout),
+    ///         _ => None,
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     dispatcher.add_fn(Event::Relayout, Box::new(|_| None));
+    ///
+    ///     assert!(dispatcher.feed_event(&WindowEvent::Resized(800, 600), map_window_event));
+    ///     assert!(!dispatcher.feed_event(&WindowEvent::CursorMoved, map_window_event));
+    /// }
+    /// ```
+    pub fn feed_event<E, F>(&mut self, event: &E, mapping: F) -> bool
+    where
+        F: Fn(&E) -> Option<T>,
+    {
+        match mapping(event) {
+            Some(event_identifier) => {
+                self.dispatch_event(&event_identifier);
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Debounces `event_identifier`: Instead of reaching listeners right
+    /// away, dispatching it only keeps the latest event of a burst, which is
+    /// delivered by [`flush_debounced`] once no further event equal to it
+    /// has arrived for `window`.
+    /// Setting a new window keeps an undelivered event.
+    ///
+    /// Event-variants without debounce-window are dispatched as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::Dispatcher;
+    /// use std::{
+    ///     sync::{Arc, Mutex},
+    ///     time::{Duration, Instant},
+    /// };
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     TextChanged,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let calls = Arc::new(Mutex::new(0));
+    ///     let counter = Arc::clone(&calls);
+    ///
+    ///     dispatcher.add_fn(
+    ///         Event::TextChanged,
+    ///         Box::new(move |_| {
+    ///             *counter.lock().unwrap() += 1;
+    ///
+    ///             None
+    ///         }),
+    ///     );
+    ///
+    ///     let window = Duration::from_millis(300);
+    ///     dispatcher.set_debounce(Event::TextChanged, window);
+    ///
+    ///     for _ in 0..5 {
+    ///         dispatcher.dispatch_event(&Event::TextChanged);
+    ///     }
+    ///     assert_eq!(*calls.lock().unwrap(), 0);
+    ///
+    ///     assert_eq!(dispatcher.flush_debounced(Instant::now() + window), 1);
+    ///     assert_eq!(*calls.lock().unwrap(), 1);
+    /// }
+    /// ```
+    ///
+    /// [`flush_debounced`]: #method.flush_debounced
+    pub fn set_debounce(&mut self, event_identifier: T, window: Duration) {
+        self.debounced
+            .entry(event_identifier)
+            .or_insert(Debounce {
+                window,
+                pending: None,
+            })
+            .window = window;
+    }
+
+    /// Stops debouncing `event_identifier`.
+    /// Returns the event that has not been delivered yet, if any, it is
+    /// not dispatched.
+    pub fn remove_debounce(&mut self, event_identifier: &T) -> Option<T> {
+        self.debounced
+            .remove(event_identifier)
+            .and_then(|debounce| debounce.pending)
+            .map(|(event, _)| event)
+    }
+
+    /// Delivers every debounced event whose latest arrival is at least its
+    /// debounce-window before `now`, in arrival-order.
+    /// Returns how many events have been delivered.
+    ///
+    /// Call it regularly, e.g. once per frame, with the time of the
+    /// dispatcher's clock, see [`set_clock`].
+    ///
+    /// [`set_clock`]: #method.set_clock
+    pub fn flush_debounced(&mut self, now: Instant) -> usize {
+        let mut due: Vec<(T, Instant)> = self
+            .debounced
+            .values_mut()
+            .filter(|debounce| {
+                debounce.pending.as_ref().is_some_and(|(_, arrival)| {
+                    now.saturating_duration_since(*arrival) >= debounce.window
+                })
+            })
+            .filter_map(|debounce| debounce.pending.take())
+            .collect();
+        due.sort_by_key(|(_, arrival)| *arrival);
+
+        for (event_identifier, _) in &due {
+            self.call_listeners(event_identifier, |_, _| true);
+        }
+
+        due.len()
+    }
+
+    /// Throttles `event_identifier`: Events equal to it are delivered right
+    /// away, but at most once per `min_interval`. Events dispatched in
+    /// between are handled according to `policy`.
+    ///
+    /// Event-variants without throttle are dispatched as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{Dispatcher, ThrottlePolicy};
+    /// use std::{
+    ///     sync::{Arc, Mutex},
+    ///     time::{Duration, Instant},
+    /// };
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     MouseMoved,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    ///     let calls = Arc::new(Mutex::new(0));
+    ///     let counter = Arc::clone(&calls);
+    ///
+    ///     dispatcher.add_fn(
+    ///         Event::MouseMoved,
+    ///         Box::new(move |_| {
+    ///             *counter.lock().unwrap() += 1;
+    ///
+    ///             None
+    ///         }),
+    ///     );
+    ///
+    ///     let frame = Duration::from_millis(16);
+    ///     dispatcher.set_throttle(Event::MouseMoved, frame, ThrottlePolicy::Trailing);
+    ///
+    ///     for _ in 0..5 {
+    ///         dispatcher.dispatch_event(&Event::MouseMoved);
+    ///     }
+    ///     assert_eq!(*calls.lock().unwrap(), 1);
+    ///
+    ///     assert_eq!(dispatcher.flush_throttled(Instant::now() + frame), 1);
+    ///     assert_eq!(*calls.lock().unwrap(), 2);
+    /// }
+    /// ```
+    pub fn set_throttle(
+        &mut self,
+        event_identifier: T,
+        min_interval: Duration,
+        policy: ThrottlePolicy,
+    ) {
+        let throttle = self.throttled.entry(event_identifier).or_insert(Throttle {
+            min_interval,
+            policy,
+            delivered: None,
+            trailing: None,
+        });
+        throttle.min_interval = min_interval;
+        throttle.policy = policy;
+    }
+
+    /// Stops throttling `event_identifier`.
+    /// Returns the suppressed event that has not been delivered yet,
+    /// if any, it is not dispatched.
+    pub fn remove_throttle(&mut self, event_identifier: &T) -> Option<T> {
+        self.throttled
+            .remove(event_identifier)
+            .and_then(|throttle| throttle.trailing)
+    }
+
+    /// Delivers the latest suppressed event of every event-variant
+    /// throttled via `ThrottlePolicy::Trailing`, whose minimal interval has
+    /// passed at `now`, in the order they have been delivered last.
+    /// Returns how many events have been delivered.
+    ///
+    /// Call it regularly, e.g. once per frame, with the time of the
+    /// dispatcher's clock, see [`set_clock`].
+    ///
+    /// [`set_clock`]: #method.set_clock
+    pub fn flush_throttled(&mut self, now: Instant) -> usize {
+        let mut due: Vec<(T, Option<Instant>)> = self
+            .throttled
+            .values_mut()
+            .filter(|throttle| throttle.trailing.is_some() && throttle.is_open(now))
+            .filter_map(|throttle| {
+                let delivered = throttle.delivered.replace(now);
+
+                throttle.trailing.take().map(|event| (event, delivered))
+            })
+            .collect();
+        due.sort_by_key(|(_, delivered)| *delivered);
+
+        for (event_identifier, _) in &due {
+            self.call_listeners(event_identifier, |_, _| true);
+        }
+
+        due.len()
+    }
+
+    /// Replaces the clock timing the arrival of debounced and throttled
+    /// events, `Instant::now` by default, e.g. by a fake clock in tests.
+    pub fn set_clock<F>(&mut self, clock: F)
+    where
+        F: Fn() -> Instant + Send + Sync + 'static,
+    {
+        self.clock = Some(Arc::new(clock));
+    }
+
+    /// Wraps this dispatcher into a [`MappedDispatcher`] fed with events of
+    /// type `H`, each converted via `mapping` before being dispatched.
+    /// Like [`feed_event`] but keeping `mapping` for all future dispatches,
+    /// events converted to `None` are dropped.
+    ///
+    /// [`MappedDispatcher`]: struct.MappedDispatcher.html
+    /// [`feed_event`]: #method.feed_event
+    pub fn map<H, F>(self, mapping: F) -> MappedDispatcher<H, T, S>
+    where
+        F: Fn(&H) -> Option<T> + Send + Sync + 'static,
+    {
+        MappedDispatcher::new(self, Box::new(mapping))
+    }
+
+    /// Dispatches `event_identifier`, reporting every listener's handle and
+    /// the address identifying the listener itself to `on_call` before
+    /// calling it. Listeners for which `on_call` returns `false` are skipped.
+    /// A vetoed event counts as finished.
+    fn dispatch_with<F>(&mut self, event_identifier: &T, on_call: F) -> ExecuteRequestsResult
+    where
+        F: FnMut(ListenerHandle, *const ()) -> bool,
+    {
+        self.try_dispatch_with(event_identifier, on_call)
+            .unwrap_or(ExecuteRequestsResult::Finished)
+    }
+
+    /// Dispatches like [`dispatch_with`], but returns the [`VetoReason`] of
+    /// a vetoed event.
+    ///
+    /// [`dispatch_with`]: #method.dispatch_with
+    /// [`VetoReason`]: struct.VetoReason.html
+    fn try_dispatch_with<F>(
+        &mut self,
+        event_identifier: &T,
+        on_call: F,
+    ) -> Result<ExecuteRequestsResult, VetoReason>
+    where
+        F: FnMut(ListenerHandle, *const ()) -> bool,
+    {
+        let result = Ok(ExecuteRequestsResult::Finished);
+        let rewritten_event;
+
+        if let Some(history) = &mut self.history {
+            history.record(event_identifier.clone());
+        }
+
+        let event_identifier = match &self.pre_dispatch {
+            Some(pre_dispatch) => match (*pre_dispatch.lock())(event_identifier.clone()) {
+                Some(event) => {
+                    rewritten_event = event;
+                    &rewritten_event
+                }
+                None => return result,
+            },
+            None => event_identifier,
+        };
+
+        let mut replaced_event = None;
+
+        for middleware in &self.middleware {
+            let current = replaced_event.as_ref().unwrap_or(event_identifier);
+
+            match (*middleware.lock())(current) {
+                MiddlewareDecision::Continue => {}
+                MiddlewareDecision::ReplaceWith(event) => replaced_event = Some(event),
+                MiddlewareDecision::Drop => return result,
+            }
+        }
+
+        let event_identifier = replaced_event.as_ref().unwrap_or(event_identifier);
+
+        if let Some(validator) = &self.validator {
+            (*validator.lock())(event_identifier)?;
+        }
+
+        if !self.debounced.is_empty() {
+            if let Some(debounce) = self.debounced.get_mut(event_identifier) {
+                debounce.pending = Some((event_identifier.clone(), now(&self.clock)));
+
+                return result;
+            }
+        }
+
+        if !self.throttled.is_empty() {
+            if let Some(throttle) = self.throttled.get_mut(event_identifier) {
+                let now = now(&self.clock);
+
+                if !throttle.is_open(now) {
+                    if throttle.policy == ThrottlePolicy::Trailing {
+                        throttle.trailing = Some(event_identifier.clone());
+                    }
+
+                    return result;
+                }
+
+                throttle.delivered = Some(now);
+                throttle.trailing = None;
+            }
+        }
+
+        Ok(self.call_listeners(event_identifier, on_call))
+    }
+
+    /// Calls the listeners of `event_identifier` right away, reporting them
+    /// to `on_call` like [`dispatch_with`].
+    ///
+    /// [`dispatch_with`]: #method.dispatch_with
+    fn call_listeners<F>(&mut self, event_identifier: &T, mut on_call: F) -> ExecuteRequestsResult
+    where
+        F: FnMut(ListenerHandle, *const ()) -> bool,
+    {
+        let mut result = ExecuteRequestsResult::Finished;
+        let _forwarding = if self.forwarding {
+            Some(ForwardingGuard::enter(self as *const Self as usize))
+        } else {
+            None
+        };
+
+        #[cfg(feature = "tracing")]
+        let tracer = self.tracer.as_deref();
+        #[cfg(feature = "tracing")]
+        let names = &self.names;
+
+        let listener_collection = match self.events.get_mut(event_identifier) {
+            Some(listener_collection) => listener_collection,
+            // Events nobody listens to are ignored, without adding a key.
+            None => {
+                #[cfg(feature = "tracing")]
+                let _trace = tracer.map(|tracer| TraceGuard::enter(tracer, event_identifier, 0));
+
+                return result;
+            }
+        };
+
+        let mut found_invalid_weak_ref = false;
+
+        if let Some(comparator) = &self.ordering {
+            listener_collection
+                .traits
+                .sort_by(|a, b| comparator(a.handle, b.handle));
+            listener_collection
+                .fns
+                .sort_by(|a, b| comparator(a.handle, b.handle));
+        }
+
+        // Reports the end of the dispatch once dropped, even if a listener panics.
+        #[cfg(feature = "tracing")]
+        let mut trace_guard = tracer.map(|tracer| {
+            let enabled = listener_collection
+                .traits
+                .iter()
+                .filter(|registered| registered.enabled)
+                .count()
+                + listener_collection
+                    .fns
+                    .iter()
+                    .filter(|registered| registered.enabled)
+                    .count();
+
+            TraceGuard::enter(tracer, event_identifier, enabled)
+        });
+
+        #[cfg(feature = "tracing")]
+        let mut trace = |handle: ListenerHandle, request: Option<SyncDispatcherRequest>| {
+            if let Some(trace_guard) = &mut trace_guard {
+                trace_guard.invoked(handle, names.get(&handle).map(String::as_str), &request);
+            }
+
+            request
+        };
+        #[cfg(not(feature = "tracing"))]
+        let trace = |_: ListenerHandle, request: Option<SyncDispatcherRequest>| request;
+
+        let traits_result =
+            execute_sync_dispatcher_requests(&mut listener_collection.traits, |registered| {
+                if !registered.enabled {
+                    None
+                } else if let Some(listener_arc) = registered.listener.upgrade() {
+                    if !on_call(registered.handle, Arc::as_ptr(&listener_arc) as *const ()) {
+                        return None;
+                    }
+
+                    let mut listener = listener_arc.write();
+                    let request = trace(registered.handle, listener.on_event(event_identifier));
+
+                    if removes_listener(&request) {
+                        listener.on_unsubscribe();
+                    }
+
+                    request
+                } else {
+                    found_invalid_weak_ref = true;
+                    None
+                }
+            });
+
+        // Listeners stopping propagation stop the closures as well.
+        if let ExecuteRequestsResult::Stopped = traits_result {
+            result = ExecuteRequestsResult::Stopped;
+        } else {
+            result = execute_sync_dispatcher_requests(&mut listener_collection.fns, |registered| {
+                if !registered.enabled
+                    || !on_call(
+                        registered.handle,
+                        Arc::as_ptr(&registered.listener) as *const (),
+                    )
+                {
+                    return None;
+                }
+
+                trace(registered.handle, (registered.listener)(event_identifier))
+            });
+        }
+
+        if found_invalid_weak_ref {
+            listener_collection
+                .traits
+                .retain(|registered| Weak::clone(&registered.listener).upgrade().is_some());
+        }
+
+        #[cfg(feature = "tracing")]
+        if let Some(trace_guard) = &mut trace_guard {
+            trace_guard.result.stopped = matches!(result, ExecuteRequestsResult::Stopped);
+        }
+
+        result
+    }
+}
+
+/// Reports a dispatch to a [`DispatchTracer`] from being entered until
+/// dropped, see [`Dispatcher::set_tracer`].
+///
+/// [`DispatchTracer`]: trait.DispatchTracer.html
+/// [`Dispatcher::set_tracer`]: struct.Dispatcher.html#method.set_tracer
+#[cfg(feature = "tracing")]
+struct TraceGuard<'a, T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    tracer: &'a dyn DispatchTracer<T>,
+    event: &'a T,
+    result: DispatchResult,
+}
+
+#[cfg(feature = "tracing")]
+impl<'a, T> TraceGuard<'a, T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn enter(tracer: &'a dyn DispatchTracer<T>, event: &'a T, listeners: usize) -> Self {
+        tracer.enter(event, listeners);
+
+        TraceGuard {
+            tracer,
+            event,
+            result: DispatchResult::default(),
+        }
+    }
+
+    fn invoked(
+        &mut self,
+        handle: ListenerHandle,
+        name: Option<&str>,
+        request: &Option<SyncDispatcherRequest>,
+    ) {
+        self.result.invoked += 1;
+        self.tracer
+            .invoked(self.event, handle, name, request.as_ref());
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<'a, T> Drop for TraceGuard<'a, T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        self.tracer.exit(self.event, self.result);
+    }
+}
+
+/// Marks a dispatcher as calling its listeners on the current thread
+/// until dropped, see [`Dispatcher::add_forwarding_target`].
+///
+/// [`Dispatcher::add_forwarding_target`]: struct.Dispatcher.html#method.add_forwarding_target
+struct ForwardingGuard;
+
+impl ForwardingGuard {
+    fn enter(address: usize) -> Self {
+        FORWARDING.with(|forwarding| forwarding.borrow_mut().push(address));
+
+        ForwardingGuard
+    }
+}
+
+impl Drop for ForwardingGuard {
+    fn drop(&mut self) {
+        FORWARDING.with(|forwarding| forwarding.borrow_mut().pop());
+    }
+}
+
+impl<E, S> Dispatcher<Cancelable<E>, S>
+where
+    E: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    S: BuildHasher,
+{
+    /// Dispatches like [`dispatch_event`] and returns whether a listener
+    /// has cancelled `event`.
+    /// If `event` stops on cancel, no listener is called after it has been
+    /// cancelled, otherwise all listeners are called regardless.
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    pub fn dispatch_cancelable(&mut self, event: &Cancelable<E>) -> bool {
+        self.dispatch_with(event, |_, _| !event.is_halted());
+
+        event.is_cancelled()
+    }
+}
+
+/// Reads `clock`, falling back to `Instant::now`.
+fn now(clock: &Option<Clock>) -> Instant {
+    match clock {
+        Some(clock) => clock(),
+        None => Instant::now(),
+    }
+}
+
+/// Registers every `(event_identifier, listener)`-pair in iteration order,
+/// as if [`add_listener`] was called for each of them.
+///
+/// **Note**: The dispatcher only keeps [`Weak`]-references, the listeners
+/// need to be owned elsewhere in order to be dispatched to.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::{
+///    RwLock,
+///    sync::{Listener, Dispatcher, SyncDispatcherRequest},
+/// };
+/// use std::sync::Arc;
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     VariantA,
+///     VariantB,
+/// }
+///
+/// struct ListenerStruct {}
+///
+/// impl Listener<Event> for ListenerStruct {
+///     fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> { None }
+/// }
+///
+/// fn main() {
+///     let listener: Arc<RwLock<dyn Listener<Event> + Send + Sync>> =
+///         Arc::new(RwLock::new(ListenerStruct {}));
+///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+///
+///     dispatcher.extend(vec![
+///         (Event::VariantA, Arc::clone(&listener)),
+///         (Event::VariantB, Arc::clone(&listener)),
+///     ]);
+/// }
+/// ```
+///
+/// [`add_listener`]: struct.Dispatcher.html#method.add_listener
+/// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+impl<T, S> Extend<(T, Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>)> for Dispatcher<T, S>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    S: BuildHasher,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (T, Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>)>,
+    {
+        for (event_identifier, listener) in iter {
+            self.add_trait_object(event_identifier, listener);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Eq, Hash, PartialEq)]
+    enum Event {
+        Save,
+    }
+
+    struct Audio;
+
+    impl Listener<Event> for Audio {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            None
+        }
+    }
+
+    #[test]
+    fn reset_keeps_listener_storage() {
+        let audio = Arc::new(RwLock::new(Audio));
+        let target = Arc::new(RwLock::new(Dispatcher::default()));
+        let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+
+        for _ in 0..3 {
+            dispatcher.add_listener(Event::Save, &audio);
+            dispatcher.add_fn(Event::Save, Box::new(|_| None));
+        }
+        dispatcher.add_forwarding_target(Event::Save, &target);
+
+        dispatcher.reset();
+
+        let listener_collection = &dispatcher.events[&Event::Save];
+        assert!(listener_collection.is_empty());
+        assert!(listener_collection.traits.capacity() >= 3);
+        assert!(listener_collection.fns.capacity() >= 4);
+        assert!(!dispatcher.forwarding);
+        assert_eq!(
+            dispatcher.dispatch_event_with_result(&Event::Save).invoked,
+            0
+        );
     }
 }
@@ -0,0 +1,56 @@
+// `failure_derive` expands into impls nested in anonymous constants.
+#![allow(unknown_lints, non_local_definitions)]
+
+use super::ListenerHandle;
+use failure_derive::Fail;
+
+/// Errors for dispatches that have been refused.
+///
+/// `MaxDepthExceeded` is returned for an event requested by a listener
+/// while dispatching, whose chain of requesting events has grown beyond
+/// the dispatcher's maximal dispatch-depth, e.g. because listeners keep
+/// requesting each other's events.
+#[derive(Fail, Debug, Eq, PartialEq)]
+pub enum DispatchError {
+    #[fail(display = "Nested dispatch exceeds the maximal dispatch-depth")]
+    MaxDepthExceeded,
+}
+
+/// Errors for registrations that have been refused.
+///
+/// `Duplicate` is returned for a listener that is already registered for
+/// the event-variant under `DuplicatePolicy::Error`, carrying the handle
+/// of the existing registration.
+#[derive(Fail, Debug, Eq, PartialEq)]
+pub enum RegisterError {
+    #[fail(display = "Listener is already registered as {:?}", _0)]
+    Duplicate(ListenerHandle),
+}
+
+/// The reason a validator set via [`Dispatcher::set_validator`] gives for
+/// refusing an event, returned by [`Dispatcher::dispatch_event_validated`].
+///
+/// [`Dispatcher::set_validator`]: struct.Dispatcher.html#method.set_validator
+/// [`Dispatcher::dispatch_event_validated`]: struct.Dispatcher.html#method.dispatch_event_validated
+#[derive(Fail, Clone, Debug, Eq, PartialEq)]
+#[fail(display = "Event has been vetoed: {}", _0)]
+pub struct VetoReason(pub String);
+
+/// Errors for topic-patterns refused by a [`TopicDispatcher`].
+///
+/// `InvalidPattern` is returned for a pattern using `#` anywhere but as
+/// its last segment, carrying the pattern.
+///
+/// [`TopicDispatcher`]: struct.TopicDispatcher.html
+#[derive(Fail, Debug, Eq, PartialEq)]
+pub enum TopicError {
+    #[fail(display = "Topic-pattern uses `#` before its last segment: {}", _0)]
+    InvalidPattern(String),
+}
+
+/// Errors for ThreadPool-building related failures.
+#[derive(Fail, Debug)]
+pub enum BuildError {
+    #[fail(display = "Internal error on trying to build thread-pool: {:?}", _0)]
+    NumThreads(String),
+}
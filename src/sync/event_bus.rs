@@ -0,0 +1,176 @@
+use super::{DispatchResult, Dispatcher, Listener, ListenerHandle, RwLock, SyncFn};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    hash::Hash,
+    sync::Arc,
+};
+
+/// A [`Dispatcher`] of one event-type, with the event-type erased.
+trait TypedDispatcher: Send {
+    fn remove_listener(&mut self, handle: ListenerHandle) -> bool;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T> TypedDispatcher for Dispatcher<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn remove_listener(&mut self, handle: ListenerHandle) -> bool {
+        Dispatcher::remove_listener(self, handle)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Owns one [`Dispatcher`] per event-type, routing every event to the
+/// dispatcher of its type. Hence one bus can be passed around instead of
+/// one dispatcher per event-type.
+///
+/// Opposed to the [`AnyDispatcher`], events are still dispatched by their
+/// event-variant, every dispatcher is created on first use and can be
+/// configured via [`dispatcher_mut`].
+/// Dispatching an event-type that has never been used returns right away.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::{
+///     sync::{EventBus, Listener, SyncDispatcherRequest},
+///     RwLock,
+/// };
+/// use std::sync::Arc;
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum WindowEvent {
+///     Resized,
+/// }
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum AudioEvent {
+///     Muted,
+/// }
+///
+/// #[derive(Default)]
+/// struct Status {
+///     resized: bool,
+///     muted: bool,
+/// }
+///
+/// impl Listener<WindowEvent> for Status {
+///     fn on_event(&mut self, _event: &WindowEvent) -> Option<SyncDispatcherRequest> {
+///         self.resized = true;
+///
+///         None
+///     }
+/// }
+///
+/// impl Listener<AudioEvent> for Status {
+///     fn on_event(&mut self, _event: &AudioEvent) -> Option<SyncDispatcherRequest> {
+///         self.muted = true;
+///
+///         None
+///     }
+/// }
+///
+/// fn main() {
+///     let status = Arc::new(RwLock::new(Status::default()));
+///     let mut bus = EventBus::default();
+///
+///     bus.add_listener(WindowEvent::Resized, &status);
+///     bus.add_listener(AudioEvent::Muted, &status);
+///
+///     bus.dispatch_event(&AudioEvent::Muted);
+///
+///     assert!(!status.read().resized);
+///     assert!(status.read().muted);
+/// }
+/// ```
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`AnyDispatcher`]: struct.AnyDispatcher.html
+/// [`dispatcher_mut`]: #method.dispatcher_mut
+#[derive(Default)]
+pub struct EventBus {
+    dispatchers: HashMap<TypeId, Box<dyn TypedDispatcher>>,
+}
+
+impl EventBus {
+    /// Adds a [`Listener`] to listen for `event_identifier` on the
+    /// dispatcher of its event-type, like [`Dispatcher::add_listener`].
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`Dispatcher::add_listener`]: struct.Dispatcher.html#method.add_listener
+    pub fn add_listener<T, D>(
+        &mut self,
+        event_identifier: T,
+        listener: &Arc<RwLock<D>>,
+    ) -> ListenerHandle
+    where
+        T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+        D: Listener<T> + Send + Sync + 'static,
+    {
+        self.dispatcher_mut::<T>()
+            .add_listener(event_identifier, listener)
+    }
+
+    /// Adds a [`Fn`] to listen for `event_identifier` on the dispatcher
+    /// of its event-type, like [`Dispatcher::add_fn`].
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Dispatcher::add_fn`]: struct.Dispatcher.html#method.add_fn
+    pub fn add_fn<T>(&mut self, event_identifier: T, function: SyncFn<T>) -> ListenerHandle
+    where
+        T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    {
+        self.dispatcher_mut::<T>()
+            .add_fn(event_identifier, function)
+    }
+
+    /// Removes the listener or [`Fn`] registered with `handle`,
+    /// no matter its event-type.
+    /// Returns `false` if `handle` is not registered on this bus.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn remove_listener(&mut self, handle: ListenerHandle) -> bool {
+        self.dispatchers
+            .values_mut()
+            .any(|dispatcher| dispatcher.remove_listener(handle))
+    }
+
+    /// Dispatches `event_identifier` via the dispatcher of its event-type,
+    /// like [`Dispatcher::dispatch_event_with_result`].
+    /// An event-type without a dispatcher returns right away.
+    ///
+    /// [`Dispatcher::dispatch_event_with_result`]: struct.Dispatcher.html#method.dispatch_event_with_result
+    pub fn dispatch_event<T>(&mut self, event_identifier: &T) -> DispatchResult
+    where
+        T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    {
+        match self.dispatchers.get_mut(&TypeId::of::<T>()) {
+            Some(dispatcher) => dispatcher
+                .as_any_mut()
+                .downcast_mut::<Dispatcher<T>>()
+                .expect("dispatchers are stored by the `TypeId` of their event-type")
+                .dispatch_event_with_result(event_identifier),
+            None => DispatchResult::default(),
+        }
+    }
+
+    /// Returns the dispatcher of event-type `T`, creating it if missing,
+    /// e.g. to configure its ordering or debouncing.
+    pub fn dispatcher_mut<T>(&mut self) -> &mut Dispatcher<T>
+    where
+        T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    {
+        self.dispatchers
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(Dispatcher::<T>::default()))
+            .as_any_mut()
+            .downcast_mut()
+            .expect("dispatchers are stored by the `TypeId` of their event-type")
+    }
+}
@@ -0,0 +1,92 @@
+use parking_lot::Mutex;
+use std::sync::mpsc::{channel, sync_channel, Receiver, SendError, Sender, SyncSender};
+
+/// A cheap handle to enqueue events into a [`Dispatcher`] from any thread,
+/// obtained via [`Dispatcher::sender`].
+///
+/// Enqueued events are dispatched on the thread calling
+/// [`Dispatcher::poll`]. If the dispatcher has been created via
+/// [`Dispatcher::with_channel_capacity`], [`send`] blocks while the
+/// channel is full, otherwise the channel is unbounded.
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`Dispatcher::sender`]: struct.Dispatcher.html#method.sender
+/// [`Dispatcher::poll`]: struct.Dispatcher.html#method.poll
+/// [`Dispatcher::with_channel_capacity`]: struct.Dispatcher.html#method.with_channel_capacity
+/// [`send`]: #method.send
+#[derive(Debug)]
+pub struct EventSender<T> {
+    inner: SenderKind<T>,
+}
+
+#[derive(Debug)]
+enum SenderKind<T> {
+    Unbounded(Sender<T>),
+    Bounded(SyncSender<T>),
+}
+
+impl<T> Clone for EventSender<T> {
+    fn clone(&self) -> Self {
+        let inner = match &self.inner {
+            SenderKind::Unbounded(sender) => SenderKind::Unbounded(Sender::clone(sender)),
+            SenderKind::Bounded(sender) => SenderKind::Bounded(SyncSender::clone(sender)),
+        };
+
+        EventSender { inner }
+    }
+}
+
+impl<T> EventSender<T> {
+    /// Enqueues `event` to be dispatched by the next [`Dispatcher::poll`].
+    /// Fails and hands `event` back once the dispatcher has been dropped.
+    ///
+    /// [`Dispatcher::poll`]: struct.Dispatcher.html#method.poll
+    pub fn send(&self, event: T) -> Result<(), SendError<T>> {
+        match &self.inner {
+            SenderKind::Unbounded(sender) => sender.send(event),
+            SenderKind::Bounded(sender) => sender.send(event),
+        }
+    }
+}
+
+/// The channel of a [`Dispatcher`], keeping one sender alive itself so
+/// the channel stays open after all handed out senders have been dropped.
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+pub(crate) struct EventChannel<T> {
+    sender: EventSender<T>,
+    receiver: Mutex<Receiver<T>>,
+}
+
+impl<T> EventChannel<T> {
+    /// Opens an unbounded channel or, if `capacity` is given,
+    /// a channel holding at most `capacity` events.
+    pub(crate) fn new(capacity: Option<usize>) -> Self {
+        let (inner, receiver) = match capacity {
+            Some(capacity) => {
+                let (sender, receiver) = sync_channel(capacity);
+
+                (SenderKind::Bounded(sender), receiver)
+            }
+            None => {
+                let (sender, receiver) = channel();
+
+                (SenderKind::Unbounded(sender), receiver)
+            }
+        };
+
+        EventChannel {
+            sender: EventSender { inner },
+            receiver: Mutex::new(receiver),
+        }
+    }
+
+    pub(crate) fn sender(&self) -> EventSender<T> {
+        self.sender.clone()
+    }
+
+    /// Takes all events enqueued so far, in sending-order.
+    pub(crate) fn drain(&self) -> Vec<T> {
+        self.receiver.lock().try_iter().collect()
+    }
+}
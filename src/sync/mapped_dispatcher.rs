@@ -0,0 +1,104 @@
+use super::Dispatcher;
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+};
+
+type Mapping<H, L> = Box<dyn Fn(&H) -> Option<L> + Send + Sync>;
+
+/// A [`Dispatcher`] for event-type `L`, fed with events of another
+/// event-type `H` that are converted on every dispatch.
+/// Created via [`Dispatcher::map`].
+///
+/// Listeners keep listening to `L`, only the adapter needs to know `H`.
+/// Events that convert to `None` are dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::sync::Dispatcher;
+/// use std::sync::{Arc, Mutex};
+///
+/// enum GameEvent {
+///     PlayerJumped,
+///     ScoreChanged(u32),
+/// }
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum SoundEvent {
+///     Jump,
+/// }
+///
+/// fn main() {
+///     let mut sounds: Dispatcher<SoundEvent> = Dispatcher::default();
+///     let played = Arc::new(Mutex::new(0));
+///     let counter = Arc::clone(&played);
+///
+///     sounds.add_fn(
+///         SoundEvent::Jump,
+///         Box::new(move |_| {
+///             *counter.lock().unwrap() += 1;
+///
+///             None
+///         }),
+///     );
+///
+///     let mut game = sounds.map(|event: &GameEvent| match event {
+///         GameEvent::PlayerJumped => Some(SoundEvent::Jump),
+///         GameEvent::ScoreChanged(_) => None,
+///     });
+///
+///     assert!(game.dispatch_event(&GameEvent::PlayerJumped));
+///     assert!(!game.dispatch_event(&GameEvent::ScoreChanged(10)));
+///     assert_eq!(*played.lock().unwrap(), 1);
+/// }
+/// ```
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`Dispatcher::map`]: struct.Dispatcher.html#method.map
+pub struct MappedDispatcher<H, L, S = RandomState>
+where
+    L: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    S: BuildHasher,
+{
+    dispatcher: Dispatcher<L, S>,
+    mapping: Mapping<H, L>,
+    high_level: PhantomData<fn(&H)>,
+}
+
+impl<H, L, S> MappedDispatcher<H, L, S>
+where
+    L: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    S: BuildHasher,
+{
+    pub(crate) fn new(dispatcher: Dispatcher<L, S>, mapping: Mapping<H, L>) -> Self {
+        MappedDispatcher {
+            dispatcher,
+            mapping,
+            high_level: PhantomData,
+        }
+    }
+
+    /// Converts `event` and dispatches the result to the wrapped
+    /// dispatcher. Returns `false` if `event` has been dropped
+    /// by the conversion.
+    pub fn dispatch_event(&mut self, event: &H) -> bool {
+        self.dispatcher.feed_event(event, &self.mapping)
+    }
+
+    /// The wrapped dispatcher, e.g. to inspect its listeners.
+    pub fn dispatcher(&self) -> &Dispatcher<L, S> {
+        &self.dispatcher
+    }
+
+    /// The wrapped dispatcher, e.g. to add or remove listeners.
+    pub fn dispatcher_mut(&mut self) -> &mut Dispatcher<L, S> {
+        &mut self.dispatcher
+    }
+
+    /// Unwraps the dispatcher, dropping the conversion.
+    pub fn into_inner(self) -> Dispatcher<L, S> {
+        self.dispatcher
+    }
+}
@@ -1,22 +1,82 @@
 use super::RwLock;
-use failure_derive::Fail;
 use rayon::ThreadPool;
-use std::{collections::HashMap, hash::Hash, sync::Weak};
+use smallvec::{Array, SmallVec};
+use std::{
+    any::TypeId,
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    ops::DerefMut,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Weak,
+    },
+};
 
+pub mod any_dispatcher;
+#[cfg(feature = "async")]
+pub mod async_dispatcher;
+#[cfg(feature = "tokio")]
+pub mod broadcast_bridge;
+pub mod cancelable;
+pub mod channel_forwarder;
+#[cfg(feature = "tracing")]
+pub mod dispatch_tracer;
 pub mod dispatcher;
+mod error;
+pub mod event_bus;
+pub mod event_sender;
+pub mod mapped_dispatcher;
+pub mod ordered_dispatcher;
 pub mod parallel_dispatcher;
 pub mod priority_dispatcher;
+#[cfg(feature = "tracing")]
+pub mod span_tracer;
+pub mod subscription;
+pub mod topic_dispatcher;
+pub mod value_dispatcher;
 
-pub use dispatcher::Dispatcher;
-pub use parallel_dispatcher::ParallelDispatcher;
-pub use priority_dispatcher::PriorityDispatcher;
+pub use any_dispatcher::AnyDispatcher;
+#[cfg(feature = "async")]
+pub use async_dispatcher::{
+    AsyncDispatcher, AsyncDispatcherRequest, AsyncListener, AsyncListenerFuture,
+};
+#[cfg(feature = "tokio")]
+pub use broadcast_bridge::BroadcastBridge;
+pub use cancelable::Cancelable;
+pub use channel_forwarder::ChannelForwarder;
+#[cfg(feature = "tracing")]
+pub use dispatch_tracer::DispatchTracer;
+pub use dispatcher::{Dispatcher, DuplicatePolicy, MiddlewareDecision, ThrottlePolicy};
+pub use error::{BuildError, DispatchError, RegisterError, TopicError, VetoReason};
+pub use event_bus::EventBus;
+pub use event_sender::EventSender;
+pub use mapped_dispatcher::MappedDispatcher;
+pub use ordered_dispatcher::OrderedDispatcher;
+pub use parallel_dispatcher::{DispatchOutcome, ParallelDispatcher, TimingReport};
+pub use priority_dispatcher::{
+    DispatchReport, PriorityDispatcher, PriorityDispatcherRequest, RedirectingFn, Step,
+};
+#[cfg(feature = "tracing")]
+pub use span_tracer::SpanTracer;
+pub use subscription::Subscription;
+pub use topic_dispatcher::TopicDispatcher;
+pub use value_dispatcher::ValueDispatcher;
 
-type EventFunction<T> = Vec<Box<dyn Fn(&T) -> Option<SyncDispatcherRequest> + Send + Sync>>;
-type ListenerMap<T> = HashMap<T, FnsAndTraits<T>>;
+type SyncFn<T> = Box<dyn Fn(&T) -> Option<SyncDispatcherRequest> + Send + Sync>;
+type SharedSyncFn<T> = Arc<dyn Fn(&T) -> Option<SyncDispatcherRequest> + Send + Sync>;
+type EventFunction<T> = SmallVec<[Registered<SharedSyncFn<T>>; INLINE_LISTENERS]>;
+type ListenerTraits<T> =
+    SmallVec<[Registered<Weak<RwLock<dyn Listener<T> + Send + Sync + 'static>>>; INLINE_LISTENERS]>;
+type SharedListener<T> = Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>;
+type ListenerMap<T, S> = HashMap<T, FnsAndTraits<T>, S>;
 
 type ParallelListenerMap<T> = HashMap<T, ParallelFnsAndTraits<T>>;
-type ParallelEventFunction<T> =
-    Vec<Box<dyn Fn(&T) -> Option<ParallelDispatcherRequest> + Send + Sync>>;
+type ParallelFn<T> = Box<dyn Fn(&T) -> Option<ParallelDispatcherRequest<T>> + Send + Sync>;
+type ParallelEventFunction<T> = Vec<ParallelFn<T>>;
+
+type ValueFn<T, V> = Box<dyn Fn(&T) -> V + Send + Sync>;
+type ValueListenerTraits<T, V> =
+    Vec<Registered<Weak<RwLock<dyn ValueListener<T, V> + Send + Sync + 'static>>>>;
 
 /// An `enum` returning a request from a listener to its `sync` event-dispatcher.
 /// This `enum` is not restricted to dispatcher residing in the `sync`-module.
@@ -29,11 +89,19 @@ type ParallelEventFunction<T> =
 ///
 /// `StopListeningAndPropagation` a combination of first `StopListening`
 /// and then `StopPropagation`.
+///
+/// `Handled` marks the `Event` instance as handled, which stops
+/// propagation just like `StopPropagation`.
+/// [`PriorityDispatcher::dispatch_until_handled`] reports whether
+/// a listener handled the event.
+///
+/// [`PriorityDispatcher::dispatch_until_handled`]: struct.PriorityDispatcher.html#method.dispatch_until_handled
 #[derive(Debug)]
 pub enum SyncDispatcherRequest {
     StopListening,
     StopPropagation,
     StopListeningAndPropagation,
+    Handled,
 }
 
 /// When `execute_sync_dispatcher_requests` returns,
@@ -48,6 +116,16 @@ pub(crate) enum ExecuteRequestsResult {
 /// Every event-receiver needs to implement this trait
 /// in order to receive dispatched events.
 /// `T` being the type you use for events, e.g. an `Enum`.
+///
+/// **Note**: Listeners are locked via [`RwLock`], which does not poison.
+/// A listener panicking in `on_event` unwinds through the dispatch,
+/// but neither its lock nor the dispatcher become unusable afterwards.
+///
+/// A trait-object of a `'static` listener can be turned back into its
+/// concrete type via [`downcast_listener`].
+///
+/// [`RwLock`]: ../type.RwLock.html
+/// [`downcast_listener`]: fn.downcast_listener.html
 pub trait Listener<T>
 where
     T: PartialEq + Eq + Hash + Clone + 'static,
@@ -55,6 +133,293 @@ where
     /// This function will be called once a listened
     /// event-type `T` has been dispatched.
     fn on_event(&mut self, event: &T) -> Option<SyncDispatcherRequest>;
+
+    /// Returns the [`TypeId`] of the listener's concrete type for
+    /// [`downcast_listener`], only available for `'static` listeners.
+    /// Takes an unnameable token, hence it cannot be overridden to report
+    /// a wrong type.
+    ///
+    /// [`TypeId`]: https://doc.rust-lang.org/std/any/struct.TypeId.html
+    /// [`downcast_listener`]: fn.downcast_listener.html
+    #[doc(hidden)]
+    fn listener_type_id(&self, _token: private::TypeIdToken) -> TypeId
+    where
+        Self: 'static,
+    {
+        TypeId::of::<Self>()
+    }
+
+    /// This function will be called by the dispatchers of both modules
+    /// once the listener has been registered, once per registration.
+    /// Does nothing by default.
+    ///
+    /// **Note**: Calls of [`on_subscribe`] and [`on_unsubscribe`]
+    /// take the listener's write-lock, hence it must not be held by the
+    /// thread registering or removing the listener.
+    ///
+    /// [`on_subscribe`]: #method.on_subscribe
+    /// [`on_unsubscribe`]: #method.on_unsubscribe
+    fn on_subscribe(&mut self) {}
+
+    /// This function will be called by the dispatchers of both modules
+    /// once a registration of the listener has been removed, be it
+    /// explicitly, by clearing or restoring the dispatcher, or because
+    /// the listener requested to stop listening.
+    /// Does nothing by default.
+    ///
+    /// **Note**: Dropped listeners are not called, as there is
+    /// nothing left to call.
+    fn on_unsubscribe(&mut self) {}
+}
+
+/// Every [`FnMut`]-closure taking an event and returning a request is a
+/// [`Listener`] itself, hence it can be wrapped in an [`RwLock`] and
+/// registered via `add_listener` like any other listener, e.g. to keep
+/// it in one's own structures and to unregister it by dropping it.
+///
+/// **Note**: The event's type needs to be annotated, as a closure only
+/// takes an event by reference if told so.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::{
+///     sync::{Dispatcher, SyncDispatcherRequest},
+///     RwLock,
+/// };
+/// use std::sync::Arc;
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Tick,
+/// }
+///
+/// fn main() {
+///     let mut ticks = 0;
+///     let listener = Arc::new(RwLock::new(move |_event: &Event| {
+///         ticks += 1;
+///
+///         if ticks == 2 {
+///             Some(SyncDispatcherRequest::StopListening)
+///         } else {
+///             None
+///         }
+///     }));
+///
+///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+///     let handle = dispatcher.add_listener(Event::Tick, &listener);
+///
+///     dispatcher.dispatch_event(&Event::Tick);
+///     assert!(dispatcher.has_listener(handle));
+///
+///     dispatcher.dispatch_event(&Event::Tick);
+///     assert!(!dispatcher.has_listener(handle));
+/// }
+/// ```
+///
+/// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+/// [`Listener`]: trait.Listener.html
+/// [`RwLock`]: ../type.RwLock.html
+impl<T, F> Listener<T> for F
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+    F: FnMut(&T) -> Option<SyncDispatcherRequest> + 'static,
+{
+    fn on_event(&mut self, event: &T) -> Option<SyncDispatcherRequest> {
+        self(event)
+    }
+}
+
+/// Turns a `listener`, e.g. returned by [`Dispatcher::iter_listeners`],
+/// back into its concrete type `C`.
+/// Returns `None` if `listener` is not a `C`.
+///
+/// **Note**: `listener` is locked for reading to find out its type.
+/// While it is locked for writing, e.g. when called from within the
+/// listener's own `on_event`, waiting for the lock would deadlock,
+/// hence `None` is returned instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::{
+///     sync::{downcast_listener, Dispatcher, Listener, SyncDispatcherRequest},
+///     RwLock,
+/// };
+/// use std::sync::Arc;
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Tick,
+/// }
+///
+/// struct Clock {
+///     ticks: usize,
+/// }
+///
+/// impl Listener<Event> for Clock {
+///     fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+///         self.ticks += 1;
+///
+///         None
+///     }
+/// }
+///
+/// fn main() {
+///     let clock = Arc::new(RwLock::new(Clock { ticks: 0 }));
+///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+///     dispatcher.add_listener(Event::Tick, &clock);
+///     dispatcher.dispatch_event(&Event::Tick);
+///
+///     let listener = dispatcher.iter_listeners(&Event::Tick).next().unwrap();
+///     let clock = downcast_listener::<Clock, _>(listener).unwrap();
+///
+///     assert_eq!(clock.read().ticks, 1);
+/// }
+/// ```
+///
+/// [`Dispatcher::iter_listeners`]: dispatcher/struct.Dispatcher.html#method.iter_listeners
+pub fn downcast_listener<C, T>(
+    listener: Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>,
+) -> Option<Arc<RwLock<C>>>
+where
+    C: Listener<T> + Send + Sync + 'static,
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    if listener.try_read()?.listener_type_id(private::TypeIdToken) != TypeId::of::<C>() {
+        return None;
+    }
+
+    let listener = Arc::into_raw(listener) as *const RwLock<C>;
+
+    // The trait-object has been created from an `Arc<RwLock<C>>`, as `C`
+    // is its concrete type, hence the allocation holds a `RwLock<C>`.
+    Some(unsafe { Arc::from_raw(listener) })
+}
+
+mod private {
+    /// Passed to `Listener::listener_type_id`, unnameable outside of this
+    /// module.
+    pub struct TypeIdToken;
+}
+
+/// Combines two listeners into one, see [`chain`].
+///
+/// [`chain`]: fn.chain.html
+#[derive(Clone, Debug, Default)]
+pub struct Chain<A, B> {
+    pub first: A,
+    pub second: B,
+}
+
+/// Combines listener `first` and `second` into one listener, which forwards
+/// every event to `first` and then to `second`.
+///
+/// Both listeners always receive the event, even if `first` requests to
+/// stop propagation. Their requests are merged afterwards:
+///
+/// - If either requests to stop listening, the combined listener stops
+///   listening, as both are removed as one registration.
+/// - If either stops propagation, so does the combined listener,
+///   `Handled` takes precedence over `StopPropagation`.
+/// - Stopping to listen and stopping propagation combine to
+///   `StopListeningAndPropagation`, even if they stem from different listeners.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::{
+///     sync::{chain, Dispatcher, Listener, SyncDispatcherRequest},
+///     RwLock,
+/// };
+/// use std::sync::Arc;
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Save,
+/// }
+///
+/// struct Logger;
+///
+/// impl Listener<Event> for Logger {
+///     fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+///         None
+///     }
+/// }
+///
+/// struct OneShot;
+///
+/// impl Listener<Event> for OneShot {
+///     fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+///         Some(SyncDispatcherRequest::StopListening)
+///     }
+/// }
+///
+/// fn main() {
+///     let listener = Arc::new(RwLock::new(chain(Logger, OneShot)));
+///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+///     let handle = dispatcher.add_listener(Event::Save, &listener);
+///
+///     dispatcher.dispatch_event(&Event::Save);
+///
+///     assert!(!dispatcher.has_listener(handle));
+/// }
+/// ```
+pub fn chain<T, A, B>(first: A, second: B) -> Chain<A, B>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+    A: Listener<T>,
+    B: Listener<T>,
+{
+    Chain { first, second }
+}
+
+impl<T, A, B> Listener<T> for Chain<A, B>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+    A: Listener<T>,
+    B: Listener<T>,
+{
+    fn on_event(&mut self, event: &T) -> Option<SyncDispatcherRequest> {
+        let requests = [self.first.on_event(event), self.second.on_event(event)];
+
+        let stops_listening = requests.iter().any(|request| {
+            matches!(
+                request,
+                Some(SyncDispatcherRequest::StopListening)
+                    | Some(SyncDispatcherRequest::StopListeningAndPropagation)
+            )
+        });
+        let handled = requests
+            .iter()
+            .any(|request| matches!(request, Some(SyncDispatcherRequest::Handled)));
+        let stops_propagation = handled
+            || requests.iter().any(|request| {
+                matches!(
+                    request,
+                    Some(SyncDispatcherRequest::StopPropagation)
+                        | Some(SyncDispatcherRequest::StopListeningAndPropagation)
+                )
+            });
+
+        match (stops_listening, stops_propagation) {
+            (true, true) => Some(SyncDispatcherRequest::StopListeningAndPropagation),
+            (true, false) => Some(SyncDispatcherRequest::StopListening),
+            (false, true) if handled => Some(SyncDispatcherRequest::Handled),
+            (false, true) => Some(SyncDispatcherRequest::StopPropagation),
+            (false, false) => None,
+        }
+    }
+
+    fn on_subscribe(&mut self) {
+        self.first.on_subscribe();
+        self.second.on_subscribe();
+    }
+
+    fn on_unsubscribe(&mut self) {
+        self.first.on_unsubscribe();
+        self.second.on_unsubscribe();
+    }
 }
 
 /// Iterates over the passed `vec` and applies `function` to each element.
@@ -66,11 +431,10 @@ where
 /// in `vec`.
 /// `StopListeningAndPropagation`: Execute `StopListening`,
 /// then execute `StopPropagation`.
+/// `Handled`: Same as `StopPropagation`.
 ///
-/// **Note**: When `StopListening` is being executed,
-/// removal of items from `vec` will result use a swap of elements,
-/// resulting in an alteration of the order items were originally
-/// inserted into `vec`.
+/// **Note**: When `StopListening` is being executed, the remaining items
+/// of `vec` keep the order they were originally inserted into `vec`.
 ///
 /// **Note**: Unlike [`retain`], `execute_sync_dispatcher_requests`
 /// can break the current iteration and is able to match [`SyncDispatcherRequest`]
@@ -78,11 +442,12 @@ where
 ///
 /// [`retain`]: https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.retain
 /// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
-pub(crate) fn execute_sync_dispatcher_requests<T, F>(
-    vec: &mut Vec<T>,
+pub(crate) fn execute_sync_dispatcher_requests<T, V, F>(
+    vec: &mut V,
     mut function: F,
 ) -> ExecuteRequestsResult
 where
+    V: Registrations<T>,
     F: FnMut(&T) -> Option<SyncDispatcherRequest>,
 {
     let mut index = 0;
@@ -92,13 +457,12 @@ where
             match function(&vec[index]) {
                 None => index += 1,
                 Some(SyncDispatcherRequest::StopListening) => {
-                    vec.swap_remove(index);
-                }
-                Some(SyncDispatcherRequest::StopPropagation) => {
-                    return ExecuteRequestsResult::Stopped
+                    vec.remove(index);
                 }
+                Some(SyncDispatcherRequest::StopPropagation)
+                | Some(SyncDispatcherRequest::Handled) => return ExecuteRequestsResult::Stopped,
                 Some(SyncDispatcherRequest::StopListeningAndPropagation) => {
-                    vec.swap_remove(index);
+                    vec.remove(index);
                     return ExecuteRequestsResult::Stopped;
                 }
             }
@@ -108,12 +472,211 @@ where
     }
 }
 
+/// Applies `dispatch_level` to every priority-level of `levels` in
+/// ascending order, until a level reports having been stopped.
+///
+/// Shared by the `sync`- and `rc`-[`PriorityDispatcher`] to keep their
+/// dispatch-order in line.
+///
+/// [`PriorityDispatcher`]: struct.PriorityDispatcher.html
+pub(crate) fn dispatch_by_priority<P, L, F>(
+    levels: &mut BTreeMap<P, L>,
+    mut dispatch_level: F,
+) -> ExecuteRequestsResult
+where
+    P: Ord,
+    F: FnMut(&mut L) -> ExecuteRequestsResult,
+{
+    for level in levels.values_mut() {
+        if let ExecuteRequestsResult::Stopped = dispatch_level(level) {
+            return ExecuteRequestsResult::Stopped;
+        }
+    }
+
+    ExecuteRequestsResult::Finished
+}
+
+/// Summarises one dispatch of an event.
+///
+/// A dispatch without any listener has been `invoked` zero times,
+/// telling it apart from listeners that ran but ignored the event.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DispatchResult {
+    /// How many listeners and closures have been called.
+    pub invoked: usize,
+    /// Whether a listener stopped propagation before
+    /// all listeners have been called.
+    pub stopped: bool,
+}
+
+/// Identifies one registration of a listener or [`Fn`] on a dispatcher.
+/// Every `add_*`-method returns a new, unique handle.
+///
+/// **Note**: Registering the same listener twice yields two
+/// different handles.
+///
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ListenerHandle(usize);
+
+impl ListenerHandle {
+    /// Creates a handle that has never been handed out before.
+    pub(crate) fn new() -> Self {
+        static NEXT_HANDLE: AtomicUsize = AtomicUsize::new(0);
+
+        ListenerHandle(NEXT_HANDLE.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// A listener or closure tagged with the handle it has been registered with
+/// and whether it is enabled, disabled registrations are skipped by the
+/// `sync`-dispatchers honouring `set_listener_enabled`.
+#[derive(Clone)]
+pub(crate) struct Registered<L> {
+    pub(crate) handle: ListenerHandle,
+    pub(crate) listener: L,
+    pub(crate) enabled: bool,
+}
+
+impl<L> Registered<L> {
+    pub(crate) fn new(listener: L) -> Self {
+        Registered {
+            handle: ListenerHandle::new(),
+            listener,
+            enabled: true,
+        }
+    }
+}
+
+impl<T> Registered<Weak<RwLock<dyn Listener<T> + Send + Sync + 'static>>>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    /// Calls `on_subscribe` on `listener` and tags it with a new handle.
+    pub(crate) fn subscribe(listener: &SharedListener<T>) -> Self {
+        listener.write().on_subscribe();
+
+        Registered::new(Arc::downgrade(listener))
+    }
+
+    /// Calls `on_unsubscribe` on the listener, unless it has been dropped.
+    pub(crate) fn unsubscribe(&self) {
+        if let Some(listener) = self.listener.upgrade() {
+            listener.write().on_unsubscribe();
+        }
+    }
+}
+
+/// Returns whether `request` asks to remove the listener returning it.
+pub(crate) fn removes_listener(request: &Option<SyncDispatcherRequest>) -> bool {
+    match request {
+        Some(SyncDispatcherRequest::StopListening)
+        | Some(SyncDispatcherRequest::StopListeningAndPropagation) => true,
+        Some(SyncDispatcherRequest::StopPropagation)
+        | Some(SyncDispatcherRequest::Handled)
+        | None => false,
+    }
+}
+
+/// Number of listeners and closures each event-variant of a [`Dispatcher`]
+/// stores inline, only event-variants with more registrations allocate.
+///
+/// [`Dispatcher`]: struct.Dispatcher.html
+const INLINE_LISTENERS: usize = 2;
+
+/// Storage of registrations, letting the helpers below operate on
+/// [`Vec`]s and inline [`SmallVec`]s alike.
+///
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+/// [`SmallVec`]: https://docs.rs/smallvec/0.6/smallvec/struct.SmallVec.html
+pub(crate) trait Registrations<L>: DerefMut<Target = [L]> {
+    fn remove(&mut self, index: usize) -> L;
+}
+
+impl<L> Registrations<L> for Vec<L> {
+    fn remove(&mut self, index: usize) -> L {
+        Vec::remove(self, index)
+    }
+}
+
+impl<A: Array> Registrations<A::Item> for SmallVec<A> {
+    fn remove(&mut self, index: usize) -> A::Item {
+        SmallVec::remove(self, index)
+    }
+}
+
+/// Removes the registration identified by `handle` from `vec`,
+/// keeping the order of all remaining registrations.
+/// Returns whether `handle` has been found.
+pub(crate) fn remove_registered<L, V>(vec: &mut V, handle: ListenerHandle) -> bool
+where
+    V: Registrations<Registered<L>>,
+{
+    take_registered(vec, handle).is_some()
+}
+
+/// Removes the registration identified by `handle` from `vec` like
+/// [`remove_registered`], but returns the removed registration.
+///
+/// [`remove_registered`]: fn.remove_registered.html
+pub(crate) fn take_registered<L, V>(vec: &mut V, handle: ListenerHandle) -> Option<Registered<L>>
+where
+    V: Registrations<Registered<L>>,
+{
+    vec.iter()
+        .position(|registered| registered.handle == handle)
+        .map(|index| vec.remove(index))
+}
+
+/// A copy of all registrations of a dispatcher, taken via
+/// [`Dispatcher::snapshot`] or [`PriorityDispatcher::snapshot`] and put
+/// back in place via their `restore`-methods, e.g. to roll back to a
+/// known-good wiring.
+/// `P` is the priority-level, the [`Dispatcher`] has none.
+///
+/// The snapshot holds [`Arc`]s of the listeners and [`Fn`]s,
+/// keeping them alive as long as the snapshot lives.
+/// Listeners that have been dropped before taking the snapshot are
+/// not part of it.
+/// The snapshot of a [`Dispatcher`] keeps the names of listeners, see
+/// [`Dispatcher::add_named_listener`].
+///
+/// [`Dispatcher::snapshot`]: struct.Dispatcher.html#method.snapshot
+/// [`PriorityDispatcher::snapshot`]: struct.PriorityDispatcher.html#method.snapshot
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`Dispatcher::add_named_listener`]: struct.Dispatcher.html#method.add_named_listener
+/// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+#[derive(Clone)]
+pub struct DispatcherSnapshot<T, P = ()>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    levels: Vec<(T, P, SnapshotLevel<T>)>,
+    names: HashMap<ListenerHandle, String>,
+}
+
+/// The listeners and closures of one event-variant and priority-level,
+/// held strongly by a [`DispatcherSnapshot`] along with the handles they
+/// have been registered with when the snapshot has been taken.
+///
+/// [`DispatcherSnapshot`]: struct.DispatcherSnapshot.html
+#[derive(Clone)]
+struct SnapshotLevel<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    traits: Vec<(ListenerHandle, SharedListener<T>)>,
+    fns: Vec<(ListenerHandle, SharedSyncFn<T>)>,
+}
+
 /// Yields closures and trait-objects.
+#[derive(Clone)]
 struct FnsAndTraits<T>
 where
     T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
 {
-    traits: Vec<Weak<RwLock<dyn Listener<T> + Send + Sync + 'static>>>,
+    traits: ListenerTraits<T>,
     fns: EventFunction<T>,
 }
 
@@ -121,21 +684,228 @@ impl<T> FnsAndTraits<T>
 where
     T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
 {
-    fn new_with_traits(
-        trait_objects: Vec<Weak<RwLock<dyn Listener<T> + Send + Sync + 'static>>>,
-    ) -> Self {
+    fn new() -> Self {
+        FnsAndTraits {
+            traits: SmallVec::new(),
+            fns: SmallVec::new(),
+        }
+    }
+
+    /// Dispatches `event_identifier` to all listeners and then all closures,
+    /// passing every request returned from them to `inspect` before
+    /// executing it.
+    /// The registration identified by `skip` and disabled registrations
+    /// are not called.
+    fn dispatch<F>(
+        &mut self,
+        event_identifier: &T,
+        skip: Option<ListenerHandle>,
+        inspect: &mut F,
+    ) -> ExecuteRequestsResult
+    where
+        F: FnMut(&Option<SyncDispatcherRequest>),
+    {
+        self.dispatch_invoking(event_identifier, skip, &mut |_, call| {
+            let request = call();
+            inspect(&request);
+
+            request
+        })
+    }
+
+    /// Dispatches like [`dispatch`], but lets `invoke` call each listener,
+    /// e.g. to catch its panics, and executes the request `invoke` returns.
+    /// `invoke` receives the handle of the registration it calls.
+    ///
+    /// [`dispatch`]: #method.dispatch
+    fn dispatch_invoking<F>(
+        &mut self,
+        event_identifier: &T,
+        skip: Option<ListenerHandle>,
+        invoke: &mut F,
+    ) -> ExecuteRequestsResult
+    where
+        F: FnMut(
+            ListenerHandle,
+            &mut dyn FnMut() -> Option<SyncDispatcherRequest>,
+        ) -> Option<SyncDispatcherRequest>,
+    {
+        let mut found_invalid_weak_ref = false;
+
+        if let ExecuteRequestsResult::Stopped =
+            execute_sync_dispatcher_requests(&mut self.traits, |registered| {
+                if Some(registered.handle) == skip || !registered.enabled {
+                    None
+                } else if let Some(listener_arc) = registered.listener.upgrade() {
+                    let request = invoke(registered.handle, &mut || {
+                        listener_arc.write().on_event(event_identifier)
+                    });
+
+                    if removes_listener(&request) {
+                        listener_arc.write().on_unsubscribe();
+                    }
+
+                    request
+                } else {
+                    found_invalid_weak_ref = true;
+                    None
+                }
+            })
+        {
+            return ExecuteRequestsResult::Stopped;
+        }
+
+        let result = execute_sync_dispatcher_requests(&mut self.fns, |registered| {
+            if Some(registered.handle) == skip || !registered.enabled {
+                return None;
+            }
+
+            invoke(registered.handle, &mut || {
+                (registered.listener)(event_identifier)
+            })
+        });
+
+        if found_invalid_weak_ref {
+            self.traits
+                .retain(|registered| registered.listener.strong_count() > 0);
+        }
+
+        result
+    }
+
+    fn new_with_traits(trait_objects: ListenerTraits<T>) -> Self {
         FnsAndTraits {
             traits: trait_objects,
-            fns: vec![],
+            fns: SmallVec::new(),
         }
     }
 
     fn new_with_fns(fns: EventFunction<T>) -> Self {
         FnsAndTraits {
-            traits: vec![],
+            traits: SmallVec::new(),
             fns,
         }
     }
+
+    /// Registers the listeners and closures of `level` anew,
+    /// each under a new handle.
+    fn from_snapshot<F>(level: &SnapshotLevel<T>, mut renumbered: F) -> Self
+    where
+        F: FnMut(ListenerHandle, ListenerHandle),
+    {
+        FnsAndTraits {
+            traits: level
+                .traits
+                .iter()
+                .map(|(handle, listener)| {
+                    let registered = Registered::subscribe(listener);
+                    renumbered(*handle, registered.handle);
+
+                    registered
+                })
+                .collect(),
+            fns: level
+                .fns
+                .iter()
+                .map(|(handle, function)| {
+                    let registered = Registered::new(Arc::clone(function));
+                    renumbered(*handle, registered.handle);
+
+                    registered
+                })
+                .collect(),
+        }
+    }
+
+    /// Holds all listeners that are still alive and all closures strongly.
+    fn snapshot(&self) -> SnapshotLevel<T> {
+        SnapshotLevel {
+            traits: self
+                .traits
+                .iter()
+                .filter_map(|registered| {
+                    registered
+                        .listener
+                        .upgrade()
+                        .map(|listener| (registered.handle, listener))
+                })
+                .collect(),
+            fns: self
+                .fns
+                .iter()
+                .map(|registered| (registered.handle, Arc::clone(&registered.listener)))
+                .collect(),
+        }
+    }
+
+    /// Removes the listener or [`Fn`] registered with `handle`,
+    /// unsubscribing a removed listener.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    fn remove(&mut self, handle: ListenerHandle) -> bool {
+        match take_registered(&mut self.traits, handle) {
+            Some(registered) => {
+                registered.unsubscribe();
+
+                true
+            }
+            None => remove_registered(&mut self.fns, handle),
+        }
+    }
+
+    /// Removes all listeners and [`Fn`]s, unsubscribing the listeners.
+    /// The storage allocated so far is kept.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    fn clear(&mut self) {
+        for registered in self.traits.drain() {
+            registered.unsubscribe();
+        }
+
+        self.fns.clear();
+    }
+
+    /// Enables or disables the listener or [`Fn`] registered with `handle`.
+    /// Returns whether `handle` has been found.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    fn set_enabled(&mut self, handle: ListenerHandle, enabled: bool) -> bool {
+        let FnsAndTraits { traits, fns } = self;
+        let enabled_flag = traits
+            .iter_mut()
+            .find(|registered| registered.handle == handle)
+            .map(|registered| &mut registered.enabled)
+            .or_else(|| {
+                fns.iter_mut()
+                    .find(|registered| registered.handle == handle)
+                    .map(|registered| &mut registered.enabled)
+            });
+
+        match enabled_flag {
+            Some(enabled_flag) => {
+                *enabled_flag = enabled;
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.traits.is_empty() && self.fns.is_empty()
+    }
+
+    /// Returns `None` if `handle` is not registered, otherwise whether
+    /// the registered listener can still be called.
+    fn liveness(&self, handle: ListenerHandle) -> Option<bool> {
+        if let Some(registered) = self.traits.iter().find(|r| r.handle == handle) {
+            Some(registered.listener.strong_count() > 0)
+        } else if self.fns.iter().any(|r| r.handle == handle) {
+            Some(true)
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -160,7 +930,7 @@ mod tests {
             let mut vec = vec![0, 0, 0, 1, 1, 1, 1];
             execute_sync_dispatcher_requests(&mut vec, map_usize_to_request);
 
-            assert_eq!(vec, [1, 0, 0, 1, 1, 1]);
+            assert_eq!(vec, [1, 1, 1, 1]);
         }
 
         #[test]
@@ -194,20 +964,28 @@ mod tests {
 /// `StopListening` will remove your [`Listener`] from the
 /// event-dispatcher.
 ///
+/// `DispatchEvent` will dispatch the wrapped event as a follow-up,
+/// once all listeners of the current event have been joined.
+/// Follow-up events of follow-up events are dispatched up to the
+/// dispatcher's maximal dispatch-depth, see
+/// [`ParallelDispatcher::set_max_dispatch_depth`].
+///
 /// **Note**:
 /// Opposed to `SyncDispatcherRequest` a [`Listener`] cannot
 /// stop propagation as the propagation is happening parallel.
 ///
 /// [`Listener`]: trait.Listener.html
+/// [`ParallelDispatcher::set_max_dispatch_depth`]: struct.ParallelDispatcher.html#method.set_max_dispatch_depth
 #[derive(Debug)]
-pub enum ParallelDispatcherRequest {
+pub enum ParallelDispatcherRequest<T> {
     StopListening,
+    DispatchEvent(T),
 }
 
 /// Yields `Send` and `Sync` closures and trait-objects.
 struct ParallelFnsAndTraits<T>
 where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    T: PartialEq + Eq + Hash + Clone + Send + 'static,
 {
     traits: Vec<Weak<RwLock<dyn ParallelListener<T> + Send + Sync + 'static>>>,
     fns: ParallelEventFunction<T>,
@@ -215,7 +993,7 @@ where
 
 impl<T> ParallelFnsAndTraits<T>
 where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    T: PartialEq + Eq + Hash + Clone + Send + 'static,
 {
     fn new_with_traits(
         trait_objects: Vec<Weak<RwLock<dyn ParallelListener<T> + Send + Sync + 'static>>>,
@@ -237,18 +1015,86 @@ where
 /// Every event-receiver needs to implement this trait
 /// in order to receive dispatched events.
 /// `T` being the type you use for events, e.g. an `Enum`.
+///
+/// **Note**: [`ParallelDispatcher`] calls listeners from multiple threads,
+/// it only accepts implementors that are `Send + Sync + 'static`.
+///
+/// [`ParallelDispatcher`]: struct.ParallelDispatcher.html
 pub trait ParallelListener<T>
 where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    T: PartialEq + Eq + Hash + Clone + Send + 'static,
 {
     /// This function will be called once a listened
     /// event-type `T` has been dispatched.
-    fn on_event(&mut self, event: &T) -> Option<ParallelDispatcherRequest>;
+    fn on_event(&mut self, event: &T) -> Option<ParallelDispatcherRequest<T>>;
+
+    /// This function will be called once per listened event-type `T`
+    /// of a batch dispatched via [`ParallelDispatcher::dispatch_events`].
+    /// `events` holds the batch's events of that type in batch-order,
+    /// hence listeners building state from the sequence of events can
+    /// rely on it.
+    ///
+    /// By default, [`on_event`] is called for every event in order and
+    /// the last returned request is kept, hence only one follow-up event
+    /// can be requested per call.
+    ///
+    /// [`ParallelDispatcher::dispatch_events`]: struct.ParallelDispatcher.html#method.dispatch_events
+    /// [`on_event`]: #tymethod.on_event
+    fn on_events(&mut self, events: &[T]) -> Option<ParallelDispatcherRequest<T>> {
+        let mut request = None;
+
+        for event in events {
+            if let Some(event_request) = self.on_event(event) {
+                request = Some(event_request);
+            }
+        }
+
+        request
+    }
+}
+
+/// Every event-receiver of a [`ValueDispatcher`] needs to implement
+/// this trait, `V` being the value handed back to the dispatcher.
+///
+/// [`ValueDispatcher`]: struct.ValueDispatcher.html
+pub trait ValueListener<T, V>
+where
+    T: PartialEq + Eq + Hash + Clone + 'static,
+{
+    /// This function will be called once a listened
+    /// event-type `T` has been dispatched.
+    fn on_event(&mut self, event: &T) -> V;
+}
+
+/// Yields value-returning closures and trait-objects.
+struct ValueFnsAndTraits<T, V>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    traits: ValueListenerTraits<T, V>,
+    fns: Vec<Registered<ValueFn<T, V>>>,
 }
 
-/// Errors for ThreadPool-building related failures.
-#[derive(Fail, Debug)]
-pub enum BuildError {
-    #[fail(display = "Internal error on trying to build thread-pool: {:?}", _0)]
-    NumThreads(String),
+impl<T, V> Default for ValueFnsAndTraits<T, V>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        ValueFnsAndTraits {
+            traits: vec![],
+            fns: vec![],
+        }
+    }
 }
+
+/// The maximal dispatch-depth dispatchers start with, see
+/// [`DispatchError::MaxDepthExceeded`].
+///
+/// [`DispatchError::MaxDepthExceeded`]: enum.DispatchError.html#variant.MaxDepthExceeded
+pub const DEFAULT_MAX_DISPATCH_DEPTH: usize = 128;
+
+/// The placeholder [`Dispatcher::listener_names`] lists registrations
+/// under that have not been given a name.
+///
+/// [`Dispatcher::listener_names`]: struct.Dispatcher.html#method.listener_names
+pub const UNNAMED_LISTENER: &str = "<unnamed>";
@@ -0,0 +1,228 @@
+use super::{
+    execute_sync_dispatcher_requests, removes_listener, ExecuteRequestsResult, FnsAndTraits,
+    Listener, ListenerHandle, Registered, RwLock, SyncFn,
+};
+use std::{
+    collections::BTreeMap,
+    hash::Hash,
+    ops::RangeBounds,
+    sync::{Arc, Weak},
+};
+
+type OrderedListenerMap<T> = BTreeMap<T, FnsAndTraits<T>>;
+
+/// In charge of sync dispatching to all listeners of ordered event-types.
+/// Owns a [`BTreeMap`] of event-variants and [`Weak`]-references to their
+/// listeners and/or owns [`Fn`]s.
+/// Opposed to [`Dispatcher`], the sorted keys allow dispatching an event to
+/// the listeners of a whole range of keys, e.g. numeric event-IDs.
+///
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+/// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+/// [`Dispatcher`]: struct.Dispatcher.html
+pub struct OrderedDispatcher<T>
+where
+    T: PartialEq + Eq + Hash + Ord + Clone + Send + Sync + 'static,
+{
+    events: OrderedListenerMap<T>,
+}
+
+impl<T> Default for OrderedDispatcher<T>
+where
+    T: PartialEq + Eq + Hash + Ord + Clone + Send + Sync + 'static,
+{
+    fn default() -> OrderedDispatcher<T> {
+        OrderedDispatcher {
+            events: OrderedListenerMap::new(),
+        }
+    }
+}
+
+impl<T> OrderedDispatcher<T>
+where
+    T: PartialEq + Eq + Hash + Ord + Clone + Send + Sync + 'static,
+{
+    /// Adds a [`Listener`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`BTreeMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_listener<D: Listener<T> + Send + Sync + 'static>(
+        &mut self,
+        event_identifier: T,
+        listener: &Arc<RwLock<D>>,
+    ) -> ListenerHandle {
+        let registered = Registered::subscribe(
+            &(Arc::clone(listener) as Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>),
+        );
+        let handle = registered.handle;
+
+        self.events
+            .entry(event_identifier)
+            .or_insert_with(FnsAndTraits::new)
+            .traits
+            .push(registered);
+
+        handle
+    }
+
+    /// Adds an [`Fn`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`BTreeMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_fn(&mut self, event_identifier: T, function: SyncFn<T>) -> ListenerHandle {
+        let registered = Registered::new(Arc::from(function));
+        let handle = registered.handle;
+
+        self.events
+            .entry(event_identifier)
+            .or_insert_with(FnsAndTraits::new)
+            .fns
+            .push(registered);
+
+        handle
+    }
+
+    /// Removes the listener or [`Fn`] registered with `handle`.
+    /// Returns `false` if `handle` is not registered on this dispatcher.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn remove_listener(&mut self, handle: ListenerHandle) -> bool {
+        let event_identifier =
+            self.events
+                .iter_mut()
+                .find_map(|(event_identifier, listener_collection)| {
+                    if listener_collection.remove(handle) {
+                        Some(event_identifier.clone())
+                    } else {
+                        None
+                    }
+                });
+
+        match event_identifier {
+            Some(event_identifier) => {
+                if self.events[&event_identifier].is_empty() {
+                    self.events.remove(&event_identifier);
+                }
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// All [`Listener`]s listening to a passed `event_identifier`
+    /// will be called first, followed by all [`Fn`]s.
+    /// Returns how many of them have been called.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn dispatch_event(&mut self, event_identifier: &T) -> usize {
+        self.dispatch_range(event_identifier..=event_identifier, event_identifier)
+    }
+
+    /// Dispatches `event` to the listeners of every key within `range`,
+    /// in ascending key-order, and returns how many listeners have been
+    /// called.
+    /// Per key, [`Listener`]s are called before [`Fn`]s.
+    /// A [`SyncDispatcherRequest`] stopping propagation stops dispatching
+    /// to the remaining keys as well.
+    ///
+    /// **Note**: Like [`BTreeMap::range_mut`], this panics if `range`
+    /// starts after its end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::OrderedDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    /// struct EventId(u16);
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: OrderedDispatcher<EventId> = OrderedDispatcher::default();
+    ///     dispatcher.add_fn(EventId(100), Box::new(|_| None));
+    ///     dispatcher.add_fn(EventId(150), Box::new(|_| None));
+    ///     dispatcher.add_fn(EventId(200), Box::new(|_| None));
+    ///
+    ///     let called = dispatcher.dispatch_range(EventId(100)..EventId(200), &EventId(100));
+    ///
+    ///     assert_eq!(called, 2);
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
+    /// [`BTreeMap::range_mut`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html#method.range_mut
+    pub fn dispatch_range<R>(&mut self, range: R, event: &T) -> usize
+    where
+        R: RangeBounds<T>,
+    {
+        let mut invoked = 0;
+
+        for listener_collection in self.events.range_mut(range).map(|(_, value)| value) {
+            if let ExecuteRequestsResult::Stopped =
+                dispatch_to_collection(listener_collection, event, &mut invoked)
+            {
+                break;
+            }
+        }
+
+        invoked
+    }
+}
+
+/// Dispatches `event` to the listeners and then the [`Fn`]s of one key,
+/// counting every call in `invoked`.
+///
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+fn dispatch_to_collection<T>(
+    listener_collection: &mut FnsAndTraits<T>,
+    event: &T,
+    invoked: &mut usize,
+) -> ExecuteRequestsResult
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    let mut found_invalid_weak_ref = false;
+
+    let mut result =
+        execute_sync_dispatcher_requests(&mut listener_collection.traits, |registered| {
+            if let Some(listener_arc) = registered.listener.upgrade() {
+                *invoked += 1;
+                let mut listener = listener_arc.write();
+                let request = listener.on_event(event);
+
+                if removes_listener(&request) {
+                    listener.on_unsubscribe();
+                }
+
+                request
+            } else {
+                found_invalid_weak_ref = true;
+                None
+            }
+        });
+
+    if let ExecuteRequestsResult::Finished = result {
+        result = execute_sync_dispatcher_requests(&mut listener_collection.fns, |registered| {
+            *invoked += 1;
+            (registered.listener)(event)
+        });
+    }
+
+    if found_invalid_weak_ref {
+        listener_collection
+            .traits
+            .retain(|registered| Weak::clone(&registered.listener).upgrade().is_some());
+    }
+
+    result
+}
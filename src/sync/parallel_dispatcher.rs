@@ -1,44 +1,173 @@
 use super::{
-    super::RwLock, BuildError, ParallelDispatcherRequest, ParallelFnsAndTraits, ParallelListener,
-    ParallelListenerMap, ThreadPool,
+    super::RwLock, BuildError, DispatchError, Dispatcher, EventSender, ParallelDispatcherRequest,
+    ParallelFn, ParallelFnsAndTraits, ParallelListener, ParallelListenerMap, SyncDispatcherRequest,
+    ThreadPool, DEFAULT_MAX_DISPATCH_DEPTH,
 };
 use rayon::{
     join,
-    prelude::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator},
+    prelude::{IntoParallelIterator, ParallelIterator},
     ThreadPoolBuilder,
 };
-use std::{error::Error, hash::Hash, sync::Arc};
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+    ptr,
+    sync::{Arc, Weak},
+    time::{Duration, Instant},
+};
+
+/// Wall-clock durations measured during one dispatch via
+/// [`ParallelDispatcher::dispatch_event_timed`].
+///
+/// [`ParallelDispatcher::dispatch_event_timed`]: struct.ParallelDispatcher.html#method.dispatch_event_timed
+#[derive(Clone, Debug, Default)]
+pub struct TimingReport {
+    /// Time spent per [`ParallelListener`], paired with the listener's index
+    /// in registration-order, listeners that have been dropped are missing.
+    ///
+    /// [`ParallelListener`]: trait.ParallelListener.html
+    pub listeners: Vec<(usize, Duration)>,
+    /// Time spent per [`Fn`], paired with its index in registration-order.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fns: Vec<(usize, Duration)>,
+    /// Time from starting the dispatch until all listeners have been joined.
+    pub total: Duration,
+}
+
+/// The outcome of a dispatch via
+/// [`ParallelDispatcher::dispatch_event_timeout`], telling which listeners
+/// have been skipped for exceeding the time budget.
+///
+/// [`ParallelDispatcher::dispatch_event_timeout`]: struct.ParallelDispatcher.html#method.dispatch_event_timeout
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DispatchOutcome {
+    /// How many listeners and [`Fn`]s have been called.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub completed: usize,
+    /// The indices in registration-order of all [`ParallelListener`]s that
+    /// have not been called, ascending.
+    /// Indices refer to the listeners left after the dispatch, once the
+    /// listeners that stopped listening have been removed.
+    ///
+    /// [`ParallelListener`]: trait.ParallelListener.html
+    pub skipped_listeners: Vec<usize>,
+    /// The indices in registration-order of all [`Fn`]s that have not
+    /// been called, ascending.
+    /// Indices refer to the [`Fn`]s left after the dispatch, like
+    /// `skipped_listeners`.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub skipped_fns: Vec<usize>,
+    /// Whether follow-up events have been dropped for exceeding the
+    /// maximal dispatch-depth, see
+    /// [`ParallelDispatcher::try_dispatch_event`].
+    ///
+    /// [`ParallelDispatcher::try_dispatch_event`]: struct.ParallelDispatcher.html#method.try_dispatch_event
+    pub max_depth_exceeded: bool,
+}
+
+impl DispatchOutcome {
+    /// How many listeners and [`Fn`]s have been skipped.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn skipped(&self) -> usize {
+        self.skipped_listeners.len() + self.skipped_fns.len()
+    }
+}
 
 /// In charge of parallel dispatching to all listeners.
 /// Owns a map event-variants and [`Weak`]-references to their listeners
 /// and/or owns [`Fn`]s.
 ///
+/// **Thread-safety**: Listeners are called from the threads of a
+/// thread-pool, therefore every listener and [`Fn`] must be
+/// `Send + Sync + 'static`, which is checked upon adding them.
+/// Events must be `Send`, dispatching them by reference via
+/// [`dispatch_event`] additionally requires them to be `Sync`.
+///
+/// A listener registered for several event-variants may be called for
+/// them from several threads at once, e.g. by [`dispatch_events`].
+/// Every call write-locks the listener's [`RwLock`], hence calls of one
+/// listener are serialised while different listeners run in parallel.
+///
+/// Follow-up events requested via `ParallelDispatcherRequest::DispatchEvent`
+/// are collected from all threads and dispatched one after another once
+/// the requesting dispatch has been joined, in the order of the
+/// requesting listeners' registration.
+///
 /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
 /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+/// [`dispatch_event`]: #method.dispatch_event
+/// [`dispatch_events`]: #method.dispatch_events
+/// [`RwLock`]: ../type.RwLock.html
 pub struct ParallelDispatcher<T>
 where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    T: PartialEq + Eq + Hash + Clone + Send + 'static,
 {
     events: ParallelListenerMap<T>,
-    thread_pool: Option<ThreadPool>,
+    thread_pool: Option<Arc<ThreadPool>>,
+    pools: HashMap<T, Arc<ThreadPool>>,
+    timing: bool,
+    max_dispatch_depth: usize,
 }
 
 impl<T> Default for ParallelDispatcher<T>
 where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    T: PartialEq + Eq + Hash + Clone + Send + 'static,
 {
     fn default() -> ParallelDispatcher<T> {
         ParallelDispatcher {
             events: ParallelListenerMap::new(),
             thread_pool: None,
+            pools: HashMap::new(),
+            timing: false,
+            max_dispatch_depth: DEFAULT_MAX_DISPATCH_DEPTH,
         }
     }
 }
 
 impl<T> ParallelDispatcher<T>
 where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    T: PartialEq + Eq + Hash + Clone + Send + 'static,
 {
+    /// Creates a dispatcher running all dispatches on `pool`.
+    /// The pool is built once and reused by every dispatch, sharing it
+    /// between dispatchers avoids one set of threads per dispatcher.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::ParallelDispatcher;
+    /// use rayon::ThreadPoolBuilder;
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// fn main() {
+    ///     let pool = Arc::new(ThreadPoolBuilder::new().num_threads(2).build().unwrap());
+    ///
+    ///     let mut input_dispatcher: ParallelDispatcher<Event> =
+    ///         ParallelDispatcher::with_pool(Arc::clone(&pool));
+    ///     let mut network_dispatcher: ParallelDispatcher<Event> =
+    ///         ParallelDispatcher::with_pool(pool);
+    ///
+    ///     input_dispatcher.dispatch_event(&Event::EventType);
+    ///     network_dispatcher.dispatch_event(&Event::EventType);
+    /// }
+    /// ```
+    pub fn with_pool(pool: Arc<ThreadPool>) -> Self {
+        ParallelDispatcher {
+            thread_pool: Some(pool),
+            ..ParallelDispatcher::default()
+        }
+    }
+
     /// Adds a [`ParallelListener`] to listen for an `event_identifier`.
     /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
     ///
@@ -65,7 +194,7 @@ where
     /// struct ListenerStruct {}
     ///
     /// impl ParallelListener<Event> for ListenerStruct {
-    ///     fn on_event(&mut self, event: &Event) -> Option<ParallelDispatcherRequest> { None }
+    ///     fn on_event(&mut self, event: &Event) -> Option<ParallelDispatcherRequest<Event>> { None }
     /// }
     ///
     /// fn main() {
@@ -76,6 +205,37 @@ where
     /// }
     /// ```
     ///
+    /// Listeners that are not [`Send`] and [`Sync`], e.g. because they
+    /// own an [`Rc`], are rejected right here:
+    ///
+    /// ```rust,compile_fail,E0277
+    /// use hey_listen::{
+    ///    RwLock,
+    ///    sync::{ParallelListener, ParallelDispatcher, ParallelDispatcherRequest},
+    /// };
+    /// use std::{rc::Rc, sync::Arc};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct ListenerStruct {
+    ///     not_send: Rc<()>,
+    /// }
+    ///
+    /// impl ParallelListener<Event> for ListenerStruct {
+    ///     fn on_event(&mut self, event: &Event) -> Option<ParallelDispatcherRequest<Event>> { None }
+    /// }
+    ///
+    /// fn main() {
+    ///     let listener = Arc::new(RwLock::new(ListenerStruct { not_send: Rc::new(()) }));
+    ///     let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::default();
+    ///
+    ///     dispatcher.add_listener(Event::EventType, &listener);
+    /// }
+    /// ```
+    ///
     /// Declaring your own [`Hash`]- and [`PartialEq`]-trait to bypass
     /// hashing on fields:
     ///
@@ -105,11 +265,13 @@ where
     /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
     /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
     /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
-    pub fn add_listener<D: ParallelListener<T> + Send + Sync + 'static>(
-        &mut self,
-        event_identifier: T,
-        listener: &Arc<RwLock<D>>,
-    ) {
+    /// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
+    /// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
+    /// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+    pub fn add_listener<D>(&mut self, event_identifier: T, listener: &Arc<RwLock<D>>)
+    where
+        D: ParallelListener<T> + Send + Sync + 'static,
+    {
         if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
             listener_collection.traits.push(Arc::downgrade(
                 &(Arc::clone(listener)
@@ -167,7 +329,7 @@ where
     ///     let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::default();
     ///     let weak_listener_ref = Arc::downgrade(&Arc::clone(&listener));
     ///
-    ///     let closure = Box::new(move |event: &Event| -> Option<ParallelDispatcherRequest> {
+    ///     let closure = Box::new(move |event: &Event| -> Option<ParallelDispatcherRequest<Event>> {
     ///         if let Some(listener) = weak_listener_ref.upgrade() {
     ///             listener.write().test_method(&event);
     ///             None
@@ -180,15 +342,37 @@ where
     /// }
     /// ```
     ///
+    /// Just like listeners, [`Fn`]s capturing something that is not
+    /// `Send + Sync` are rejected:
+    ///
+    /// ```rust,compile_fail,E0277
+    /// use hey_listen::sync::{ParallelDispatcher, ParallelDispatcherRequest};
+    /// use std::{cell::Cell, rc::Rc};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// fn main() {
+    ///     let counter = Rc::new(Cell::new(0));
+    ///     let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::default();
+    ///
+    ///     let closure = Box::new(move |_: &Event| -> Option<ParallelDispatcherRequest<Event>> {
+    ///         counter.set(counter.get() + 1);
+    ///
+    ///         None
+    ///     });
+    ///
+    ///     dispatcher.add_fn(Event::EventType, closure);
+    /// }
+    /// ```
+    ///
     /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
     /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
     /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
     /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
-    pub fn add_fn(
-        &mut self,
-        event_identifier: T,
-        function: Box<dyn Fn(&T) -> Option<ParallelDispatcherRequest> + Send + Sync>,
-    ) {
+    pub fn add_fn(&mut self, event_identifier: T, function: ParallelFn<T>) {
         if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
             listener_collection.fns.push(function);
 
@@ -214,12 +398,291 @@ where
     pub fn num_threads(&mut self, num: usize) -> Result<(), BuildError> {
         match ThreadPoolBuilder::new().num_threads(num).build() {
             Ok(pool) => {
-                self.thread_pool = Some(pool);
+                self.thread_pool = Some(Arc::new(pool));
                 Ok(())
             }
-            Err(error) => Err(BuildError::NumThreads(error.description().to_string())),
+            Err(error) => Err(BuildError::NumThreads(error.to_string())),
+        }
+    }
+
+    /// Assigns `pool` to dispatches of `event_identifier`, keeping them
+    /// off the dispatcher's default thread-pool.
+    /// Events without an assigned pool keep using the default one.
+    ///
+    /// **Note**: Assigning a pool has no effect on registered listeners,
+    /// it only decides which threads run them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::ParallelDispatcher;
+    /// use rayon::ThreadPoolBuilder;
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Audio,
+    ///     Pathfinding,
+    /// }
+    ///
+    /// fn main() {
+    ///     let audio_pool = Arc::new(ThreadPoolBuilder::new().num_threads(1).build().unwrap());
+    ///     let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::default();
+    ///
+    ///     dispatcher.set_pool_for(Event::Audio, audio_pool);
+    /// }
+    /// ```
+    pub fn set_pool_for(&mut self, event_identifier: T, pool: Arc<ThreadPool>) {
+        self.pools.insert(event_identifier, pool);
+    }
+
+    /// Removes the pool assigned to `event_identifier` via [`set_pool_for`],
+    /// reverting its dispatches to the default thread-pool.
+    /// Returns the removed pool, if there was one.
+    ///
+    /// [`set_pool_for`]: #method.set_pool_for
+    pub fn remove_pool_for(&mut self, event_identifier: &T) -> Option<Arc<ThreadPool>> {
+        self.pools.remove(event_identifier)
+    }
+
+    /// Enables or disables measuring listeners in
+    /// [`dispatch_event_timed`], timing is disabled by default.
+    ///
+    /// [`dispatch_event_timed`]: #method.dispatch_event_timed
+    pub fn enable_timing(&mut self, enabled: bool) {
+        self.timing = enabled;
+    }
+
+    /// Sets how deep follow-up events requested via
+    /// `ParallelDispatcherRequest::DispatchEvent` may nest, defaulting to
+    /// [`DEFAULT_MAX_DISPATCH_DEPTH`].
+    /// A dispatched event has a depth of `0`, a follow-up event requested
+    /// while dispatching an event of depth `n` has a depth of `n + 1`.
+    /// Follow-up events exceeding `depth` are dropped, see
+    /// [`try_dispatch_event`].
+    ///
+    /// [`DEFAULT_MAX_DISPATCH_DEPTH`]: constant.DEFAULT_MAX_DISPATCH_DEPTH.html
+    /// [`try_dispatch_event`]: #method.try_dispatch_event
+    pub fn set_max_dispatch_depth(&mut self, depth: usize) {
+        self.max_dispatch_depth = depth;
+    }
+
+    /// Dispatches like [`dispatch_event`], but hands every listener and
+    /// [`Fn`] its own clone of `event_identifier` instead of sharing one
+    /// reference across threads.
+    /// Therefore, this method does not require `T` to be `Sync`.
+    ///
+    /// **Note**: Events are cloned once per listener before any listener
+    /// is called.
+    /// Follow-up events are dispatched the same way.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::ParallelDispatcher;
+    /// use std::{
+    ///     cell::Cell,
+    ///     hash::{Hash, Hasher},
+    /// };
+    ///
+    /// #[derive(Clone)]
+    /// struct Event {
+    ///     id: u32,
+    ///     scratch: Cell<u32>,
+    /// }
+    ///
+    /// impl Hash for Event {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.id.hash(state);
+    ///     }
+    /// }
+    ///
+    /// impl PartialEq for Event {
+    ///     fn eq(&self, other: &Event) -> bool {
+    ///         self.id == other.id
+    ///     }
+    /// }
+    ///
+    /// impl Eq for Event {}
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::default();
+    ///
+    ///     dispatcher.add_fn(Event { id: 1, scratch: Cell::new(0) }, Box::new(|event| {
+    ///         event.scratch.set(event.scratch.get() + 1);
+    ///
+    ///         None
+    ///     }));
+    ///
+    ///     dispatcher.dispatch_event_cloned(&Event { id: 1, scratch: Cell::new(0) });
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn dispatch_event_cloned(&mut self, event_identifier: &T) {
+        let follow_ups = self.dispatch_cloned_once(event_identifier);
+        let _ = self.dispatch_follow_ups(follow_ups, Self::dispatch_cloned_once);
+    }
+
+    /// Dispatches clones of `event_identifier` without its follow-up
+    /// events, which are returned instead.
+    fn dispatch_cloned_once(&mut self, event_identifier: &T) -> Vec<T> {
+        let results = match self.events.get(event_identifier) {
+            Some(listener_collection) => parallel_pass(
+                self.thread_pool_for(event_identifier),
+                units(&listener_collection.traits, || event_identifier.clone()),
+                units(&listener_collection.fns, || event_identifier.clone()),
+                |listener, event| listener.on_event(event),
+                || true,
+                false,
+            ),
+            None => return Vec::new(),
+        };
+
+        self.finish_pass(event_identifier, results)
+    }
+
+    /// Removes the listeners of `event_identifier` that requested so or
+    /// have been dropped during a parallel pass and returns the requested
+    /// follow-up events.
+    fn finish_pass(&mut self, event_identifier: &T, results: PassResults<usize, T>) -> Vec<T> {
+        let (traits_to_remove, fns_to_remove, follow_ups) = results.into_requests();
+
+        if let Some(listener_collection) = self.events.get_mut(event_identifier) {
+            remove_indices(&mut listener_collection.fns, fns_to_remove);
+            remove_indices(&mut listener_collection.traits, traits_to_remove);
+        }
+
+        follow_ups
+    }
+
+    /// Dispatches `follow_ups` and the follow-up events they request in
+    /// turn via `dispatch_once`, breadth-first, until no more are requested.
+    /// Follow-up events exceeding the maximal dispatch-depth are dropped
+    /// and reported as `DispatchError::MaxDepthExceeded`.
+    fn dispatch_follow_ups<F>(
+        &mut self,
+        follow_ups: Vec<T>,
+        mut dispatch_once: F,
+    ) -> Result<(), DispatchError>
+    where
+        F: FnMut(&mut Self, &T) -> Vec<T>,
+    {
+        let mut result = Ok(());
+        let mut queue: VecDeque<_> = follow_ups.into_iter().map(|event| (event, 1)).collect();
+
+        while let Some((event, depth)) = queue.pop_front() {
+            if depth > self.max_dispatch_depth {
+                result = Err(DispatchError::MaxDepthExceeded);
+
+                continue;
+            }
+
+            queue.extend(
+                dispatch_once(self, &event)
+                    .into_iter()
+                    .map(|follow_up| (follow_up, depth + 1)),
+            );
+        }
+
+        result
+    }
+
+    /// Returns the thread-pool dispatches of `event_identifier` shall run on,
+    /// `None` means no pool has been built and `Rayon`'s global pool is used.
+    fn thread_pool_for(&self, event_identifier: &T) -> Option<&ThreadPool> {
+        match self.pools.get(event_identifier) {
+            Some(pool) => Some(&**pool),
+            None => self.thread_pool.as_deref(),
         }
     }
+}
+
+impl<T> ParallelDispatcher<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Converts the dispatcher into a [`Dispatcher`] calling the same
+    /// listeners and [`Fn`]s one after another on the dispatching thread,
+    /// e.g. to step through a parallel setup with a debugger.
+    ///
+    /// Listeners are converted as they are, they do not need to implement
+    /// [`Listener`] as well: Every [`ParallelListener`] is still held
+    /// weakly and stops listening once it has been dropped.
+    /// All registrations become [`Fn`]s of the [`Dispatcher`], listeners
+    /// first, each in registration-order.
+    ///
+    /// **Note**: Listeners are called from a single thread, but keep
+    /// returning [`ParallelDispatcherRequest`]s. Follow-up events requested
+    /// via `ParallelDispatcherRequest::DispatchEvent` are sent to the
+    /// [`Dispatcher`]'s own channel, they are dispatched by [`poll`].
+    /// Thread-pools, timing and the maximal dispatch-depth are not kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{ParallelDispatcher, ParallelDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Ping,
+    ///     Pong,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut parallel: ParallelDispatcher<Event> = ParallelDispatcher::default();
+    ///     parallel.add_fn(Event::Ping, Box::new(|_| {
+    ///         Some(ParallelDispatcherRequest::DispatchEvent(Event::Pong))
+    ///     }));
+    ///     parallel.add_fn(Event::Pong, Box::new(|_| None));
+    ///
+    ///     let mut sequential = parallel.into_sequential();
+    ///
+    ///     assert_eq!(sequential.dispatch_event_with_result(&Event::Ping).invoked, 1);
+    ///     assert_eq!(sequential.poll(), 1);
+    /// }
+    /// ```
+    ///
+    /// [`Dispatcher`]: struct.Dispatcher.html
+    /// [`Listener`]: trait.Listener.html
+    /// [`ParallelListener`]: trait.ParallelListener.html
+    /// [`ParallelDispatcherRequest`]: enum.ParallelDispatcherRequest.html
+    /// [`poll`]: struct.Dispatcher.html#method.poll
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn into_sequential(self) -> Dispatcher<T> {
+        let mut dispatcher = Dispatcher::default();
+        let sender = dispatcher.sender();
+
+        for (event_identifier, listener_collection) in self.events {
+            for listener in listener_collection.traits {
+                let sender = sender.clone();
+
+                dispatcher.add_fn(
+                    event_identifier.clone(),
+                    Box::new(move |event| match listener.upgrade() {
+                        Some(listener) => {
+                            let request = listener.write().on_event(event);
+
+                            sequential_request(request, &sender)
+                        }
+                        None => Some(SyncDispatcherRequest::StopListening),
+                    }),
+                );
+            }
+
+            for function in listener_collection.fns {
+                let sender = sender.clone();
+
+                dispatcher.add_fn(
+                    event_identifier.clone(),
+                    Box::new(move |event| sequential_request(function(event), &sender)),
+                );
+            }
+        }
+
+        dispatcher
+    }
 
     /// All [`ParallelListener`]s listening to a passed `event_identifier`
     /// will be called via their implemented [`on_event`]-method.
@@ -227,95 +690,634 @@ where
     /// with `ParallelDispatcherRequest::StopListening` will cause them
     /// to be removed from the event-dispatcher.
     ///
+    /// `ParallelDispatcherRequest::DispatchEvent` dispatches the requested
+    /// event after all listeners have been joined.
+    ///
+    /// **Note**: Listeners run on the pool assigned to `event_identifier`
+    /// via [`set_pool_for`], otherwise on the default thread-pool.
+    ///
     /// [`ParallelListener`]: trait.ParallelListener.html
     /// [`on_event`]: trait.ParallelListener.html#tymethod.on_event
     /// [`ParallelDispatcherRequest`]: enum.ParallelDispatcherRequest.html
+    /// [`set_pool_for`]: #method.set_pool_for
     /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
     /// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
     pub fn dispatch_event(&mut self, event_identifier: &T) {
-        if let Some(listener_collection) = self.events.get_mut(event_identifier) {
-            let fns_to_remove = RwLock::new(Vec::new());
-            let traits_to_remove = RwLock::new(Vec::new());
-
-            if let Some(ref thread_pool) = self.thread_pool {
-                thread_pool.install(|| {
-                    ParallelDispatcher::joined_parallel_dispatch(
-                        listener_collection,
-                        event_identifier,
-                        &fns_to_remove,
-                        &traits_to_remove,
-                    )
-                });
-            } else {
-                ParallelDispatcher::joined_parallel_dispatch(
-                    listener_collection,
-                    event_identifier,
-                    &fns_to_remove,
-                    &traits_to_remove,
-                );
+        let _ = self.try_dispatch_event(event_identifier);
+    }
+
+    /// Dispatches like [`dispatch_event`], but returns
+    /// [`DispatchError::MaxDepthExceeded`] if follow-up events have been
+    /// dropped for exceeding the maximal dispatch-depth, e.g. because
+    /// listeners keep requesting each other's events.
+    /// All other follow-up events have been dispatched nonetheless.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{DispatchError, ParallelDispatcher, ParallelDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Ping,
+    ///     Pong,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::default();
+    ///     dispatcher.set_max_dispatch_depth(8);
+    ///
+    ///     dispatcher.add_fn(Event::Ping, Box::new(|_| {
+    ///         Some(ParallelDispatcherRequest::DispatchEvent(Event::Pong))
+    ///     }));
+    ///     dispatcher.add_fn(Event::Pong, Box::new(|_| {
+    ///         Some(ParallelDispatcherRequest::DispatchEvent(Event::Ping))
+    ///     }));
+    ///
+    ///     assert_eq!(
+    ///         dispatcher.try_dispatch_event(&Event::Ping),
+    ///         Err(DispatchError::MaxDepthExceeded)
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`DispatchError::MaxDepthExceeded`]: enum.DispatchError.html#variant.MaxDepthExceeded
+    pub fn try_dispatch_event(&mut self, event_identifier: &T) -> Result<(), DispatchError> {
+        let follow_ups = self.dispatch_once(event_identifier);
+
+        self.dispatch_follow_ups(follow_ups, Self::dispatch_once)
+    }
+
+    /// Dispatches `event_identifier` without its follow-up events,
+    /// which are returned instead.
+    fn dispatch_once(&mut self, event_identifier: &T) -> Vec<T> {
+        let results = match self.events.get(event_identifier) {
+            Some(listener_collection) => parallel_pass(
+                self.thread_pool_for(event_identifier),
+                units(&listener_collection.traits, || event_identifier),
+                units(&listener_collection.fns, || event_identifier),
+                |listener, event| listener.on_event(event),
+                || true,
+                false,
+            ),
+            None => return Vec::new(),
+        };
+
+        self.finish_pass(event_identifier, results)
+    }
+
+    /// Dispatches like [`dispatch_event`] and, if enabled via
+    /// [`enable_timing`], returns how long each listener took.
+    ///
+    /// Each listener measures itself and the measurements are merged
+    /// after all listeners have been joined, no lock is shared
+    /// for measuring.
+    /// With timing disabled no time is taken and `None` is returned.
+    /// Follow-up events are dispatched afterwards and not measured.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::ParallelDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::default();
+    ///     dispatcher.add_fn(Event::EventType, Box::new(|_| None));
+    ///     dispatcher.enable_timing(true);
+    ///
+    ///     let report = dispatcher.dispatch_event_timed(&Event::EventType).unwrap();
+    ///
+    ///     assert_eq!(report.fns.len(), 1);
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`enable_timing`]: #method.enable_timing
+    pub fn dispatch_event_timed(&mut self, event_identifier: &T) -> Option<TimingReport> {
+        if !self.timing {
+            self.dispatch_event(event_identifier);
+
+            return None;
+        }
+
+        let start = Instant::now();
+
+        let results = match self.events.get(event_identifier) {
+            Some(listener_collection) => parallel_pass(
+                self.thread_pool_for(event_identifier),
+                units(&listener_collection.traits, || event_identifier),
+                units(&listener_collection.fns, || event_identifier),
+                |listener, event| listener.on_event(event),
+                || true,
+                true,
+            ),
+            None => PassResults::default(),
+        };
+
+        let report = TimingReport {
+            listeners: durations(&results.traits),
+            fns: durations(&results.fns),
+            total: start.elapsed(),
+        };
+
+        let follow_ups = self.finish_pass(event_identifier, results);
+        let _ = self.dispatch_follow_ups(follow_ups, Self::dispatch_once);
+
+        Some(report)
+    }
+
+    /// Dispatches like [`dispatch_event`], but stops calling further
+    /// listeners and [`Fn`]s once `budget` has been used up, e.g. to bound
+    /// the time spent per frame.
+    /// Listeners that have already been called are not interrupted, hence
+    /// the dispatch may take longer than `budget` by the duration of the
+    /// listeners running when it is used up.
+    ///
+    /// Returns how many listeners have been called and which have been
+    /// skipped. Follow-up events requested by the called listeners are
+    /// dispatched afterwards, without budget, the outcome tells whether
+    /// some of them have been dropped for exceeding the maximal
+    /// dispatch-depth.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::ParallelDispatcher;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: ParallelDispatcher<Event> = ParallelDispatcher::default();
+    ///     dispatcher.add_fn(Event::EventType, Box::new(|_| None));
+    ///
+    ///     let outcome = dispatcher.dispatch_event_timeout(&Event::EventType, Duration::from_secs(1));
+    ///     assert_eq!(outcome.completed, 1);
+    ///
+    ///     let outcome = dispatcher.dispatch_event_timeout(&Event::EventType, Duration::from_secs(0));
+    ///     assert_eq!(outcome.skipped_fns, [0]);
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn dispatch_event_timeout(
+        &mut self,
+        event_identifier: &T,
+        budget: Duration,
+    ) -> DispatchOutcome {
+        let deadline = Instant::now() + budget;
+
+        let results = match self.events.get(event_identifier) {
+            Some(listener_collection) => parallel_pass(
+                self.thread_pool_for(event_identifier),
+                units(&listener_collection.traits, || event_identifier),
+                units(&listener_collection.fns, || event_identifier),
+                |listener, event| listener.on_event(event),
+                || Instant::now() < deadline,
+                false,
+            ),
+            None => PassResults::default(),
+        };
+
+        let mut outcome = DispatchOutcome {
+            completed: results.completed(),
+            skipped_listeners: skipped_positions(&results.traits),
+            skipped_fns: skipped_positions(&results.fns),
+            max_depth_exceeded: false,
+        };
+
+        let follow_ups = self.finish_pass(event_identifier, results);
+        outcome.max_depth_exceeded = self
+            .dispatch_follow_ups(follow_ups, Self::dispatch_once)
+            .is_err();
+
+        outcome
+    }
+
+    /// Dispatches all `events` in one single parallel pass, instead of
+    /// forking and joining once per event.
+    /// Every [`ParallelListener`] receives all events of the event-type it
+    /// is registered for via one call of [`on_events`], in batch-order.
+    /// Every pair of an event and one of its [`Fn`]s is a separate unit of
+    /// work, all units are joined once.
+    ///
+    /// **Note**: Only the order within one call of [`on_events`] is
+    /// guaranteed, distinct listeners and [`Fn`]s run concurrently and a
+    /// listener registered for multiple event-types receives them via
+    /// separate calls in any order.
+    ///
+    /// **Note**: `ParallelDispatcherRequest::StopListening` is applied
+    /// after the whole batch has been dispatched, a listener issuing it
+    /// will still receive the remaining events of the batch.
+    /// Follow-up events are dispatched one by one after the batch.
+    ///
+    /// **Note**: Events run on their pool assigned via [`set_pool_for`],
+    /// otherwise on the default thread-pool. The batch is split into one
+    /// joined pass per pool, running one pass after another, hence events
+    /// of distinct pools never share threads.
+    ///
+    /// [`ParallelListener`]: trait.ParallelListener.html
+    /// [`on_events`]: trait.ParallelListener.html#method.on_events
+    /// [`set_pool_for`]: #method.set_pool_for
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn dispatch_events(&mut self, events: &[T]) {
+        let mut results = PassResults::default();
+
+        {
+            let mut passes: Vec<PoolPass<'_, '_, T>> = Vec::new();
+            let mut events_by_type: HashMap<&T, Vec<T>> = HashMap::new();
+            let mut fn_order = 0;
+
+            for event_identifier in events {
+                if let Some(listener_collection) = self.events.get(event_identifier) {
+                    events_by_type
+                        .entry(event_identifier)
+                        .or_default()
+                        .push(event_identifier.clone());
+
+                    let pass = pass_for(&mut passes, self.thread_pool_for(event_identifier));
+
+                    for (index, callback) in listener_collection.fns.iter().enumerate() {
+                        pass.fn_work.push(Unit {
+                            order: fn_order,
+                            key: (event_identifier, index),
+                            listener: callback,
+                            payload: event_identifier,
+                        });
+                        fn_order += 1;
+                    }
+                }
             }
 
-            fns_to_remove.write().iter().for_each(|index| {
-                listener_collection.fns.swap_remove(*index);
-            });
+            let mut trait_order = 0;
 
-            traits_to_remove.write().iter().for_each(|index| {
-                listener_collection.traits.swap_remove(*index);
+            for (event_identifier, events_of_type) in &events_by_type {
+                if let Some(listener_collection) = self.events.get(event_identifier) {
+                    let pass = pass_for(&mut passes, self.thread_pool_for(event_identifier));
+
+                    for (index, listener) in listener_collection.traits.iter().enumerate() {
+                        pass.trait_work.push(Unit {
+                            order: trait_order,
+                            key: (*event_identifier, index),
+                            listener,
+                            payload: events_of_type,
+                        });
+                        trait_order += 1;
+                    }
+                }
+            }
+
+            for pass in passes {
+                results.append(parallel_pass(
+                    pass.thread_pool,
+                    pass.trait_work,
+                    pass.fn_work,
+                    |listener, events_of_type| listener.on_events(events_of_type),
+                    || true,
+                    false,
+                ));
+            }
+        }
+
+        let (traits_to_remove, fns_to_remove, follow_ups) = results.into_requests();
+
+        for (event_identifier, indices) in group_by_event(fns_to_remove) {
+            if let Some(listener_collection) = self.events.get_mut(event_identifier) {
+                remove_indices(&mut listener_collection.fns, indices);
+            }
+        }
+
+        for (event_identifier, indices) in group_by_event(traits_to_remove) {
+            if let Some(listener_collection) = self.events.get_mut(event_identifier) {
+                remove_indices(&mut listener_collection.traits, indices);
+            }
+        }
+
+        let _ = self.dispatch_follow_ups(follow_ups, Self::dispatch_once);
+    }
+}
+
+/// The units of work of a batch running on one thread-pool.
+struct PoolPass<'e, 'a, T> {
+    thread_pool: Option<&'a ThreadPool>,
+    trait_work: Vec<BatchUnit<'e, T, &'a WeakParallelListener<T>, &'a Vec<T>>>,
+    fn_work: Vec<BatchUnit<'e, T, &'a ParallelFn<T>, &'e T>>,
+}
+
+/// A unit of work of a batch, keyed by its event-variant and index.
+type BatchUnit<'e, T, L, P> = Unit<(&'e T, usize), L, P>;
+
+/// Returns the pass of `passes` running on `thread_pool`, appending it if
+/// missing. Pools are told apart by identity.
+fn pass_for<'e, 'a, 'p, T>(
+    passes: &'p mut Vec<PoolPass<'e, 'a, T>>,
+    thread_pool: Option<&'a ThreadPool>,
+) -> &'p mut PoolPass<'e, 'a, T> {
+    let position = passes
+        .iter()
+        .position(|pass| match (pass.thread_pool, thread_pool) {
+            (Some(a), Some(b)) => ptr::eq(a, b),
+            (None, None) => true,
+            _ => false,
+        });
+
+    match position {
+        Some(position) => &mut passes[position],
+        None => {
+            passes.push(PoolPass {
+                thread_pool,
+                trait_work: Vec::new(),
+                fn_work: Vec::new(),
             });
+
+            passes.last_mut().expect("a pass has just been pushed")
+        }
+    }
+}
+
+type WeakParallelListener<T> = Weak<RwLock<dyn ParallelListener<T> + Send + Sync + 'static>>;
+
+/// A unit of work of a parallel pass: A listener or [`Fn`] located via
+/// `key`, called with `payload` as the `order`-th unit of work.
+///
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+struct Unit<K, L, P> {
+    order: usize,
+    key: K,
+    listener: L,
+    payload: P,
+}
+
+/// What became of a unit of work during a parallel pass.
+enum Ran<T> {
+    /// Called, returning its request, and how long the call took if timed.
+    Called(Option<ParallelDispatcherRequest<T>>, Option<Duration>),
+    /// Not called, as its listener has been dropped.
+    Dropped,
+    /// Not called, as the pass has not admitted it.
+    Skipped,
+}
+
+impl<T> Ran<T> {
+    /// Returns whether the listener shall be removed.
+    fn removes_listener(&self) -> bool {
+        matches!(
+            self,
+            Ran::Called(Some(ParallelDispatcherRequest::StopListening), _) | Ran::Dropped
+        )
+    }
+}
+
+/// What became of all units of work of parallel passes, each paired with
+/// its order and key, in the order the units have been scheduled in.
+struct PassResults<K, T> {
+    traits: Vec<(usize, K, Ran<T>)>,
+    fns: Vec<(usize, K, Ran<T>)>,
+}
+
+impl<K, T> Default for PassResults<K, T> {
+    fn default() -> Self {
+        PassResults {
+            traits: Vec::new(),
+            fns: Vec::new(),
         }
     }
+}
 
-    /// Encapsulates `Rayon`'s joined `par_iter`-function on
-    /// `Fn`s and `ParallelListener`s.
+impl<K, T> PassResults<K, T> {
+    /// Moves the results of `other` after these results.
+    fn append(&mut self, mut other: PassResults<K, T>) {
+        self.traits.append(&mut other.traits);
+        self.fns.append(&mut other.fns);
+    }
+
+    /// How many listeners and [`Fn`]s have been called.
     ///
-    /// This enables it to be used captured inside a `ThreadPool`'s
-    /// `install`-method but also bare as is - in case no
-    /// `ThreadPool` is avail.
-    fn joined_parallel_dispatch(
-        listener_collection: &ParallelFnsAndTraits<T>,
-        event_identifier: &T,
-        fns_to_remove: &RwLock<Vec<usize>>,
-        traits_to_remove: &RwLock<Vec<usize>>,
-    ) {
-        join(
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    fn completed(&self) -> usize {
+        self.traits
+            .iter()
+            .chain(&self.fns)
+            .filter(|(_, _, ran)| matches!(ran, Ran::Called(..)))
+            .count()
+    }
+
+    /// Returns the keys of the trait-objects and [`Fn`]s to remove and the
+    /// follow-up events, the trait-objects' ones first, each in the order
+    /// of the requesting units of work.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    fn into_requests(self) -> (Vec<K>, Vec<K>, Vec<T>) {
+        let (traits_to_remove, mut follow_ups) = split_requests(self.traits);
+        let (fns_to_remove, fn_follow_ups) = split_requests(self.fns);
+        follow_ups.extend(fn_follow_ups);
+
+        (traits_to_remove, fns_to_remove, follow_ups)
+    }
+}
+
+/// Splits `results` into the keys of the listeners to remove and the
+/// follow-up events, ordered by their requesting units of work.
+fn split_requests<K, T>(results: Vec<(usize, K, Ran<T>)>) -> (Vec<K>, Vec<T>) {
+    let mut to_remove = Vec::new();
+    let mut follow_ups = Vec::new();
+
+    for (order, key, ran) in results {
+        if ran.removes_listener() {
+            to_remove.push(key);
+        } else if let Ran::Called(Some(ParallelDispatcherRequest::DispatchEvent(event)), _) = ran {
+            follow_ups.push((order, event));
+        }
+    }
+
+    follow_ups.sort_by_key(|(order, _)| *order);
+
+    (
+        to_remove,
+        follow_ups.into_iter().map(|(_, event)| event).collect(),
+    )
+}
+
+/// Returns one unit of work per element of `listeners`, keyed and ordered
+/// by its index and called with a payload created via `payload`.
+fn units<L, P>(listeners: &[L], mut payload: impl FnMut() -> P) -> Vec<Unit<usize, &L, P>> {
+    listeners
+        .iter()
+        .enumerate()
+        .map(|(index, listener)| Unit {
+            order: index,
+            key: index,
+            listener,
+            payload: payload(),
+        })
+        .collect()
+}
+
+/// Calls all units of `trait_work` and `fn_work` in one joined parallel
+/// pass on `thread_pool`, or on the current pool if `None`.
+///
+/// Every unit is only called if `admit` returns `true` right before,
+/// trait-objects via `call_listener` and [`Fn`]s with their payload.
+/// If `timed`, every unit measures its call itself, no lock is shared.
+///
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+fn parallel_pass<'a, T, K, P, E, C, A>(
+    thread_pool: Option<&ThreadPool>,
+    trait_work: Vec<Unit<K, &'a WeakParallelListener<T>, P>>,
+    fn_work: Vec<Unit<K, &'a ParallelFn<T>, E>>,
+    call_listener: C,
+    admit: A,
+    timed: bool,
+) -> PassResults<K, T>
+where
+    T: Send + 'static,
+    K: Send,
+    P: Send,
+    E: Borrow<T> + Send,
+    C: Fn(&mut (dyn ParallelListener<T> + Send + Sync), &P) -> Option<ParallelDispatcherRequest<T>>
+        + Sync,
+    A: Fn() -> bool + Sync,
+{
+    let call = |unit_call: &dyn Fn() -> Option<ParallelDispatcherRequest<T>>| {
+        if timed {
+            let start = Instant::now();
+            let request = unit_call();
+
+            Ran::Called(request, Some(start.elapsed()))
+        } else {
+            Ran::Called(unit_call(), None)
+        }
+    };
+
+    let dispatch = || {
+        let (traits, fns) = join(
             || {
-                listener_collection
-                    .traits
-                    .par_iter()
-                    .enumerate()
-                    .for_each(|(index, listener)| {
-                        if let Some(listener_arc) = listener.upgrade() {
-                            let mut listener = listener_arc.write();
-
-                            if let Some(instruction) = listener.on_event(event_identifier) {
-                                match instruction {
-                                    ParallelDispatcherRequest::StopListening => {
-                                        traits_to_remove.write().push(index)
-                                    }
-                                }
-                            }
+                trait_work
+                    .into_par_iter()
+                    .map(|unit| {
+                        let ran = if !admit() {
+                            Ran::Skipped
+                        } else if let Some(listener_arc) = unit.listener.upgrade() {
+                            call(&|| call_listener(&mut *listener_arc.write(), &unit.payload))
                         } else {
-                            traits_to_remove.write().push(index)
-                        }
+                            Ran::Dropped
+                        };
+
+                        (unit.order, unit.key, ran)
                     })
+                    .collect()
             },
             || {
-                listener_collection
-                    .fns
-                    .par_iter()
-                    .enumerate()
-                    .for_each(|(index, callback)| {
-                        if let Some(instruction) = callback(event_identifier) {
-                            match instruction {
-                                ParallelDispatcherRequest::StopListening => {
-                                    fns_to_remove.write().push(index);
-                                }
-                            }
+                fn_work
+                    .into_par_iter()
+                    .map(|unit| {
+                        let ran = if admit() {
+                            call(&|| (unit.listener)(unit.payload.borrow()))
                         } else {
-                            ()
-                        }
-                    });
+                            Ran::Skipped
+                        };
+
+                        (unit.order, unit.key, ran)
+                    })
+                    .collect()
             },
         );
+
+        PassResults { traits, fns }
+    };
+
+    match thread_pool {
+        Some(thread_pool) => thread_pool.install(dispatch),
+        None => dispatch(),
+    }
+}
+
+/// Returns the measured durations of the called units of `results`,
+/// paired with their keys.
+fn durations<T>(results: &[(usize, usize, Ran<T>)]) -> Vec<(usize, Duration)> {
+    results
+        .iter()
+        .filter_map(|(_, index, ran)| match ran {
+            Ran::Called(_, Some(duration)) => Some((*index, *duration)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the indices of the skipped units of `results`, as they are
+/// once the listeners to remove have been removed via `remove_indices`.
+fn skipped_positions<T>(results: &[(usize, usize, Ran<T>)]) -> Vec<usize> {
+    let mut removed = 0;
+    let mut skipped = Vec::new();
+
+    for (_, index, ran) in results {
+        if let Ran::Skipped = ran {
+            skipped.push(index - removed);
+        } else if ran.removes_listener() {
+            removed += 1;
+        }
     }
+
+    skipped
+}
+
+/// Translates `request` of a [`ParallelListener`] for the [`Dispatcher`],
+/// sending a requested follow-up event via `sender`.
+///
+/// [`ParallelListener`]: trait.ParallelListener.html
+/// [`Dispatcher`]: struct.Dispatcher.html
+fn sequential_request<T>(
+    request: Option<ParallelDispatcherRequest<T>>,
+    sender: &EventSender<T>,
+) -> Option<SyncDispatcherRequest> {
+    match request {
+        Some(ParallelDispatcherRequest::StopListening) => {
+            Some(SyncDispatcherRequest::StopListening)
+        }
+        Some(ParallelDispatcherRequest::DispatchEvent(event)) => {
+            let _ = sender.send(event);
+
+            None
+        }
+        None => None,
+    }
+}
+
+/// Groups `(event, index)`-pairs by their event.
+fn group_by_event<T>(pairs: Vec<(&T, usize)>) -> HashMap<&T, Vec<usize>>
+where
+    T: Eq + Hash,
+{
+    let mut grouped: HashMap<&T, Vec<usize>> = HashMap::new();
+
+    for (event_identifier, index) in pairs {
+        grouped.entry(event_identifier).or_default().push(index);
+    }
+
+    grouped
+}
+
+/// Removes all elements at `indices` from `vec`, keeping the order of the
+/// remaining elements.
+/// Duplicated indices are removed only once.
+fn remove_indices<L>(vec: &mut Vec<L>, mut indices: Vec<usize>) {
+    indices.sort_unstable();
+
+    let mut index = 0;
+    vec.retain(|_| {
+        let keep = indices.binary_search(&index).is_err();
+        index += 1;
+
+        keep
+    });
 }
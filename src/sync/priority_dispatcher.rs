@@ -1,15 +1,118 @@
 use super::{
-    execute_sync_dispatcher_requests, ExecuteRequestsResult, FnsAndTraits, Listener, RwLock,
-    SyncDispatcherRequest,
+    cancelable::Cancelable, dispatch_by_priority, removes_listener, DispatcherSnapshot,
+    ExecuteRequestsResult, FnsAndTraits, Listener, ListenerHandle, Registered, RwLock,
+    SyncDispatcherRequest, SyncFn, DEFAULT_MAX_DISPATCH_DEPTH,
 };
+use parking_lot::Mutex;
 use std::{
     collections::{BTreeMap, HashMap},
+    convert::TryFrom,
     hash::Hash,
-    sync::{Arc, Weak},
+    mem,
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
 };
 
 type PriorityListenerMap<P, T> = HashMap<T, BTreeMap<P, FnsAndTraits<T>>>;
 
+/// Locates a registration inside one priority-level.
+#[derive(Clone, Copy)]
+enum Position {
+    Trait(usize),
+    Fn(usize),
+}
+
+/// Priorities that can be moved by a number of steps,
+/// enabling [`PriorityDispatcher::bump_priority`].
+///
+/// Implemented for all primitive integers by adding the steps.
+///
+/// [`PriorityDispatcher::bump_priority`]: struct.PriorityDispatcher.html#method.bump_priority
+pub trait Step: Sized {
+    /// Returns the priority `delta` steps away from `self`,
+    /// or `None` if that priority cannot be represented.
+    fn offset(&self, delta: i32) -> Option<Self>;
+}
+
+macro_rules! impl_step {
+    ($($integer:ty),*) => {
+        $(
+            impl Step for $integer {
+                fn offset(&self, delta: i32) -> Option<Self> {
+                    let value = i128::try_from(*self).ok()?.checked_add(i128::from(delta))?;
+
+                    Self::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_step!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// An `enum` returning a request from a [`RedirectingFn`] to its
+/// [`PriorityDispatcher`], see [`PriorityDispatcher::add_redirecting_fn`].
+///
+/// All variants but `Redirect` are honoured like the
+/// [`SyncDispatcherRequest`] of the same name.
+///
+/// `Redirect` replaces the dispatched event by `event`: The current
+/// priority-level is dispatched to the end, then the dispatch of the
+/// original event ends and `event` is dispatched instead.
+/// With `inherit_priority`, `event` is dispatched from the current
+/// priority-level onwards, hence listeners of earlier levels do not
+/// process the refined event a second time, otherwise from the
+/// first level.
+///
+/// [`RedirectingFn`]: type.RedirectingFn.html
+/// [`PriorityDispatcher`]: struct.PriorityDispatcher.html
+/// [`PriorityDispatcher::add_redirecting_fn`]: struct.PriorityDispatcher.html#method.add_redirecting_fn
+/// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PriorityDispatcherRequest<T> {
+    StopListening,
+    StopPropagation,
+    StopListeningAndPropagation,
+    Handled,
+    Redirect { event: T, inherit_priority: bool },
+}
+
+impl<T> From<SyncDispatcherRequest> for PriorityDispatcherRequest<T> {
+    fn from(request: SyncDispatcherRequest) -> Self {
+        match request {
+            SyncDispatcherRequest::StopListening => PriorityDispatcherRequest::StopListening,
+            SyncDispatcherRequest::StopPropagation => PriorityDispatcherRequest::StopPropagation,
+            SyncDispatcherRequest::StopListeningAndPropagation => {
+                PriorityDispatcherRequest::StopListeningAndPropagation
+            }
+            SyncDispatcherRequest::Handled => PriorityDispatcherRequest::Handled,
+        }
+    }
+}
+
+/// A [`Fn`] registered via [`PriorityDispatcher::add_redirecting_fn`],
+/// able to redirect the dispatched event.
+///
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+/// [`PriorityDispatcher::add_redirecting_fn`]: struct.PriorityDispatcher.html#method.add_redirecting_fn
+pub type RedirectingFn<T> = Box<dyn Fn(&T) -> Option<PriorityDispatcherRequest<T>> + Send + Sync>;
+
+/// Summarises one dispatch of [`PriorityDispatcher::dispatch_event_report`],
+/// including which registrations removed themselves.
+///
+/// [`PriorityDispatcher::dispatch_event_report`]: struct.PriorityDispatcher.html#method.dispatch_event_report
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DispatchReport {
+    /// How many listeners and closures have been called.
+    pub invoked: usize,
+    /// The handles of all registrations that requested to stop listening,
+    /// in calling-order.
+    pub removed: Vec<ListenerHandle>,
+    /// Whether a listener stopped propagation before
+    /// all listeners have been called.
+    pub stopped: bool,
+}
+
 /// In charge of prioritised sync dispatching to all listeners.
 /// Owns a map event-variants and [`Weak`]-references to their
 /// listeners and/or owns [`Fn`]s.
@@ -30,6 +133,9 @@ where
     T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
 {
     events: PriorityListenerMap<P, T>,
+    /// Events redirected to by the priority-level being dispatched,
+    /// each along with whether it inherits the level.
+    redirects: Arc<Mutex<Vec<(T, bool)>>>,
 }
 
 impl<P, T> Default for PriorityDispatcher<P, T>
@@ -40,6 +146,7 @@ where
     fn default() -> PriorityDispatcher<P, T> {
         PriorityDispatcher {
             events: PriorityListenerMap::new(),
+            redirects: Arc::new(Mutex::new(Vec::new())),
         }
     }
 }
@@ -52,6 +159,7 @@ where
     /// Adds a [`Listener`] to listen for an `event_identifier`, considering
     /// a given `priority` implementing the [`Ord`]-trait, to sort dispatch-order.
     /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
     ///
     /// **Note**: If your `Enum` owns fields you need to consider implementing
     /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields,
@@ -118,44 +226,33 @@ where
     /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
     /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
     /// [`Ord`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
     pub fn add_listener<D: Listener<T> + Send + Sync + 'static>(
         &mut self,
         event_identifier: T,
         listener: &Arc<RwLock<D>>,
         priority: P,
-    ) {
-        if let Some(prioritised_listener_collection) = self.events.get_mut(&event_identifier) {
-            if let Some(priority_level_collection) =
-                prioritised_listener_collection.get_mut(&priority)
-            {
-                priority_level_collection.traits.push(Arc::downgrade(
-                    &(Arc::clone(listener) as Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>),
-                ));
+    ) -> ListenerHandle {
+        let registered = Registered::subscribe(
+            &(Arc::clone(listener) as Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>),
+        );
+        let handle = registered.handle;
 
-                return;
-            }
-            prioritised_listener_collection.insert(
-                priority.clone(),
-                FnsAndTraits::new_with_traits(vec![Arc::downgrade(
-                    &(Arc::clone(listener) as Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>),
-                )]),
-            );
-            return;
-        }
+        self.events
+            .entry(event_identifier)
+            .or_default()
+            .entry(priority)
+            .or_insert_with(FnsAndTraits::new)
+            .traits
+            .push(registered);
 
-        let mut b_tree_map = BTreeMap::new();
-        b_tree_map.insert(
-            priority,
-            FnsAndTraits::new_with_traits(vec![Arc::downgrade(
-                &(Arc::clone(listener) as Arc<RwLock<dyn Listener<T> + Send + Sync + 'static>>),
-            )]),
-        );
-        self.events.insert(event_identifier, b_tree_map);
+        handle
     }
 
     /// Adds an [`Fn`] to listen for an `event_identifier`, considering
     /// a given `priority` implementing the [`Ord`]-trait in order to sort dispatch-order.
     /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
     ///
     /// # Examples
     ///
@@ -206,28 +303,342 @@ where
     /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
     /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
     /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
     pub fn add_fn(
         &mut self,
         event_identifier: T,
-        function: Box<dyn Fn(&T) -> Option<SyncDispatcherRequest> + Send + Sync>,
+        function: SyncFn<T>,
         priority: P,
-    ) {
-        if let Some(prioritised_listener_collection) = self.events.get_mut(&event_identifier) {
-            if let Some(priority_level_collection) =
-                prioritised_listener_collection.get_mut(&priority)
-            {
-                priority_level_collection.fns.push(function);
+    ) -> ListenerHandle {
+        let registered = Registered::new(Arc::from(function));
+        let handle = registered.handle;
+
+        self.events
+            .entry(event_identifier)
+            .or_default()
+            .entry(priority)
+            .or_insert_with(FnsAndTraits::new)
+            .fns
+            .push(registered);
+
+        handle
+    }
+
+    /// Adds a [`RedirectingFn`] to listen for an `event_identifier` like
+    /// [`add_fn`], which may return a [`PriorityDispatcherRequest::Redirect`]
+    /// to refine the dispatched event, e.g. to turn a raw input into the
+    /// command it stands for.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// Redirects are honoured by [`dispatch_event`] and
+    /// [`dispatch_from_tier`], every other dispatch-method ignores them.
+    /// Redirects of redirected events are followed up to
+    /// [`DEFAULT_MAX_DISPATCH_DEPTH`] times, deeper ones are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{PriorityDispatcher, PriorityDispatcherRequest};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     KeyPress(char),
+    ///     Save,
+    /// }
+    ///
+    /// fn main() {
+    ///     let saved = Arc::new(Mutex::new(0));
+    ///     let saved_by_fn = Arc::clone(&saved);
+    ///     let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    ///     dispatcher.add_redirecting_fn(
+    ///         Event::KeyPress('s'),
+    ///         Box::new(|_| {
+    ///             Some(PriorityDispatcherRequest::Redirect {
+    ///                 event: Event::Save,
+    ///                 inherit_priority: true,
+    ///             })
+    ///         }),
+    ///         1,
+    ///     );
+    ///     dispatcher.add_fn(
+    ///         Event::Save,
+    ///         Box::new(move |_| {
+    ///             *saved_by_fn.lock().unwrap() += 1;
+    ///
+    ///             None
+    ///         }),
+    ///         2,
+    ///     );
+    ///
+    ///     dispatcher.dispatch_event(&Event::KeyPress('s'));
+    ///
+    ///     assert_eq!(*saved.lock().unwrap(), 1);
+    /// }
+    /// ```
+    ///
+    /// [`RedirectingFn`]: type.RedirectingFn.html
+    /// [`add_fn`]: #method.add_fn
+    /// [`PriorityDispatcherRequest::Redirect`]: enum.PriorityDispatcherRequest.html#variant.Redirect
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`dispatch_from_tier`]: #method.dispatch_from_tier
+    /// [`DEFAULT_MAX_DISPATCH_DEPTH`]: constant.DEFAULT_MAX_DISPATCH_DEPTH.html
+    pub fn add_redirecting_fn(
+        &mut self,
+        event_identifier: T,
+        function: RedirectingFn<T>,
+        priority: P,
+    ) -> ListenerHandle {
+        let redirects = Arc::clone(&self.redirects);
+
+        self.add_fn(
+            event_identifier,
+            Box::new(move |event_identifier| match function(event_identifier)? {
+                PriorityDispatcherRequest::StopListening => {
+                    Some(SyncDispatcherRequest::StopListening)
+                }
+                PriorityDispatcherRequest::StopPropagation => {
+                    Some(SyncDispatcherRequest::StopPropagation)
+                }
+                PriorityDispatcherRequest::StopListeningAndPropagation => {
+                    Some(SyncDispatcherRequest::StopListeningAndPropagation)
+                }
+                PriorityDispatcherRequest::Handled => Some(SyncDispatcherRequest::Handled),
+                PriorityDispatcherRequest::Redirect {
+                    event,
+                    inherit_priority,
+                } => {
+                    redirects.lock().push((event, inherit_priority));
+
+                    None
+                }
+            }),
+            priority,
+        )
+    }
+
+    /// Moves the listener or [`Fn`] registered with `handle` from its
+    /// priority-level to the level `delta` steps away, as defined by [`Step`].
+    /// For integers, `delta` is simply added to the priority, thus a negative
+    /// `delta` moves the listener ahead of higher priority-levels.
+    ///
+    /// Returns `false` if `handle` is unknown or if the new priority-level
+    /// is out of range for `P`, in both cases nothing is moved.
+    ///
+    /// **Note**: When moved, the listener is appended behind all listeners
+    /// already residing at the new priority-level.
+    /// A `delta` of `0` leaves the listener untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::{
+    ///    RwLock,
+    ///    sync::{Listener, PriorityDispatcher, SyncDispatcherRequest},
+    /// };
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct ListenerStruct {}
+    ///
+    /// impl Listener<Event> for ListenerStruct {
+    ///     fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> { None }
+    /// }
+    ///
+    /// fn main() {
+    ///     let listener = Arc::new(RwLock::new(ListenerStruct {}));
+    ///     let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    ///     let handle = dispatcher.add_listener(Event::EventType, &listener, 1);
+    ///
+    ///     assert!(dispatcher.bump_priority(handle, -1));
+    ///     assert!(!dispatcher.bump_priority(handle, -1));
+    /// }
+    /// ```
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Step`]: trait.Step.html
+    pub fn bump_priority(&mut self, handle: ListenerHandle, delta: i32) -> bool
+    where
+        P: Step,
+    {
+        for prioritised_listener_collection in self.events.values_mut() {
+            let (priority, position) = match locate(prioritised_listener_collection, handle) {
+                Some(found) => found,
+                None => continue,
+            };
+
+            if delta == 0 {
+                return true;
+            }
+
+            let new_priority = match priority.offset(delta) {
+                Some(new_priority) => new_priority,
+                None => return false,
+            };
+
+            let listener_collection = prioritised_listener_collection
+                .get_mut(&priority)
+                .expect("priority-level has just been found");
+
+            match position {
+                Position::Trait(index) => {
+                    let registered = listener_collection.traits.remove(index);
+
+                    prioritised_listener_collection
+                        .entry(new_priority)
+                        .or_insert_with(FnsAndTraits::new)
+                        .traits
+                        .push(registered);
+                }
+                Position::Fn(index) => {
+                    let registered = listener_collection.fns.remove(index);
+
+                    prioritised_listener_collection
+                        .entry(new_priority)
+                        .or_insert_with(FnsAndTraits::new)
+                        .fns
+                        .push(registered);
+                }
+            }
+
+            if let Some(listener_collection) = prioritised_listener_collection.get(&priority) {
+                if listener_collection.is_empty() {
+                    prioritised_listener_collection.remove(&priority);
+                }
+            }
+
+            return true;
+        }
+
+        false
+    }
+
+    /// Removes the listener or [`Fn`] registered with `handle`,
+    /// the order of all other listeners stays untouched.
+    /// Returns `false` if `handle` is not registered on this dispatcher,
+    /// e.g. because it has already been removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::PriorityDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///     let handle = dispatcher.add_fn(Event::EventType, Box::new(|_| None), 1);
+    ///
+    ///     assert!(dispatcher.remove_listener(handle));
+    ///     assert!(!dispatcher.remove_listener(handle));
+    /// }
+    /// ```
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn remove_listener(&mut self, handle: ListenerHandle) -> bool {
+        for prioritised_listener_collection in self.events.values_mut() {
+            let removed = prioritised_listener_collection.iter_mut().find_map(
+                |(priority, listener_collection)| {
+                    if listener_collection.remove(handle) {
+                        Some((priority.clone(), listener_collection.is_empty()))
+                    } else {
+                        None
+                    }
+                },
+            );
 
-                return;
+            if let Some((priority, is_empty)) = removed {
+                if is_empty {
+                    prioritised_listener_collection.remove(&priority);
+                }
+
+                return true;
             }
-            prioritised_listener_collection
-                .insert(priority.clone(), FnsAndTraits::new_with_fns(vec![function]));
-            return;
         }
 
-        let mut b_tree_map = BTreeMap::new();
-        b_tree_map.insert(priority, FnsAndTraits::new_with_fns(vec![function]));
-        self.events.insert(event_identifier, b_tree_map);
+        false
+    }
+
+    /// Enables or disables the listener or [`Fn`] registered with `handle`.
+    /// A disabled registration keeps its priority-level and position but is
+    /// skipped by every dispatch, until it is enabled again.
+    /// Returns `false` if `handle` is not registered on this dispatcher.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn set_listener_enabled(&mut self, handle: ListenerHandle, enabled: bool) -> bool {
+        self.events
+            .values_mut()
+            .flat_map(|prioritised_listener_collection| {
+                prioritised_listener_collection.values_mut()
+            })
+            .any(|listener_collection| listener_collection.set_enabled(handle, enabled))
+    }
+
+    /// Takes a [`DispatcherSnapshot`] of all registrations along with
+    /// their priority-levels, to be put back in place via [`restore`].
+    ///
+    /// [`DispatcherSnapshot`]: struct.DispatcherSnapshot.html
+    /// [`restore`]: #method.restore
+    pub fn snapshot(&self) -> DispatcherSnapshot<T, P> {
+        DispatcherSnapshot {
+            levels: self
+                .events
+                .iter()
+                .flat_map(|(event_identifier, prioritised_listener_collection)| {
+                    prioritised_listener_collection.iter().map(
+                        move |(priority, listener_collection)| {
+                            (
+                                event_identifier.clone(),
+                                priority.clone(),
+                                listener_collection.snapshot(),
+                            )
+                        },
+                    )
+                })
+                .collect(),
+            names: HashMap::new(),
+        }
+    }
+
+    /// Replaces all registrations with the ones of `snapshot`,
+    /// keeping their priority-levels and order.
+    ///
+    /// **Note**: Restored registrations get new [`ListenerHandle`]s,
+    /// handles handed out before no longer identify them.
+    /// Like [`add_listener`], the dispatcher only holds [`Weak`]-references
+    /// to restored [`Listener`]s, hence listeners only owned by `snapshot`
+    /// live as long as `snapshot` does.
+    ///
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    /// [`add_listener`]: #method.add_listener
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    /// [`Listener`]: trait.Listener.html
+    pub fn restore(&mut self, snapshot: &DispatcherSnapshot<T, P>) {
+        for listener_collection in self.events.values_mut().flat_map(BTreeMap::values_mut) {
+            listener_collection.clear();
+        }
+
+        self.events.clear();
+
+        for (event_identifier, priority, level) in &snapshot.levels {
+            self.events
+                .entry(event_identifier.clone())
+                .or_default()
+                .insert(
+                    priority.clone(),
+                    FnsAndTraits::from_snapshot(level, |_, _| ()),
+                );
+        }
     }
 
     /// All [`Listener`]s listening to a passed `event_identifier`
@@ -237,45 +648,506 @@ where
     /// be removed from the event-dispatcher.
     ///
     /// **Notice**: [`Listener`]s will called ordered by their priority-level.
+    /// Events redirected to by a [`RedirectingFn`] are dispatched once
+    /// the redirecting priority-level is done.
     ///
     /// [`Listener`]: trait.Listener.html
     /// [`on_event`]: trait.Listener.html#tymethod.on_event
     /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
     /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
+    /// [`RedirectingFn`]: type.RedirectingFn.html
     pub fn dispatch_event(&mut self, event_identifier: &T) {
+        self.redirects.lock().clear();
+        self.dispatch_redirecting(event_identifier, None, 0);
+    }
+
+    /// Dispatches like [`dispatch_event`], trying one listener after
+    /// another until one returns [`SyncDispatcherRequest::Handled`].
+    /// Returns `true` if a listener handled `event_identifier` and
+    /// `false` if all listeners have been tried.
+    ///
+    /// **Note**: Listeners stopping propagation otherwise end the
+    /// dispatch as well, but the event does not count as handled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{PriorityDispatcher, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     KeyPress,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///
+    ///     assert!(!dispatcher.dispatch_until_handled(&Event::KeyPress));
+    ///
+    ///     dispatcher.add_fn(Event::KeyPress, Box::new(|_| None), 0);
+    ///     dispatcher.add_fn(
+    ///         Event::KeyPress,
+    ///         Box::new(|_| Some(SyncDispatcherRequest::Handled)),
+    ///         1,
+    ///     );
+    ///
+    ///     assert!(dispatcher.dispatch_until_handled(&Event::KeyPress));
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`SyncDispatcherRequest::Handled`]: enum.SyncDispatcherRequest.html#variant.Handled
+    pub fn dispatch_until_handled(&mut self, event_identifier: &T) -> bool {
+        let mut handled = false;
+
+        self.dispatch_inspecting(event_identifier, None, |request| {
+            if let Some(SyncDispatcherRequest::Handled) = request {
+                handled = true;
+            }
+        });
+
+        handled
+    }
+
+    /// Dispatches like [`dispatch_event`], but calls the listener registered
+    /// with `front` before all others, regardless of its priority-level.
+    /// Afterwards all other listeners are called as usual, `front` is not
+    /// called a second time.
+    /// If `front` is not listening to `event_identifier`, this equals
+    /// [`dispatch_event`].
+    /// Returns how many listeners and [`Fn`]s have been called.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::PriorityDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     KeyPress,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///     dispatcher.add_fn(Event::KeyPress, Box::new(|_| None), 0);
+    ///     let focused = dispatcher.add_fn(Event::KeyPress, Box::new(|_| None), 9);
+    ///
+    ///     assert_eq!(dispatcher.dispatch_event_with_front(&Event::KeyPress, focused), 2);
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn dispatch_event_with_front(
+        &mut self,
+        event_identifier: &T,
+        front: ListenerHandle,
+    ) -> usize {
+        let mut invocations = 0;
+        let mut skip = None;
+
         if let Some(prioritised_listener_collection) = self.events.get_mut(event_identifier) {
-            for (_, listener_collection) in prioritised_listener_collection.iter_mut() {
-                let mut found_invalid_weak_ref = false;
-
-                if let ExecuteRequestsResult::Stopped = execute_sync_dispatcher_requests(
-                    &mut listener_collection.traits,
-                    |weak_listener| {
-                        if let Some(listener_arc) = weak_listener.upgrade() {
-                            let mut listener = listener_arc.write();
-                            listener.on_event(event_identifier)
-                        } else {
-                            found_invalid_weak_ref = true;
-                            None
+            if let Some((priority, position)) = locate(prioritised_listener_collection, front) {
+                skip = Some(front);
+
+                let listener_collection = prioritised_listener_collection
+                    .get_mut(&priority)
+                    .expect("priority-level has just been found");
+
+                let request = match position {
+                    Position::Trait(index) if listener_collection.traits[index].enabled => {
+                        match listener_collection.traits[index].listener.upgrade() {
+                            Some(listener_arc) => {
+                                invocations += 1;
+                                let mut listener = listener_arc.write();
+                                let request = listener.on_event(event_identifier);
+
+                                if removes_listener(&request) {
+                                    listener.on_unsubscribe();
+                                }
+
+                                request
+                            }
+                            None => None,
+                        }
+                    }
+                    Position::Fn(index) if listener_collection.fns[index].enabled => {
+                        invocations += 1;
+                        (listener_collection.fns[index].listener)(event_identifier)
+                    }
+                    Position::Trait(_) | Position::Fn(_) => None,
+                };
+
+                if removes_listener(&request) {
+                    match position {
+                        Position::Trait(index) => {
+                            listener_collection.traits.remove(index);
+                        }
+                        Position::Fn(index) => {
+                            listener_collection.fns.remove(index);
                         }
+                    }
+
+                    if listener_collection.is_empty() {
+                        prioritised_listener_collection.remove(&priority);
+                    }
+                }
+
+                if let Some(SyncDispatcherRequest::StopPropagation)
+                | Some(SyncDispatcherRequest::StopListeningAndPropagation)
+                | Some(SyncDispatcherRequest::Handled) = request
+                {
+                    return invocations;
+                }
+            }
+        }
+
+        self.dispatch_inspecting(event_identifier, skip, |_| invocations += 1);
+
+        invocations
+    }
+
+    /// Dispatches `event_identifier` only to the listeners registered with
+    /// exactly `priority`, allowing to step through the priority-levels
+    /// one at a time and inspect state in between.
+    /// Returns how many listeners and [`Fn`]s have been called.
+    ///
+    /// Requests are honoured as in [`dispatch_event`], but stopping
+    /// propagation only ends this priority-level, as no other level is
+    /// dispatched to anyway.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::PriorityDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Turn,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///     dispatcher.add_fn(Event::Turn, Box::new(|_| None), 0);
+    ///     dispatcher.add_fn(Event::Turn, Box::new(|_| None), 1);
+    ///     dispatcher.add_fn(Event::Turn, Box::new(|_| None), 1);
+    ///
+    ///     assert_eq!(dispatcher.dispatch_tier(&Event::Turn, &0), 1);
+    ///     assert_eq!(dispatcher.dispatch_tier(&Event::Turn, &1), 2);
+    ///     assert_eq!(dispatcher.dispatch_tier(&Event::Turn, &2), 0);
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn dispatch_tier(&mut self, event_identifier: &T, priority: &P) -> usize {
+        let mut invocations = 0;
+
+        if let Some(listener_collection) =
+            self.events
+                .get_mut(event_identifier)
+                .and_then(|prioritised_listener_collection| {
+                    prioritised_listener_collection.get_mut(priority)
+                })
+        {
+            listener_collection.dispatch(event_identifier, None, &mut |_| invocations += 1);
+        }
+
+        invocations
+    }
+
+    /// Dispatches like [`dispatch_event`], but starts at the priority-level
+    /// `priority` instead of the first one, skipping all listeners that
+    /// would be called earlier.
+    /// Returns how many listeners and [`Fn`]s have been called.
+    ///
+    /// Events redirected to by a [`RedirectingFn`] are dispatched like
+    /// in [`dispatch_event`], their calls count as well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::PriorityDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Input,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///     dispatcher.add_fn(Event::Input, Box::new(|_| None), 0);
+    ///     dispatcher.add_fn(Event::Input, Box::new(|_| None), 1);
+    ///     dispatcher.add_fn(Event::Input, Box::new(|_| None), 2);
+    ///
+    ///     assert_eq!(dispatcher.dispatch_from_tier(&Event::Input, &1), 2);
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`RedirectingFn`]: type.RedirectingFn.html
+    pub fn dispatch_from_tier(&mut self, event_identifier: &T, priority: &P) -> usize {
+        self.redirects.lock().clear();
+        self.dispatch_redirecting(event_identifier, Some(priority), 0)
+    }
+
+    /// Dispatches like [`dispatch_event`], but isolates listeners from each
+    /// other's panics: A panicking listener is skipped as if it had
+    /// returned `None` and the dispatch continues with the next listener,
+    /// within its priority-level and across all following ones.
+    /// Returns the priority-level and the calling-position within that
+    /// level of every panicked listener, in calling-order.
+    ///
+    /// **Note**: The panic-hook still runs for every panic, printing its
+    /// message to `stderr` by default. Listeners are locked via
+    /// [`RwLock`], which does not poison, a panicked listener is called
+    /// again by the next dispatch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::PriorityDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Input,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///     dispatcher.add_fn(Event::Input, Box::new(|_| None), 0);
+    ///     dispatcher.add_fn(Event::Input, Box::new(|_| panic!("misbehaving")), 0);
+    ///     dispatcher.add_fn(Event::Input, Box::new(|_| None), 1);
+    ///
+    ///     assert_eq!(dispatcher.dispatch_event_catching(&Event::Input), [(0, 1)]);
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`RwLock`]: ../type.RwLock.html
+    pub fn dispatch_event_catching(&mut self, event_identifier: &T) -> Vec<(P, usize)> {
+        let mut panics = Vec::new();
+
+        if let Some(prioritised_listener_collection) = self.events.get_mut(event_identifier) {
+            for (priority, listener_collection) in prioritised_listener_collection.iter_mut() {
+                let mut position = 0;
+
+                let result = listener_collection.dispatch_invoking(
+                    event_identifier,
+                    None,
+                    &mut |_, call| {
+                        let request =
+                            panic::catch_unwind(AssertUnwindSafe(call)).unwrap_or_else(|_| {
+                                panics.push((priority.clone(), position));
+                                None
+                            });
+                        position += 1;
+
+                        request
                     },
-                ) {
+                );
+
+                if let ExecuteRequestsResult::Stopped = result {
                     break;
                 }
+            }
+        }
+
+        panics
+    }
+
+    /// Dispatches like [`dispatch_event`] and returns a [`DispatchReport`]
+    /// of how many listeners have been called, which of them stopped
+    /// listening and whether one stopped propagation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::{PriorityDispatcher, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Tick,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    ///     dispatcher.add_fn(Event::Tick, Box::new(|_| None), 0);
+    ///     let once = dispatcher.add_fn(
+    ///         Event::Tick,
+    ///         Box::new(|_| Some(SyncDispatcherRequest::StopListening)),
+    ///         1,
+    ///     );
+    ///
+    ///     let report = dispatcher.dispatch_event_report(&Event::Tick);
+    ///
+    ///     assert_eq!(report.invoked, 2);
+    ///     assert_eq!(report.removed, [once]);
+    /// }
+    /// ```
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    /// [`DispatchReport`]: struct.DispatchReport.html
+    pub fn dispatch_event_report(&mut self, event_identifier: &T) -> DispatchReport {
+        let mut report = DispatchReport::default();
+
+        if let Some(prioritised_listener_collection) = self.events.get_mut(event_identifier) {
+            let result =
+                dispatch_by_priority(prioritised_listener_collection, |listener_collection| {
+                    listener_collection.dispatch_invoking(
+                        event_identifier,
+                        None,
+                        &mut |handle, call| {
+                            let request = call();
+                            report.invoked += 1;
+
+                            if let Some(SyncDispatcherRequest::StopListening)
+                            | Some(SyncDispatcherRequest::StopListeningAndPropagation) = request
+                            {
+                                report.removed.push(handle);
+                            }
+
+                            request
+                        },
+                    )
+                });
+
+            report.stopped = matches!(result, ExecuteRequestsResult::Stopped);
+        }
+
+        report
+    }
+
+    /// Dispatches `event_identifier` from the priority-level `from` onwards,
+    /// or from the first one, until a level stops propagation or redirects.
+    /// Redirected events are dispatched once the redirecting level is done,
+    /// `depth` counting the redirects leading to `event_identifier`.
+    /// Returns how many listeners and [`Fn`]s have been called.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    fn dispatch_redirecting(
+        &mut self,
+        event_identifier: &T,
+        from: Option<&P>,
+        depth: usize,
+    ) -> usize {
+        let mut invocations = 0;
+        let mut redirecting_priority = None;
+
+        if let Some(prioritised_listener_collection) = self.events.get_mut(event_identifier) {
+            let levels = match from {
+                Some(priority) => prioritised_listener_collection.range_mut(priority..),
+                None => prioritised_listener_collection.range_mut(..),
+            };
+
+            for (priority, listener_collection) in levels {
+                let result =
+                    listener_collection.dispatch(event_identifier, None, &mut |_| invocations += 1);
+
+                if !self.redirects.lock().is_empty() {
+                    redirecting_priority = Some(priority.clone());
 
-                if let ExecuteRequestsResult::Stopped =
-                    execute_sync_dispatcher_requests(&mut listener_collection.fns, |callback| {
-                        callback(event_identifier)
-                    })
-                {
                     break;
                 }
 
-                if found_invalid_weak_ref {
-                    listener_collection
-                        .traits
-                        .retain(|listener| Weak::clone(listener).upgrade().is_some());
+                if let ExecuteRequestsResult::Stopped = result {
+                    break;
                 }
             }
         }
+
+        if let Some(priority) = redirecting_priority {
+            let redirects = mem::take(&mut *self.redirects.lock());
+
+            if depth < DEFAULT_MAX_DISPATCH_DEPTH {
+                for (event, inherit_priority) in redirects {
+                    let from = if inherit_priority {
+                        Some(&priority)
+                    } else {
+                        None
+                    };
+
+                    invocations += self.dispatch_redirecting(&event, from, depth + 1);
+                }
+            }
+        }
+
+        invocations
     }
+
+    /// Dispatches `event_identifier`, passing every request returned
+    /// from a listener to `inspect` before executing it.
+    /// The registration identified by `skip` is not called.
+    fn dispatch_inspecting<F>(
+        &mut self,
+        event_identifier: &T,
+        skip: Option<ListenerHandle>,
+        mut inspect: F,
+    ) where
+        F: FnMut(&Option<SyncDispatcherRequest>),
+    {
+        if let Some(prioritised_listener_collection) = self.events.get_mut(event_identifier) {
+            dispatch_by_priority(prioritised_listener_collection, |listener_collection| {
+                listener_collection.dispatch(event_identifier, skip, &mut inspect)
+            });
+        }
+    }
+}
+
+impl<P, E> PriorityDispatcher<P, Cancelable<E>>
+where
+    P: Ord + Clone,
+    E: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Dispatches like [`dispatch_event`] and returns whether a listener
+    /// has cancelled `event`.
+    /// If `event` stops on cancel, no listener is called after it has been
+    /// cancelled, neither on the same nor on a later priority-level.
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    pub fn dispatch_cancelable(&mut self, event: &Cancelable<E>) -> bool {
+        if let Some(prioritised_listener_collection) = self.events.get_mut(event) {
+            dispatch_by_priority(prioritised_listener_collection, |listener_collection| {
+                listener_collection.dispatch_invoking(event, None, &mut |_, call| {
+                    if event.is_halted() {
+                        Some(SyncDispatcherRequest::StopPropagation)
+                    } else {
+                        call()
+                    }
+                })
+            });
+        }
+
+        event.is_cancelled()
+    }
+}
+
+/// Finds the priority-level and position of the registration
+/// identified by `handle`.
+fn locate<P, T>(
+    prioritised_listener_collection: &BTreeMap<P, FnsAndTraits<T>>,
+    handle: ListenerHandle,
+) -> Option<(P, Position)>
+where
+    P: Ord + Clone,
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    prioritised_listener_collection
+        .iter()
+        .find_map(|(priority, listener_collection)| {
+            if let Some(index) = listener_collection
+                .traits
+                .iter()
+                .position(|registered| registered.handle == handle)
+            {
+                Some((priority.clone(), Position::Trait(index)))
+            } else {
+                listener_collection
+                    .fns
+                    .iter()
+                    .position(|registered| registered.handle == handle)
+                    .map(|index| (priority.clone(), Position::Fn(index)))
+            }
+        })
 }
@@ -0,0 +1,131 @@
+use super::{
+    DispatchResult, DispatchTracer, ListenerHandle, SyncDispatcherRequest, UNNAMED_LISTENER,
+};
+use std::{cell::RefCell, fmt::Debug, hash::Hash};
+use tracing::{field, span::EnteredSpan};
+
+thread_local! {
+    /// The `dispatch`-spans of the dispatches ongoing on this thread,
+    /// innermost last.
+    static DISPATCH_SPANS: RefCell<Vec<EnteredSpan>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A [`DispatchTracer`] reporting dispatches to [`tracing`].
+/// Only available with the `tracing`-feature enabled, which makes every
+/// [`Dispatcher`] start with a tracer created via [`without_payload`],
+/// replaced via [`Dispatcher::set_tracer`].
+///
+/// Every dispatch reaching the listeners is wrapped in a `dispatch`-span
+/// at `DEBUG`-level, carrying the number of enabled `listeners` and, if
+/// the tracer has been created via [`new`], the `Debug`-representation of
+/// the `event`. Once the dispatch is done, the span records how many
+/// listeners have been `invoked` and whether one `stopped` propagation.
+/// Every called listener emits an event at `TRACE`-level within the span,
+/// carrying its `handle`, `name` and returned `request`.
+///
+/// Without the feature, dispatchers carry no tracer at all, hence
+/// tracing costs nothing unless the feature is enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::sync::{Dispatcher, SpanTracer};
+///
+/// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Save,
+/// }
+///
+/// fn main() {
+///     let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+///     dispatcher.add_fn(Event::Save, Box::new(|_| None));
+///     dispatcher.set_tracer(SpanTracer::new());
+///
+///     // Reported to the subscriber installed via `tracing`, if any.
+///     dispatcher.dispatch_event(&Event::Save);
+/// }
+/// ```
+///
+/// [`DispatchTracer`]: trait.DispatchTracer.html
+/// [`tracing`]: https://docs.rs/tracing
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`Dispatcher::set_tracer`]: struct.Dispatcher.html#method.set_tracer
+/// [`new`]: #method.new
+/// [`without_payload`]: #method.without_payload
+pub struct SpanTracer<T> {
+    payload: Option<fn(&T) -> &dyn Debug>,
+}
+
+impl<T: Debug> SpanTracer<T> {
+    /// Creates a tracer recording the `Debug`-representation of every
+    /// dispatched event as `event`-field.
+    pub fn new() -> Self {
+        SpanTracer {
+            payload: Some(as_debug::<T>),
+        }
+    }
+}
+
+impl<T: Debug> Default for SpanTracer<T> {
+    fn default() -> Self {
+        SpanTracer::new()
+    }
+}
+
+impl<T> SpanTracer<T> {
+    /// Creates a tracer leaving out the `event`-field, for events that do
+    /// not implement `Debug` or should not end up in traces.
+    pub fn without_payload() -> Self {
+        SpanTracer { payload: None }
+    }
+}
+
+impl<T> DispatchTracer<T> for SpanTracer<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn enter(&self, event: &T, listeners: usize) {
+        let span = tracing::debug_span!(
+            "dispatch",
+            listeners,
+            event = field::Empty,
+            invoked = field::Empty,
+            stopped = field::Empty,
+        );
+
+        if let Some(payload) = self.payload {
+            span.record("event", field::debug(payload(event)));
+        }
+
+        DISPATCH_SPANS.with(|spans| spans.borrow_mut().push(span.entered()));
+    }
+
+    fn invoked(
+        &self,
+        _event: &T,
+        handle: ListenerHandle,
+        name: Option<&str>,
+        request: Option<&SyncDispatcherRequest>,
+    ) {
+        tracing::trace!(
+            ?handle,
+            name = name.unwrap_or(UNNAMED_LISTENER),
+            ?request,
+            "listener invoked"
+        );
+    }
+
+    fn exit(&self, _event: &T, result: DispatchResult) {
+        let span = DISPATCH_SPANS.with(|spans| spans.borrow_mut().pop());
+
+        if let Some(span) = span {
+            span.record("invoked", result.invoked);
+            span.record("stopped", result.stopped);
+        }
+    }
+}
+
+/// Views `event` via its `Debug`-implementation.
+fn as_debug<T: Debug>(event: &T) -> &dyn Debug {
+    event
+}
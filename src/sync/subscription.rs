@@ -0,0 +1,66 @@
+use super::ListenerHandle;
+use std::{
+    sync::mpsc::{Receiver, RecvError, TryIter, TryRecvError},
+    time::Duration,
+};
+
+/// Receives every event dispatched to the event-variant it has been
+/// created for via [`Dispatcher::subscribe`], to be pulled whenever
+/// convenient instead of being handled by a callback.
+///
+/// Events are cloned into an unbounded channel during dispatching.
+/// Dropping the subscription closes the channel, the dispatcher removes
+/// the registration the next time it dispatches to it.
+///
+/// [`Dispatcher::subscribe`]: struct.Dispatcher.html#method.subscribe
+#[derive(Debug)]
+pub struct Subscription<T> {
+    pub(crate) handle: ListenerHandle,
+    pub(crate) receiver: Receiver<T>,
+}
+
+impl<T> Subscription<T> {
+    /// The [`ListenerHandle`] of this subscription's registration, allowing
+    /// to unregister it via `remove_listener` without dropping it.
+    ///
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn handle(&self) -> ListenerHandle {
+        self.handle
+    }
+
+    /// Blocks until the next event is received.
+    /// Fails once the registration has been removed from or dropped
+    /// with its dispatcher and all received events have been taken.
+    pub fn recv(&self) -> Result<T, RecvError> {
+        self.receiver.recv()
+    }
+
+    /// Like [`recv`] but gives up waiting after `timeout`.
+    ///
+    /// [`recv`]: #method.recv
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<T> {
+        self.receiver.recv_timeout(timeout).ok()
+    }
+
+    /// Takes the next received event without blocking.
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        self.receiver.try_recv()
+    }
+
+    /// Takes all events received so far without blocking,
+    /// in dispatch-order.
+    pub fn drain(&self) -> TryIter<'_, T> {
+        self.receiver.try_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Subscription<T> {
+    type Item = T;
+    type IntoIter = std::sync::mpsc::Iter<'a, T>;
+
+    /// Blocks for every next event, ending once the registration has
+    /// been removed from or dropped with its dispatcher.
+    fn into_iter(self) -> Self::IntoIter {
+        self.receiver.iter()
+    }
+}
@@ -0,0 +1,223 @@
+use super::{DispatchResult, ListenerHandle, Registered, SyncDispatcherRequest, TopicError};
+use std::{collections::HashMap, sync::Arc};
+
+type TopicFn<P> = Box<dyn Fn(&str, &P) -> Option<SyncDispatcherRequest> + Send + Sync>;
+type SharedTopicFn<P> = Arc<dyn Fn(&str, &P) -> Option<SyncDispatcherRequest> + Send + Sync>;
+type Subscriptions<P> = Vec<Registered<SharedTopicFn<P>>>;
+
+/// One segment of the subscription-trie, holding the subscriptions
+/// ending at it and the segments following it.
+struct TopicNode<P> {
+    segments: HashMap<String, TopicNode<P>>,
+    any_segment: Option<Box<TopicNode<P>>>,
+    exact: Subscriptions<P>,
+    remaining: Subscriptions<P>,
+}
+
+impl<P> Default for TopicNode<P> {
+    fn default() -> TopicNode<P> {
+        TopicNode {
+            segments: HashMap::new(),
+            any_segment: None,
+            exact: Vec::new(),
+            remaining: Vec::new(),
+        }
+    }
+}
+
+impl<P> TopicNode<P> {
+    /// Collects the subscriptions matching `segments`, all subscriptions
+    /// ending in `#` match any number of remaining segments, even none.
+    fn collect_matches<'a>(
+        &'a self,
+        segments: &[&str],
+        matches: &mut Vec<&'a Registered<SharedTopicFn<P>>>,
+    ) {
+        matches.extend(&self.remaining);
+
+        match segments.split_first() {
+            None => matches.extend(&self.exact),
+            Some((segment, rest)) => {
+                if let Some(node) = self.segments.get(*segment) {
+                    node.collect_matches(rest, matches);
+                }
+
+                if let Some(node) = &self.any_segment {
+                    node.collect_matches(rest, matches);
+                }
+            }
+        }
+    }
+
+    /// Removes the subscription identified by `handle` and returns
+    /// whether it has been found.
+    fn remove(&mut self, handle: ListenerHandle) -> bool {
+        let before = self.exact.len() + self.remaining.len();
+        self.exact.retain(|registered| registered.handle != handle);
+        self.remaining
+            .retain(|registered| registered.handle != handle);
+
+        before != self.exact.len() + self.remaining.len()
+            || self.segments.values_mut().any(|node| node.remove(handle))
+            || self
+                .any_segment
+                .as_mut()
+                .is_some_and(|node| node.remove(handle))
+    }
+}
+
+/// In charge of sync dispatching by topics, e.g. `entity/42/damaged`.
+/// Topics consist of segments separated by `/`, subscriptions may use
+/// wildcards to listen to several topics at once:
+///
+/// `*` or `+` matches exactly one segment,
+/// `entity/*/damaged` matches `entity/42/damaged` but not `entity/damaged`.
+///
+/// `#` as last segment matches any number of remaining segments,
+/// `ui/#` matches `ui`, `ui/button` and `ui/button/clicked`.
+///
+/// Subscriptions are stored in a trie of segments, a dispatch only visits
+/// the segments of its topic instead of testing every subscription.
+///
+/// # Examples
+///
+/// ```rust
+/// use hey_listen::sync::TopicDispatcher;
+/// use std::sync::{Arc, Mutex};
+///
+/// fn main() {
+///     let mut dispatcher: TopicDispatcher<u32> = TopicDispatcher::default();
+///     let damaged = Arc::new(Mutex::new(Vec::new()));
+///     let record = Arc::clone(&damaged);
+///
+///     dispatcher
+///         .add_fn(
+///             "entity/*/damaged",
+///             Box::new(move |topic, damage| {
+///                 record.lock().unwrap().push((topic.to_string(), *damage));
+///
+///                 None
+///             }),
+///         )
+///         .unwrap();
+///
+///     dispatcher.dispatch_event("entity/42/damaged", &7);
+///     dispatcher.dispatch_event("entity/42/healed", &3);
+///
+///     assert_eq!(*damaged.lock().unwrap(), [("entity/42/damaged".to_string(), 7)]);
+/// }
+/// ```
+pub struct TopicDispatcher<P> {
+    root: TopicNode<P>,
+}
+
+impl<P> Default for TopicDispatcher<P> {
+    fn default() -> TopicDispatcher<P> {
+        TopicDispatcher {
+            root: TopicNode::default(),
+        }
+    }
+}
+
+impl<P> TopicDispatcher<P> {
+    /// Adds a [`Fn`] to listen for all topics matching `pattern`, it is
+    /// called with the dispatched topic and payload.
+    /// Returns a [`ListenerHandle`] identifying this registration, or
+    /// [`TopicError::InvalidPattern`] if `pattern` uses `#` anywhere
+    /// but as its last segment.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    /// [`TopicError::InvalidPattern`]: enum.TopicError.html#variant.InvalidPattern
+    pub fn add_fn(
+        &mut self,
+        pattern: &str,
+        function: TopicFn<P>,
+    ) -> Result<ListenerHandle, TopicError> {
+        let segments: Vec<&str> = pattern.split('/').collect();
+        let (last, init) = segments
+            .split_last()
+            .expect("splitting yields at least one segment");
+
+        if init.contains(&"#") {
+            return Err(TopicError::InvalidPattern(pattern.to_string()));
+        }
+
+        let (path, remaining) = if *last == "#" {
+            (init, true)
+        } else {
+            (&segments[..], false)
+        };
+
+        let mut node = &mut self.root;
+
+        for segment in path {
+            node = match *segment {
+                "*" | "+" => node.any_segment.get_or_insert_with(Box::default),
+                segment => node.segments.entry(segment.to_string()).or_default(),
+            };
+        }
+
+        let registered = Registered::new(Arc::from(function));
+        let handle = registered.handle;
+
+        if remaining {
+            node.remaining.push(registered);
+        } else {
+            node.exact.push(registered);
+        }
+
+        Ok(handle)
+    }
+
+    /// Removes the subscription registered with `handle`.
+    /// Returns `false` if `handle` is not registered on this dispatcher.
+    pub fn remove_listener(&mut self, handle: ListenerHandle) -> bool {
+        self.root.remove(handle)
+    }
+
+    /// Calls every [`Fn`] subscribed to a pattern matching `topic` with
+    /// `topic` and `payload`, in the order they have been added, no matter
+    /// which pattern they match by.
+    /// A returned [`SyncDispatcherRequest`] is honoured, subscriptions
+    /// stopping to listen are removed once all have been called.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
+    pub fn dispatch_event(&mut self, topic: &str, payload: &P) -> DispatchResult {
+        let segments: Vec<&str> = topic.split('/').collect();
+        let mut matches = Vec::new();
+        self.root.collect_matches(&segments, &mut matches);
+        matches.sort_by_key(|registered| registered.handle);
+
+        let mut dispatch_result = DispatchResult::default();
+        let mut stopped_listening = Vec::new();
+
+        for registered in matches {
+            dispatch_result.invoked += 1;
+
+            match (registered.listener)(topic, payload) {
+                None => (),
+                Some(SyncDispatcherRequest::StopListening) => {
+                    stopped_listening.push(registered.handle)
+                }
+                Some(SyncDispatcherRequest::StopPropagation)
+                | Some(SyncDispatcherRequest::Handled) => {
+                    dispatch_result.stopped = true;
+                    break;
+                }
+                Some(SyncDispatcherRequest::StopListeningAndPropagation) => {
+                    stopped_listening.push(registered.handle);
+                    dispatch_result.stopped = true;
+                    break;
+                }
+            }
+        }
+
+        for handle in stopped_listening {
+            self.root.remove(handle);
+        }
+
+        dispatch_result
+    }
+}
@@ -0,0 +1,251 @@
+use super::{
+    remove_registered, ListenerHandle, Registered, RwLock, ValueFn, ValueFnsAndTraits,
+    ValueListener,
+};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::{Arc, Weak},
+};
+
+/// In charge of sync dispatching to listeners returning a value `V`.
+/// Owns a map event-variants and [`Weak`]-references to their
+/// [`ValueListener`]s and/or owns [`Fn`]s.
+///
+/// Opposed to [`Dispatcher`], listeners cannot issue requests,
+/// instead their returned values are folded via [`dispatch_fold`].
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+/// [`ValueListener`]: trait.ValueListener.html
+/// [`Dispatcher`]: struct.Dispatcher.html
+/// [`dispatch_fold`]: #method.dispatch_fold
+pub struct ValueDispatcher<T, V>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    events: HashMap<T, ValueFnsAndTraits<T, V>>,
+}
+
+impl<T, V> Default for ValueDispatcher<T, V>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn default() -> ValueDispatcher<T, V> {
+        ValueDispatcher {
+            events: HashMap::new(),
+        }
+    }
+}
+
+impl<T, V> ValueDispatcher<T, V>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Adds a [`ValueListener`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// [`ValueListener`]: trait.ValueListener.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_listener<D>(
+        &mut self,
+        event_identifier: T,
+        listener: &Arc<RwLock<D>>,
+    ) -> ListenerHandle
+    where
+        D: ValueListener<T, V> + Send + Sync + 'static,
+    {
+        let registered = Registered::new(Arc::downgrade(
+            &(Arc::clone(listener) as Arc<RwLock<dyn ValueListener<T, V> + Send + Sync + 'static>>),
+        ));
+        let handle = registered.handle;
+
+        self.events
+            .entry(event_identifier)
+            .or_default()
+            .traits
+            .push(registered);
+
+        handle
+    }
+
+    /// Adds a [`Fn`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Returns a [`ListenerHandle`] identifying this registration.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`ListenerHandle`]: struct.ListenerHandle.html
+    pub fn add_fn(&mut self, event_identifier: T, function: ValueFn<T, V>) -> ListenerHandle {
+        let registered = Registered::new(function);
+        let handle = registered.handle;
+
+        self.events
+            .entry(event_identifier)
+            .or_default()
+            .fns
+            .push(registered);
+
+        handle
+    }
+
+    /// Removes the listener or [`Fn`] registered with `handle`.
+    /// Returns `false` if `handle` is not registered on this dispatcher.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn remove_listener(&mut self, handle: ListenerHandle) -> bool {
+        self.events.values_mut().any(|listener_collection| {
+            remove_registered(&mut listener_collection.traits, handle)
+                || remove_registered(&mut listener_collection.fns, handle)
+        })
+    }
+
+    /// Dispatches `event_identifier` to all [`ValueListener`]s, then to all
+    /// [`Fn`]s, and folds their returned values into `init` via `fold`,
+    /// similar to [`Iterator::fold`].
+    /// Returns `init` untouched if nobody listens to `event_identifier`.
+    ///
+    /// # Examples
+    ///
+    /// Summing up damage contributions:
+    ///
+    /// ```rust
+    /// use hey_listen::{
+    ///    RwLock,
+    ///    sync::{ValueDispatcher, ValueListener},
+    /// };
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Hit,
+    /// }
+    ///
+    /// struct Weapon {
+    ///     damage: u32,
+    /// }
+    ///
+    /// impl ValueListener<Event, u32> for Weapon {
+    ///     fn on_event(&mut self, _event: &Event) -> u32 {
+    ///         self.damage
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let sword = Arc::new(RwLock::new(Weapon { damage: 5 }));
+    ///     let mut dispatcher: ValueDispatcher<Event, u32> = ValueDispatcher::default();
+    ///
+    ///     dispatcher.add_listener(Event::Hit, &sword);
+    ///     dispatcher.add_fn(Event::Hit, Box::new(|_| 2));
+    ///
+    ///     let damage = dispatcher.dispatch_fold(&Event::Hit, 0, |sum, damage| sum + damage);
+    ///
+    ///     assert_eq!(damage, 7);
+    /// }
+    /// ```
+    ///
+    /// [`ValueListener`]: trait.ValueListener.html
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Iterator::fold`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.fold
+    pub fn dispatch_fold<A, F>(&mut self, event_identifier: &T, init: A, mut fold: F) -> A
+    where
+        F: FnMut(A, &V) -> A,
+    {
+        let listener_collection = match self.events.get_mut(event_identifier) {
+            Some(listener_collection) => listener_collection,
+            None => return init,
+        };
+
+        let mut accumulator = init;
+        let mut found_invalid_weak_ref = false;
+
+        for registered in &listener_collection.traits {
+            if let Some(listener_arc) = registered.listener.upgrade() {
+                let value = listener_arc.write().on_event(event_identifier);
+                accumulator = fold(accumulator, &value);
+            } else {
+                found_invalid_weak_ref = true;
+            }
+        }
+
+        for registered in &listener_collection.fns {
+            let value = (registered.listener)(event_identifier);
+            accumulator = fold(accumulator, &value);
+        }
+
+        if found_invalid_weak_ref {
+            listener_collection
+                .traits
+                .retain(|registered| Weak::clone(&registered.listener).upgrade().is_some());
+        }
+
+        accumulator
+    }
+}
+
+impl<T, V> ValueDispatcher<T, Option<V>>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Asks the listeners of `event_identifier` one after another and
+    /// returns the first value one of them answers with, the remaining
+    /// listeners are not called.
+    /// [`ValueListener`]s are asked before [`Fn`]s, each in the order they
+    /// have been added, hence the earliest registration answering wins.
+    /// Returns `None` if no listener answers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hey_listen::sync::ValueDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Query {
+    ///     ScreenWidth,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: ValueDispatcher<Query, Option<u32>> = ValueDispatcher::default();
+    ///
+    ///     dispatcher.add_fn(Query::ScreenWidth, Box::new(|_| None));
+    ///     dispatcher.add_fn(Query::ScreenWidth, Box::new(|_| Some(1920)));
+    ///     dispatcher.add_fn(Query::ScreenWidth, Box::new(|_| Some(800)));
+    ///
+    ///     assert_eq!(dispatcher.dispatch_query(&Query::ScreenWidth), Some(1920));
+    /// }
+    /// ```
+    ///
+    /// [`ValueListener`]: trait.ValueListener.html
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn dispatch_query(&mut self, event_identifier: &T) -> Option<V> {
+        let listener_collection = self.events.get_mut(event_identifier)?;
+        let mut found_invalid_weak_ref = false;
+
+        let answer = listener_collection
+            .traits
+            .iter()
+            .find_map(|registered| match registered.listener.upgrade() {
+                Some(listener_arc) => listener_arc.write().on_event(event_identifier),
+                None => {
+                    found_invalid_weak_ref = true;
+                    None
+                }
+            })
+            .or_else(|| {
+                listener_collection
+                    .fns
+                    .iter()
+                    .find_map(|registered| (registered.listener)(event_identifier))
+            });
+
+        if found_invalid_weak_ref {
+            listener_collection
+                .traits
+                .retain(|registered| registered.listener.strong_count() > 0);
+        }
+
+        answer
+    }
+}
@@ -0,0 +1,92 @@
+use hey_listen::{
+    sync::{AnyDispatcher, DispatchResult, Listener, SyncDispatcherRequest},
+    RwLock,
+};
+use std::sync::Arc;
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct PlayerDied {
+    id: u32,
+}
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct ItemPicked;
+
+#[derive(Default)]
+struct Journal {
+    deaths: Vec<u32>,
+    items: usize,
+}
+
+impl Listener<PlayerDied> for Journal {
+    fn on_event(&mut self, event: &PlayerDied) -> Option<SyncDispatcherRequest> {
+        self.deaths.push(event.id);
+
+        None
+    }
+}
+
+impl Listener<ItemPicked> for Journal {
+    fn on_event(&mut self, _event: &ItemPicked) -> Option<SyncDispatcherRequest> {
+        self.items += 1;
+
+        Some(SyncDispatcherRequest::StopListening)
+    }
+}
+
+/// **Intended test-behaviour**: Events reach the listeners of their type
+/// only, one listener can listen to several event-types.
+///
+/// **Test**: A journal listens to `PlayerDied` and `ItemPicked`, the latter
+/// only once. A type without listeners dispatches to nobody.
+#[test]
+fn dispatches_by_event_type() {
+    let journal = Arc::new(RwLock::new(Journal::default()));
+    let mut dispatcher = AnyDispatcher::default();
+
+    dispatcher.add_listener::<PlayerDied, _>(&journal);
+    dispatcher.add_listener::<ItemPicked, _>(&journal);
+
+    dispatcher.dispatch_event(&PlayerDied { id: 1 });
+    dispatcher.dispatch_event(&ItemPicked);
+    dispatcher.dispatch_event(&ItemPicked);
+    dispatcher.dispatch_event(&PlayerDied { id: 2 });
+
+    assert_eq!(journal.read().deaths, [1, 2]);
+    assert_eq!(journal.read().items, 1);
+    assert_eq!(
+        dispatcher.dispatch_event(&String::from("unrelated")),
+        DispatchResult::default()
+    );
+}
+
+/// **Intended test-behaviour**: Stopping propagation and removing
+/// registrations work like on the enum-keyed dispatcher.
+///
+/// **Test**: Two closures listen to `PlayerDied`, the first stops
+/// propagation until it is removed by its handle.
+#[test]
+fn stop_propagation_and_removal() {
+    let mut dispatcher = AnyDispatcher::default();
+    let stopper =
+        dispatcher.add_fn::<PlayerDied>(Box::new(|_| Some(SyncDispatcherRequest::StopPropagation)));
+    dispatcher.add_fn::<PlayerDied>(Box::new(|_| None));
+
+    assert_eq!(
+        dispatcher.dispatch_event(&PlayerDied { id: 0 }),
+        DispatchResult {
+            invoked: 1,
+            stopped: true,
+        }
+    );
+
+    assert!(dispatcher.remove_listener(stopper));
+    assert!(!dispatcher.remove_listener(stopper));
+    assert_eq!(
+        dispatcher.dispatch_event(&PlayerDied { id: 0 }),
+        DispatchResult {
+            invoked: 1,
+            stopped: false,
+        }
+    );
+}
@@ -0,0 +1,165 @@
+#![cfg(feature = "async")]
+
+use hey_listen::sync::{
+    AsyncDispatcher, AsyncDispatcherRequest, AsyncListener, AsyncListenerFuture,
+};
+use parking_lot::Mutex;
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+};
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum Event {
+    Request,
+}
+
+/// Wakes the thread blocking on a future.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Drives `future` to completion on the current thread.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// Returns `Pending` once before completing, like a listener awaiting I/O.
+#[derive(Default)]
+struct YieldOnce {
+    yielded: bool,
+}
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            Poll::Ready(())
+        } else {
+            self.yielded = true;
+            context.waker().wake_by_ref();
+
+            Poll::Pending
+        }
+    }
+}
+
+struct LoggingListener {
+    name: &'static str,
+    log: Arc<Mutex<Vec<String>>>,
+    request: Option<AsyncDispatcherRequest>,
+}
+
+impl AsyncListener<Event> for LoggingListener {
+    fn on_event<'a>(&'a self, _event: &'a Event) -> AsyncListenerFuture<'a> {
+        Box::pin(async move {
+            self.log.lock().push(format!("{} started", self.name));
+            YieldOnce::default().await;
+            self.log.lock().push(format!("{} finished", self.name));
+
+            self.request
+        })
+    }
+}
+
+fn listener(
+    name: &'static str,
+    log: &Arc<Mutex<Vec<String>>>,
+    request: Option<AsyncDispatcherRequest>,
+) -> Arc<LoggingListener> {
+    Arc::new(LoggingListener {
+        name,
+        log: Arc::clone(log),
+        request,
+    })
+}
+
+/// **Intended test-behaviour**: A sequential dispatch awaits each listener
+/// before calling the next one, a concurrent dispatch starts all of them
+/// before any finishes.
+///
+/// **Test**: Two listeners yield once while handling an event, the log
+/// records their interleaving for both kinds of dispatch.
+#[test]
+fn sequential_and_concurrent_dispatch_interleave_differently() {
+    let dispatcher: AsyncDispatcher<Event> = AsyncDispatcher::default();
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let listener_a = listener("a", &log, None);
+    let listener_b = listener("b", &log, None);
+
+    dispatcher.add_listener(Event::Request, &listener_a);
+    dispatcher.add_listener(Event::Request, &listener_b);
+
+    block_on(dispatcher.dispatch_event(&Event::Request));
+    assert_eq!(
+        *log.lock(),
+        ["a started", "a finished", "b started", "b finished"]
+    );
+
+    log.lock().clear();
+    block_on(dispatcher.dispatch_event_concurrent(&Event::Request));
+    assert_eq!(
+        *log.lock(),
+        ["a started", "b started", "a finished", "b finished"]
+    );
+}
+
+/// **Intended test-behaviour**: Requests to stop listening are applied
+/// after the dispatch, stopping propagation only affects sequential
+/// dispatches.
+///
+/// **Test**: The first listener stops listening and propagation, the second
+/// listener is skipped once and reached by every later dispatch.
+#[test]
+fn stop_requests_apply_after_dispatch() {
+    let dispatcher: AsyncDispatcher<Event> = AsyncDispatcher::default();
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let stopping = listener(
+        "a",
+        &log,
+        Some(AsyncDispatcherRequest::StopListeningAndPropagation),
+    );
+    let staying = listener("b", &log, Some(AsyncDispatcherRequest::StopPropagation));
+
+    dispatcher.add_listener(Event::Request, &stopping);
+    let handle = dispatcher.add_listener(Event::Request, &staying);
+
+    block_on(dispatcher.dispatch_event(&Event::Request));
+    assert_eq!(*log.lock(), ["a started", "a finished"]);
+
+    log.lock().clear();
+    block_on(dispatcher.dispatch_event_concurrent(&Event::Request));
+    assert_eq!(*log.lock(), ["b started", "b finished"]);
+
+    assert!(dispatcher.remove_listener(handle));
+    log.lock().clear();
+    block_on(dispatcher.dispatch_event(&Event::Request));
+    assert!(log.lock().is_empty());
+}
+
+#[test]
+fn dispatch_futures_are_send() {
+    fn assert_send<F: Future + Send>(_: F) {}
+
+    let dispatcher: AsyncDispatcher<Event> = AsyncDispatcher::default();
+
+    assert_send(dispatcher.dispatch_event(&Event::Request));
+    assert_send(dispatcher.dispatch_event_concurrent(&Event::Request));
+}
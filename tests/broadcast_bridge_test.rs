@@ -0,0 +1,64 @@
+#![cfg(feature = "tokio")]
+
+use hey_listen::{
+    sync::{BroadcastBridge, Dispatcher},
+    RwLock,
+};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Event {
+    Save,
+    Quit,
+}
+
+/// **Intended test-behaviour**: A broadcast-bridge delivers every event to all
+/// receivers, which await them as tasks, and keeps listening while no
+/// receiver is left.
+///
+/// **Test**: Two spawned tasks await `Save` and `Quit`. Then both
+/// receivers are gone, the next dispatch keeps the bridge registered and
+/// a late receiver gets the events from then on.
+#[tokio::test]
+async fn broadcast_bridge_broadcasts_to_subscribed_tasks() {
+    let bridge = Arc::new(RwLock::new(BroadcastBridge::new(8)));
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    let handle = dispatcher.add_listener(Event::Save, &bridge);
+    dispatcher.add_listener(Event::Quit, &bridge);
+
+    let subscribers: Vec<_> = (0..2)
+        .map(|_| {
+            let mut receiver = bridge.read().subscribe();
+
+            tokio::spawn(async move {
+                let mut received = Vec::new();
+
+                while let Ok(event) = receiver.recv().await {
+                    received.push(event.clone());
+
+                    if event == Event::Quit {
+                        break;
+                    }
+                }
+
+                received
+            })
+        })
+        .collect();
+    assert_eq!(bridge.read().receiver_count(), 2);
+
+    dispatcher.dispatch_event(&Event::Save);
+    dispatcher.dispatch_event(&Event::Quit);
+
+    for subscriber in subscribers {
+        assert_eq!(subscriber.await.unwrap(), [Event::Save, Event::Quit]);
+    }
+
+    assert_eq!(bridge.read().receiver_count(), 0);
+    dispatcher.dispatch_event(&Event::Save);
+    assert!(dispatcher.has_listener(handle));
+
+    let mut late = bridge.read().subscribe();
+    dispatcher.dispatch_event(&Event::Quit);
+    assert_eq!(late.recv().await, Ok(Event::Quit));
+}
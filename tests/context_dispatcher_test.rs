@@ -0,0 +1,85 @@
+use hey_listen::{
+    rc::{ContextDispatcher, ContextListener, SyncDispatcherRequest},
+    RwLock,
+};
+use std::rc::Rc;
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum Event {
+    Click,
+    Scroll,
+}
+
+#[derive(Default)]
+struct AppState {
+    log: Vec<String>,
+    clicks: usize,
+}
+
+struct Counter;
+
+impl ContextListener<Event, AppState> for Counter {
+    fn on_event(
+        &mut self,
+        _event: &Event,
+        app_state: &mut AppState,
+    ) -> Option<SyncDispatcherRequest> {
+        app_state.clicks += 1;
+        app_state.log.push(format!("counted {}", app_state.clicks));
+
+        None
+    }
+}
+
+#[test]
+fn later_listeners_observe_earlier_mutations() {
+    let counter = Rc::new(RwLock::new(Counter));
+    let mut dispatcher = ContextDispatcher::<Event, AppState>::default();
+    let mut app_state = AppState::default();
+
+    dispatcher.add_listener(Event::Click, &counter);
+    dispatcher.add_fn(Event::Click, |_, app_state| {
+        app_state.log.push(format!("saw {}", app_state.clicks));
+
+        None
+    });
+
+    let result = dispatcher.dispatch_event_with_context(&Event::Click, &mut app_state);
+    dispatcher.dispatch_event_with_context(&Event::Click, &mut app_state);
+
+    assert_eq!(result.invoked, 2);
+    assert_eq!(app_state.log, ["counted 1", "saw 1", "counted 2", "saw 2"]);
+    assert_eq!(
+        dispatcher
+            .dispatch_event_with_context(&Event::Scroll, &mut app_state)
+            .invoked,
+        0
+    );
+}
+
+#[test]
+fn requests_and_removal_are_honoured() {
+    let counter = Rc::new(RwLock::new(Counter));
+    let dropped_counter = Rc::new(RwLock::new(Counter));
+    let mut dispatcher = ContextDispatcher::<Event, AppState>::default();
+    let mut app_state = AppState::default();
+
+    dispatcher.add_fn(Event::Click, |_, app_state| {
+        app_state.log.push("once".to_string());
+
+        Some(SyncDispatcherRequest::StopListeningAndPropagation)
+    });
+    dispatcher.add_listener(Event::Click, &dropped_counter);
+    let handle = dispatcher.add_listener(Event::Click, &counter);
+    drop(dropped_counter);
+
+    dispatcher.dispatch_event_with_context(&Event::Click, &mut app_state);
+    assert_eq!(app_state.log, ["once"]);
+
+    let result = dispatcher.dispatch_event_with_context(&Event::Click, &mut app_state);
+    assert_eq!(result.invoked, 1);
+    assert_eq!(app_state.clicks, 1);
+
+    assert!(dispatcher.remove_listener(handle));
+    assert!(!dispatcher.remove_listener(handle));
+}
@@ -0,0 +1,93 @@
+use hey_listen::{
+    sync::{DispatchResult, EventBus, Listener, SyncDispatcherRequest},
+    RwLock,
+};
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum WindowEvent {
+    Resized,
+    Closed,
+}
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum InputEvent {
+    KeyPressed,
+}
+
+#[derive(Default)]
+struct Recorder {
+    window: usize,
+    input: usize,
+}
+
+impl Listener<WindowEvent> for Recorder {
+    fn on_event(&mut self, _event: &WindowEvent) -> Option<SyncDispatcherRequest> {
+        self.window += 1;
+
+        None
+    }
+}
+
+impl Listener<InputEvent> for Recorder {
+    fn on_event(&mut self, _event: &InputEvent) -> Option<SyncDispatcherRequest> {
+        self.input += 1;
+
+        Some(SyncDispatcherRequest::StopListening)
+    }
+}
+
+/// **Intended test-behaviour**: Events reach the dispatcher of their type
+/// and variant only, unused event-types dispatch to nobody.
+///
+/// **Test**: One recorder listens to a window- and an input-event, the
+/// latter once, and the bus is shared via `Arc<Mutex<_>>`.
+#[test]
+fn routes_events_by_type_and_variant() {
+    let recorder = Arc::new(RwLock::new(Recorder::default()));
+    let bus = Arc::new(Mutex::new(EventBus::default()));
+
+    {
+        let mut bus = bus.lock().unwrap();
+        bus.add_listener(WindowEvent::Resized, &recorder);
+        bus.add_listener(InputEvent::KeyPressed, &recorder);
+    }
+
+    let mut bus = bus.lock().unwrap();
+    bus.dispatch_event(&WindowEvent::Resized);
+    bus.dispatch_event(&WindowEvent::Closed);
+    bus.dispatch_event(&InputEvent::KeyPressed);
+    bus.dispatch_event(&InputEvent::KeyPressed);
+
+    assert_eq!(recorder.read().window, 1);
+    assert_eq!(recorder.read().input, 1);
+    assert_eq!(bus.dispatch_event(&42u32), DispatchResult::default());
+}
+
+/// **Intended test-behaviour**: Per-type dispatchers can be configured
+/// directly, handles can be removed without naming their event-type.
+///
+/// **Test**: A stopping closure is registered through the bus, a second
+/// one via `dispatcher_mut`, then the first one is removed again.
+#[test]
+fn configures_and_removes_per_type() {
+    let mut bus = EventBus::default();
+    let stopper = bus.add_fn(
+        WindowEvent::Closed,
+        Box::new(|_| Some(SyncDispatcherRequest::StopPropagation)),
+    );
+    bus.dispatcher_mut::<WindowEvent>()
+        .add_fn(WindowEvent::Closed, Box::new(|_| None));
+
+    assert_eq!(
+        bus.dispatch_event(&WindowEvent::Closed),
+        DispatchResult {
+            invoked: 1,
+            stopped: true,
+        }
+    );
+
+    assert!(bus.remove_listener(stopper));
+    assert!(!bus.remove_listener(stopper));
+    assert_eq!(bus.dispatch_event(&WindowEvent::Closed).invoked, 1);
+}
@@ -0,0 +1,80 @@
+use hey_listen::{
+    rc::{FallibleDispatcher, FallibleListener, ListenerFailure, SyncDispatcherRequest},
+    RwLock,
+};
+use std::rc::Rc;
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum Command {
+    Rename,
+    Delete,
+}
+
+#[derive(Debug, PartialEq)]
+enum ValidationError {
+    EmptyName,
+    ReadOnly,
+}
+
+struct ReadOnlyGuard {
+    checked: usize,
+}
+
+impl FallibleListener<Command, ValidationError> for ReadOnlyGuard {
+    fn on_event(
+        &mut self,
+        _command: &Command,
+    ) -> Result<Option<SyncDispatcherRequest>, ListenerFailure<ValidationError>> {
+        self.checked += 1;
+
+        Err(ValidationError::ReadOnly)?
+    }
+}
+
+#[test]
+fn all_errors_are_collected_in_order() {
+    let guard = Rc::new(RwLock::new(ReadOnlyGuard { checked: 0 }));
+    let mut dispatcher = FallibleDispatcher::<Command, ValidationError>::default();
+    let called_last = Rc::new(RwLock::new(false));
+
+    dispatcher.add_fn(Command::Rename, |_| Err(ValidationError::EmptyName.into()));
+    dispatcher.add_listener(Command::Rename, &guard);
+    let last = Rc::clone(&called_last);
+    dispatcher.add_fn(Command::Rename, move |_| {
+        *last.write() = true;
+
+        Ok(None)
+    });
+
+    assert_eq!(
+        dispatcher.dispatch_event_fallible(&Command::Rename),
+        Err(vec![ValidationError::EmptyName, ValidationError::ReadOnly])
+    );
+    assert_eq!(guard.read().checked, 1);
+    assert!(*called_last.read());
+    assert_eq!(dispatcher.dispatch_event_fallible(&Command::Delete), Ok(()));
+}
+
+#[test]
+fn failing_listener_can_stop_listening_and_propagation() {
+    let mut dispatcher = FallibleDispatcher::<Command, ValidationError>::default();
+
+    dispatcher.add_fn(Command::Delete, |_| {
+        Err(ListenerFailure::with_request(
+            ValidationError::ReadOnly,
+            SyncDispatcherRequest::StopListeningAndPropagation,
+        ))
+    });
+    let handle = dispatcher.add_fn(Command::Delete, |_| Err(ValidationError::EmptyName.into()));
+
+    assert_eq!(
+        dispatcher.dispatch_event_fallible(&Command::Delete),
+        Err(vec![ValidationError::ReadOnly])
+    );
+    assert_eq!(
+        dispatcher.dispatch_event_fallible(&Command::Delete),
+        Err(vec![ValidationError::EmptyName])
+    );
+    assert!(dispatcher.remove_listener(handle));
+    assert_eq!(dispatcher.dispatch_event_fallible(&Command::Delete), Ok(()));
+}
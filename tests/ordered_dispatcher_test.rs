@@ -0,0 +1,68 @@
+use hey_listen::{
+    sync::{Listener, OrderedDispatcher, SyncDispatcherRequest},
+    RwLock,
+};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct EventId(u32);
+
+struct EventListener {
+    received: Vec<EventId>,
+}
+
+impl Listener<EventId> for EventListener {
+    fn on_event(&mut self, event: &EventId) -> Option<SyncDispatcherRequest> {
+        self.received.push(event.clone());
+
+        None
+    }
+}
+
+/// **Intended test-behaviour**: Dispatching a range reaches the listeners
+/// of every key inside the range in ascending key-order, and nothing else.
+///
+/// **Test**: We register one listener on four keys, dispatch to the range
+/// `2..=3` and expect exactly two calls.
+#[test]
+fn dispatch_range_reaches_keys_inside_range() {
+    let listener = Arc::new(RwLock::new(EventListener {
+        received: Vec::new(),
+    }));
+    let mut dispatcher = OrderedDispatcher::<EventId>::default();
+
+    for id in 1..=4 {
+        dispatcher.add_listener(EventId(id), &listener);
+    }
+
+    assert_eq!(
+        dispatcher.dispatch_range(EventId(2)..=EventId(3), &EventId(7)),
+        2
+    );
+    assert_eq!(listener.read().received, [EventId(7), EventId(7)]);
+
+    assert_eq!(dispatcher.dispatch_event(&EventId(4)), 1);
+    assert_eq!(dispatcher.dispatch_event(&EventId(5)), 0);
+}
+
+/// **Intended test-behaviour**: Stopping propagation inside a range skips
+/// all higher keys.
+///
+/// **Test**: The closure on key 2 stops propagation, hence the closure
+/// on key 3 is never called.
+#[test]
+fn stop_propagation_ends_range_dispatch() {
+    let mut dispatcher = OrderedDispatcher::<EventId>::default();
+
+    dispatcher.add_fn(EventId(1), Box::new(|_| None));
+    dispatcher.add_fn(
+        EventId(2),
+        Box::new(|_| Some(SyncDispatcherRequest::StopPropagation)),
+    );
+    let third = dispatcher.add_fn(EventId(3), Box::new(|_| None));
+
+    assert_eq!(dispatcher.dispatch_range(.., &EventId(0)), 2);
+
+    assert!(dispatcher.remove_listener(third));
+    assert!(!dispatcher.remove_listener(third));
+}
@@ -1,8 +1,8 @@
 use hey_listen::{
-    sync::{ParallelDispatcherRequest, ParallelDispatcher, ParallelListener},
+    sync::{DispatchError, ParallelDispatcher, ParallelDispatcherRequest, ParallelListener},
     RwLock,
 };
-use std::sync::Arc;
+use std::{sync::Arc, thread, time::Duration};
 
 #[derive(Clone, Eq, Hash, PartialEq)]
 enum Event {
@@ -18,7 +18,7 @@ fn dispatch_parallel_to_dyn_traits() {
     }
 
     impl ParallelListener<Event> for CountingEventListener {
-        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest> {
+        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest<Event>> {
             self.dispatch_counter += 1;
 
             None
@@ -60,7 +60,7 @@ fn dispatch_parallel_to_functions() {
     #[derive(Default)]
     struct DispatchCounter {
         counter: usize,
-    };
+    }
 
     let counter_a = Arc::new(RwLock::new(DispatchCounter::default()));
     let counter_b = Arc::new(RwLock::new(DispatchCounter::default()));
@@ -113,7 +113,7 @@ fn stop_listening_parallel_for_dyn_traits() {
     }
 
     impl ParallelListener<Event> for CountingEventListener {
-        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest> {
+        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest<Event>> {
             self.dispatch_counter += 1;
 
             Some(ParallelDispatcherRequest::StopListening)
@@ -155,7 +155,7 @@ fn stop_listening_parallel_for_fns() {
     #[derive(Default)]
     struct DispatchCounter {
         counter: usize,
-    };
+    }
 
     let counter_a = Arc::new(RwLock::new(DispatchCounter::default()));
     let counter_b = Arc::new(RwLock::new(DispatchCounter::default()));
@@ -202,6 +202,639 @@ fn stop_listening_parallel_for_fns() {
 
 #[test]
 fn is_send_and_sync() {
-    fn assert_send<T: Send + Sync>(_: &T) {};
+    fn assert_send<T: Send + Sync>(_: &T) {}
     assert_send(&ParallelDispatcher::<Event>::default());
 }
+
+#[test]
+fn dispatch_on_pool_assigned_to_event() {
+    use rayon::ThreadPoolBuilder;
+
+    #[derive(Default)]
+    struct PoolSizeListener {
+        pool_sizes: Vec<usize>,
+    }
+
+    impl ParallelListener<Event> for PoolSizeListener {
+        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest<Event>> {
+            self.pool_sizes.push(rayon::current_num_threads());
+
+            None
+        }
+    }
+
+    let mut dispatcher = ParallelDispatcher::<Event>::default();
+    dispatcher.num_threads(2).unwrap();
+
+    let pool = Arc::new(ThreadPoolBuilder::new().num_threads(3).build().unwrap());
+    dispatcher.set_pool_for(Event::VariantA, pool);
+
+    let listener = Arc::new(RwLock::new(PoolSizeListener::default()));
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.add_listener(Event::VariantB, &listener);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert_eq!(listener.read().pool_sizes, [3, 2]);
+
+    assert!(dispatcher.remove_pool_for(&Event::VariantA).is_some());
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(listener.read().pool_sizes, [3, 2, 2]);
+}
+
+/// **Intended test-behaviour**: A batch runs every event on the pool
+/// assigned to it, events without a pool run on the default one.
+///
+/// **Test**: Assign a pool of three threads to `VariantA` on a dispatcher
+/// of two threads, then dispatch both variants in one batch to listeners
+/// and closures reporting the size of the pool they run on.
+#[test]
+fn dispatch_batch_on_pools_assigned_to_events() {
+    use rayon::ThreadPoolBuilder;
+
+    #[derive(Default)]
+    struct PoolSizeListener {
+        pool_sizes: Vec<usize>,
+    }
+
+    impl ParallelListener<Event> for PoolSizeListener {
+        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest<Event>> {
+            self.pool_sizes.push(rayon::current_num_threads());
+
+            None
+        }
+    }
+
+    let mut dispatcher = ParallelDispatcher::<Event>::default();
+    dispatcher.num_threads(2).unwrap();
+
+    let pool = Arc::new(ThreadPoolBuilder::new().num_threads(3).build().unwrap());
+    dispatcher.set_pool_for(Event::VariantA, pool);
+
+    let listener_a = Arc::new(RwLock::new(PoolSizeListener::default()));
+    let listener_b = Arc::new(RwLock::new(PoolSizeListener::default()));
+    dispatcher.add_listener(Event::VariantA, &listener_a);
+    dispatcher.add_listener(Event::VariantB, &listener_b);
+
+    let fn_pool_sizes = Arc::new(RwLock::new(Vec::new()));
+
+    for event in &[Event::VariantA, Event::VariantB] {
+        let fn_pool_sizes = Arc::clone(&fn_pool_sizes);
+        let expected = if *event == Event::VariantA { 3 } else { 2 };
+
+        dispatcher.add_fn(
+            event.clone(),
+            Box::new(move |_| {
+                fn_pool_sizes
+                    .write()
+                    .push(rayon::current_num_threads() == expected);
+
+                None
+            }),
+        );
+    }
+
+    dispatcher.dispatch_events(&[Event::VariantA, Event::VariantB, Event::VariantA]);
+
+    assert_eq!(listener_a.read().pool_sizes, [3, 3]);
+    assert_eq!(listener_b.read().pool_sizes, [2]);
+    assert_eq!(*fn_pool_sizes.read(), [true, true, true]);
+}
+
+#[test]
+fn dispatch_batch_with_interleaved_events() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Default)]
+    struct CountingEventListener {
+        variant_a_counter: usize,
+        variant_b_counter: usize,
+    }
+
+    impl ParallelListener<Event> for CountingEventListener {
+        fn on_event(&mut self, event: &Event) -> Option<ParallelDispatcherRequest<Event>> {
+            match *event {
+                Event::VariantA => self.variant_a_counter += 1,
+                Event::VariantB => self.variant_b_counter += 1,
+            }
+
+            None
+        }
+    }
+
+    let mut dispatcher = ParallelDispatcher::<Event>::default();
+    let listener_a = Arc::new(RwLock::new(CountingEventListener::default()));
+    let listener_b = Arc::new(RwLock::new(CountingEventListener::default()));
+
+    dispatcher.add_listener(Event::VariantA, &listener_a);
+    dispatcher.add_listener(Event::VariantB, &listener_a);
+    dispatcher.add_listener(Event::VariantB, &listener_b);
+
+    let fn_counter = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&fn_counter);
+    dispatcher.add_fn(
+        Event::VariantB,
+        Box::new(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            Some(ParallelDispatcherRequest::StopListening)
+        }),
+    );
+
+    let batch = [
+        Event::VariantA,
+        Event::VariantB,
+        Event::VariantA,
+        Event::VariantB,
+        Event::VariantB,
+    ];
+    dispatcher.dispatch_events(&batch);
+
+    assert_eq!(listener_a.read().variant_a_counter, 2);
+    assert_eq!(listener_a.read().variant_b_counter, 3);
+    assert_eq!(listener_b.read().variant_a_counter, 0);
+    assert_eq!(listener_b.read().variant_b_counter, 3);
+    assert_eq!(fn_counter.load(Ordering::SeqCst), 3);
+
+    dispatcher.dispatch_events(&batch);
+
+    assert_eq!(listener_a.read().variant_b_counter, 6);
+    assert_eq!(listener_b.read().variant_b_counter, 6);
+    assert_eq!(fn_counter.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn dispatch_cloned_event_without_sync() {
+    use std::{
+        cell::Cell,
+        hash::{Hash, Hasher},
+    };
+
+    #[derive(Clone)]
+    struct ScratchEvent {
+        id: u32,
+        scratch: Cell<u32>,
+    }
+
+    impl Hash for ScratchEvent {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.id.hash(state);
+        }
+    }
+
+    impl PartialEq for ScratchEvent {
+        fn eq(&self, other: &ScratchEvent) -> bool {
+            self.id == other.id
+        }
+    }
+
+    impl Eq for ScratchEvent {}
+
+    #[derive(Default)]
+    struct RecordingListener {
+        scratch_values: Vec<u32>,
+    }
+
+    impl ParallelListener<ScratchEvent> for RecordingListener {
+        fn on_event(
+            &mut self,
+            event: &ScratchEvent,
+        ) -> Option<ParallelDispatcherRequest<ScratchEvent>> {
+            self.scratch_values.push(event.scratch.get());
+            event.scratch.set(0);
+
+            Some(ParallelDispatcherRequest::StopListening)
+        }
+    }
+
+    let event = ScratchEvent {
+        id: 1,
+        scratch: Cell::new(7),
+    };
+
+    let mut dispatcher = ParallelDispatcher::<ScratchEvent>::default();
+    let listener_a = Arc::new(RwLock::new(RecordingListener::default()));
+    let listener_b = Arc::new(RwLock::new(RecordingListener::default()));
+    dispatcher.add_listener(event.clone(), &listener_a);
+    dispatcher.add_listener(event.clone(), &listener_b);
+
+    dispatcher.dispatch_event_cloned(&event);
+    dispatcher.dispatch_event_cloned(&event);
+
+    assert_eq!(listener_a.read().scratch_values, [7]);
+    assert_eq!(listener_b.read().scratch_values, [7]);
+    assert_eq!(event.scratch.get(), 7);
+}
+
+#[test]
+fn timed_dispatch_reports_every_listener() {
+    struct SleepingListener;
+
+    impl ParallelListener<Event> for SleepingListener {
+        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest<Event>> {
+            std::thread::sleep(std::time::Duration::from_millis(5));
+
+            Some(ParallelDispatcherRequest::StopListening)
+        }
+    }
+
+    let mut dispatcher = ParallelDispatcher::<Event>::default();
+    let listener = Arc::new(RwLock::new(SleepingListener));
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.add_fn(Event::VariantA, Box::new(|_| None));
+
+    assert!(dispatcher.dispatch_event_timed(&Event::VariantA).is_none());
+
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.enable_timing(true);
+    let report = dispatcher.dispatch_event_timed(&Event::VariantA).unwrap();
+
+    assert_eq!(report.listeners.len(), 1);
+    assert!(report.listeners[0].1 >= std::time::Duration::from_millis(5));
+    assert_eq!(report.fns.len(), 1);
+    assert!(report.total >= report.listeners[0].1);
+
+    let report = dispatcher.dispatch_event_timed(&Event::VariantA).unwrap();
+
+    assert!(report.listeners.is_empty());
+    assert_eq!(report.fns.len(), 1);
+}
+
+#[test]
+fn dispatchers_share_one_pool() {
+    use rayon::ThreadPoolBuilder;
+
+    let pool = Arc::new(
+        ThreadPoolBuilder::new()
+            .num_threads(2)
+            .thread_name(|index| format!("shared-{}", index))
+            .build()
+            .unwrap(),
+    );
+    let thread_names = Arc::new(RwLock::new(Vec::new()));
+
+    let mut dispatchers = vec![
+        ParallelDispatcher::<Event>::with_pool(Arc::clone(&pool)),
+        ParallelDispatcher::<Event>::with_pool(Arc::clone(&pool)),
+    ];
+
+    for dispatcher in &mut dispatchers {
+        let thread_names = Arc::clone(&thread_names);
+
+        dispatcher.add_fn(
+            Event::VariantA,
+            Box::new(move |_| {
+                let name = std::thread::current()
+                    .name()
+                    .unwrap_or_default()
+                    .to_string();
+                thread_names.write().push(name);
+
+                None
+            }),
+        );
+    }
+
+    for _ in 0..3 {
+        for dispatcher in &mut dispatchers {
+            dispatcher.dispatch_event(&Event::VariantA);
+        }
+    }
+
+    let thread_names = thread_names.read();
+    assert_eq!(thread_names.len(), 6);
+    assert!(thread_names.iter().all(|name| name.starts_with("shared-")));
+}
+
+#[test]
+fn batch_reaches_listener_in_batch_order() {
+    use std::hash::{Hash, Hasher};
+
+    #[derive(Clone)]
+    struct SequencedEvent {
+        kind: u8,
+        sequence: u32,
+    }
+
+    impl Hash for SequencedEvent {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.kind.hash(state);
+        }
+    }
+
+    impl PartialEq for SequencedEvent {
+        fn eq(&self, other: &SequencedEvent) -> bool {
+            self.kind == other.kind
+        }
+    }
+
+    impl Eq for SequencedEvent {}
+
+    #[derive(Default)]
+    struct RecordingListener {
+        sequences: Vec<u32>,
+    }
+
+    impl ParallelListener<SequencedEvent> for RecordingListener {
+        fn on_event(
+            &mut self,
+            event: &SequencedEvent,
+        ) -> Option<ParallelDispatcherRequest<SequencedEvent>> {
+            self.sequences.push(event.sequence);
+
+            None
+        }
+    }
+
+    let mut dispatcher = ParallelDispatcher::<SequencedEvent>::default();
+    let listener_even = Arc::new(RwLock::new(RecordingListener::default()));
+    let listener_odd = Arc::new(RwLock::new(RecordingListener::default()));
+
+    dispatcher.add_listener(
+        SequencedEvent {
+            kind: 0,
+            sequence: 0,
+        },
+        &listener_even,
+    );
+    dispatcher.add_listener(
+        SequencedEvent {
+            kind: 1,
+            sequence: 0,
+        },
+        &listener_odd,
+    );
+
+    let batch: Vec<SequencedEvent> = (0..1000)
+        .map(|sequence| SequencedEvent {
+            kind: (sequence % 2) as u8,
+            sequence,
+        })
+        .collect();
+    dispatcher.dispatch_events(&batch);
+
+    let expected_even: Vec<u32> = (0..1000).filter(|sequence| sequence % 2 == 0).collect();
+    let expected_odd: Vec<u32> = (0..1000).filter(|sequence| sequence % 2 == 1).collect();
+
+    assert_eq!(listener_even.read().sequences, expected_even);
+    assert_eq!(listener_odd.read().sequences, expected_odd);
+}
+
+#[test]
+fn follow_up_events_are_dispatched_after_join() {
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    enum Step {
+        Start,
+        Next(u8),
+    }
+
+    let mut dispatcher = ParallelDispatcher::<Step>::default();
+    let order = Arc::new(RwLock::new(Vec::new()));
+
+    let recorded_order = Arc::clone(&order);
+    dispatcher.add_fn(
+        Step::Start,
+        Box::new(move |_| {
+            recorded_order.write().push(Step::Start);
+
+            Some(ParallelDispatcherRequest::DispatchEvent(Step::Next(1)))
+        }),
+    );
+
+    let recorded_order = Arc::clone(&order);
+    dispatcher.add_fn(
+        Step::Next(1),
+        Box::new(move |_| {
+            recorded_order.write().push(Step::Next(1));
+
+            Some(ParallelDispatcherRequest::DispatchEvent(Step::Next(2)))
+        }),
+    );
+
+    let recorded_order = Arc::clone(&order);
+    dispatcher.add_fn(
+        Step::Next(2),
+        Box::new(move |_| {
+            recorded_order.write().push(Step::Next(2));
+
+            Some(ParallelDispatcherRequest::DispatchEvent(Step::Start))
+        }),
+    );
+
+    dispatcher.set_max_dispatch_depth(4);
+
+    assert_eq!(
+        dispatcher.try_dispatch_event(&Step::Start),
+        Err(DispatchError::MaxDepthExceeded)
+    );
+    assert_eq!(
+        *order.read(),
+        vec![
+            Step::Start,
+            Step::Next(1),
+            Step::Next(2),
+            Step::Start,
+            Step::Next(1),
+        ]
+    );
+
+    order.write().clear();
+    dispatcher.set_max_dispatch_depth(0);
+    dispatcher.dispatch_event(&Step::Next(2));
+
+    assert_eq!(*order.read(), vec![Step::Next(2)]);
+}
+
+/// **Intended test-behaviour**: One listener shared by many event-variants
+/// is never called concurrently, no update of it gets lost.
+///
+/// **Test**: A listener increments its counter in a racy read-yield-write
+/// sequence and is registered for 64 event-variants, all of them are
+/// dispatched in parallel batches on four threads.
+#[test]
+fn shared_listener_calls_are_serialised() {
+    struct RacyCounter {
+        count: usize,
+    }
+
+    impl ParallelListener<u32> for RacyCounter {
+        fn on_event(&mut self, _event: &u32) -> Option<ParallelDispatcherRequest<u32>> {
+            let count = self.count;
+            std::thread::yield_now();
+            self.count = count + 1;
+
+            None
+        }
+    }
+
+    let listener = Arc::new(RwLock::new(RacyCounter { count: 0 }));
+    let mut dispatcher = ParallelDispatcher::<u32>::default();
+    dispatcher.num_threads(4).unwrap();
+
+    let keys: Vec<u32> = (0..64).collect();
+    for key in &keys {
+        dispatcher.add_listener(*key, &listener);
+    }
+
+    for _ in 0..10 {
+        dispatcher.dispatch_events(&keys);
+    }
+    for key in &keys {
+        dispatcher.dispatch_event(key);
+    }
+
+    assert_eq!(listener.read().count, 11 * keys.len());
+}
+
+/// **Intended test-behaviour**: Once the time budget is used up no further
+/// listeners are started, started ones finish.
+///
+/// **Test**: Ten closures sleeping 100ms run on one thread with a budget
+/// of 250ms, a budget of zero skips all of them and an ample budget none.
+#[test]
+fn dispatch_event_timeout_skips_listeners_beyond_budget() {
+    let mut dispatcher = ParallelDispatcher::<Event>::default();
+    dispatcher.num_threads(1).unwrap();
+
+    for _ in 0..10 {
+        dispatcher.add_fn(
+            Event::VariantA,
+            Box::new(|_| {
+                thread::sleep(Duration::from_millis(100));
+
+                None
+            }),
+        );
+    }
+    dispatcher.add_fn(Event::VariantB, Box::new(|_| None));
+
+    let outcome = dispatcher.dispatch_event_timeout(&Event::VariantA, Duration::from_millis(250));
+    assert!((1..10).contains(&outcome.completed));
+    assert_eq!(outcome.completed + outcome.skipped(), 10);
+    assert_eq!(
+        outcome.skipped_fns,
+        (outcome.completed..10).collect::<Vec<_>>()
+    );
+    assert!(outcome.skipped_listeners.is_empty());
+
+    let outcome = dispatcher.dispatch_event_timeout(&Event::VariantA, Duration::from_secs(0));
+    assert_eq!(outcome.completed, 0);
+    assert_eq!(outcome.skipped(), 10);
+
+    let outcome = dispatcher.dispatch_event_timeout(&Event::VariantB, Duration::from_secs(60));
+    assert_eq!(outcome.completed, 1);
+    assert_eq!(outcome.skipped(), 0);
+}
+
+/// **Intended test-behaviour**: Skipped indices refer to the listeners left
+/// after the dispatch, which keep their registration-order.
+///
+/// **Test**: On one thread, the first of four closures stops listening,
+/// the second sleeps beyond the budget of 50ms, hence the last two are
+/// skipped. They are reported at their indices after removing the first
+/// closure, dispatching again calls the remaining ones in order.
+#[test]
+fn dispatch_event_timeout_reports_indices_after_removal() {
+    let mut dispatcher = ParallelDispatcher::<Event>::default();
+    dispatcher.num_threads(1).unwrap();
+    let calls = Arc::new(RwLock::new(Vec::new()));
+
+    for id in 0..4 {
+        let calls = Arc::clone(&calls);
+
+        dispatcher.add_fn(
+            Event::VariantA,
+            Box::new(move |_| {
+                calls.write().push(id);
+
+                match id {
+                    0 => Some(ParallelDispatcherRequest::StopListening),
+                    1 => {
+                        thread::sleep(Duration::from_millis(100));
+
+                        None
+                    }
+                    _ => None,
+                }
+            }),
+        );
+    }
+
+    let outcome = dispatcher.dispatch_event_timeout(&Event::VariantA, Duration::from_millis(50));
+    assert_eq!(outcome.completed, 2);
+    assert_eq!(outcome.skipped_fns, [1, 2]);
+
+    calls.write().clear();
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(*calls.read(), [1, 2, 3]);
+}
+
+/// **Intended test-behaviour**: Follow-up events dropped for exceeding the
+/// maximal dispatch-depth are reported by the outcome.
+///
+/// **Test**: Two closures keep requesting each other's events, the outcome
+/// reports the exceeded depth, a single closure does not.
+#[test]
+fn dispatch_event_timeout_reports_exceeded_depth() {
+    let mut dispatcher = ParallelDispatcher::<Event>::default();
+    dispatcher.set_max_dispatch_depth(4);
+    dispatcher.add_fn(
+        Event::VariantA,
+        Box::new(|_| Some(ParallelDispatcherRequest::DispatchEvent(Event::VariantB))),
+    );
+
+    let outcome = dispatcher.dispatch_event_timeout(&Event::VariantA, Duration::from_secs(60));
+    assert!(!outcome.max_depth_exceeded);
+
+    dispatcher.add_fn(
+        Event::VariantB,
+        Box::new(|_| Some(ParallelDispatcherRequest::DispatchEvent(Event::VariantA))),
+    );
+
+    let outcome = dispatcher.dispatch_event_timeout(&Event::VariantA, Duration::from_secs(60));
+    assert_eq!(outcome.completed, 1);
+    assert!(outcome.max_depth_exceeded);
+}
+
+/// **Intended test-behaviour**: A sequential dispatcher converted from a
+/// parallel one keeps calling its listeners and queues follow-up events.
+///
+/// **Test**: Convert a dispatcher with one listener, one dropped listener
+/// and one closure requesting a follow-up, then dispatch and poll.
+#[test]
+fn into_sequential_keeps_listeners_and_follow_ups() {
+    #[derive(Default)]
+    struct CountingEventListener {
+        dispatch_counter: usize,
+    }
+
+    impl ParallelListener<Event> for CountingEventListener {
+        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest<Event>> {
+            self.dispatch_counter += 1;
+
+            None
+        }
+    }
+
+    let mut parallel = ParallelDispatcher::<Event>::default();
+    let listener = Arc::new(RwLock::new(CountingEventListener::default()));
+    let dropped = Arc::new(RwLock::new(CountingEventListener::default()));
+
+    parallel.add_listener(Event::VariantA, &listener);
+    parallel.add_listener(Event::VariantA, &dropped);
+    parallel.add_fn(
+        Event::VariantA,
+        Box::new(|_| Some(ParallelDispatcherRequest::DispatchEvent(Event::VariantB))),
+    );
+    parallel.add_listener(Event::VariantB, &listener);
+    drop(dropped);
+
+    let mut sequential = parallel.into_sequential();
+    let result = sequential.dispatch_event_with_result(&Event::VariantA);
+
+    assert_eq!(result.invoked, 3);
+    assert_eq!(listener.read().dispatch_counter, 1);
+
+    assert_eq!(sequential.poll(), 1);
+    assert_eq!(listener.read().dispatch_counter, 2);
+}
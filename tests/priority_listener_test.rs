@@ -1,5 +1,5 @@
 use hey_listen::{
-    sync::{Listener, PriorityDispatcher, SyncDispatcherRequest},
+    sync::{Cancelable, DispatchReport, Listener, PriorityDispatcher, SyncDispatcherRequest},
     RwLock,
 };
 use std::sync::Arc;
@@ -260,6 +260,477 @@ fn stop_listening_and_propagation_of_fns() {
 
 #[test]
 fn is_send_and_sync() {
-    fn assert_send<T: Send + Sync>(_: &T) {};
+    fn assert_send<T: Send + Sync>(_: &T) {}
     assert_send(&PriorityDispatcher::<u32, Event>::default());
 }
+
+/// **Intended test-behaviour**: Bumping a listener moves it by the given
+/// amount of priority-levels, behind listeners already at that level.
+///
+/// **Test**: We register three listeners on different levels, bump the
+/// last one ahead onto the first level and expect it to be dispatched
+/// second. Bumping out of `u32`'s range or with an unknown handle fails.
+#[test]
+fn bump_priority_moves_listener() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let new_listener = |name: &str| {
+        Arc::new(RwLock::new(EventListener {
+            name: name.to_string(),
+            name_record: Arc::clone(&names_record),
+        }))
+    };
+
+    let receiver_a = new_listener("a");
+    let receiver_b = new_listener("b");
+    let receiver_c = new_listener("c");
+
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    let handle_a = dispatcher.add_listener(Event::EventType, &receiver_a, 0);
+    dispatcher.add_listener(Event::EventType, &receiver_b, 1);
+    let handle_c = dispatcher.add_listener(Event::EventType, &receiver_c, 2);
+
+    assert!(dispatcher.bump_priority(handle_c, -2));
+    assert!(!dispatcher.bump_priority(handle_a, -1));
+
+    let mut other_dispatcher = PriorityDispatcher::<u32, Event>::default();
+    assert!(!other_dispatcher.bump_priority(handle_a, 1));
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.read(), ["a", "c", "b"]);
+}
+
+/// **Intended test-behaviour**: Dispatching until handled tries listeners
+/// by priority-level and stops at the first one handling the event.
+///
+/// **Test**: Three closures on different levels record their name,
+/// the second one handles the event, hence the third is never called.
+/// Without any handling listener, `false` is returned.
+#[test]
+fn dispatch_until_handled_stops_at_handler() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    for &(priority, handles) in &[(0, false), (1, true), (2, false)] {
+        let record = Arc::clone(&names_record);
+
+        dispatcher.add_fn(
+            Event::EventType,
+            Box::new(move |_| {
+                record.write().push(priority);
+
+                if handles {
+                    Some(SyncDispatcherRequest::Handled)
+                } else {
+                    None
+                }
+            }),
+            priority,
+        );
+    }
+
+    assert!(dispatcher.dispatch_until_handled(&Event::EventType));
+    assert_eq!(*names_record.read(), [0, 1]);
+
+    let mut unhandled = PriorityDispatcher::<u32, Event>::default();
+    unhandled.add_fn(Event::EventType, Box::new(|_| None), 0);
+
+    assert!(!unhandled.dispatch_until_handled(&Event::EventType));
+}
+
+/// **Intended test-behaviour**: A front-handle is dispatched to first for
+/// one dispatch only, without being called twice.
+///
+/// **Test**: We register three listeners on ascending levels and dispatch
+/// with the last one in front, then dispatch normally again.
+#[test]
+fn dispatch_with_front_calls_focused_listener_first() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let new_listener = |name: &str| {
+        Arc::new(RwLock::new(EventListener {
+            name: name.to_string(),
+            name_record: Arc::clone(&names_record),
+        }))
+    };
+
+    let receiver_a = new_listener("a");
+    let receiver_b = new_listener("b");
+    let receiver_c = new_listener("c");
+
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    dispatcher.add_listener(Event::EventType, &receiver_a, 0);
+    dispatcher.add_listener(Event::EventType, &receiver_b, 1);
+    let focused = dispatcher.add_listener(Event::EventType, &receiver_c, 2);
+
+    assert_eq!(
+        dispatcher.dispatch_event_with_front(&Event::EventType, focused),
+        3
+    );
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.read(), ["c", "a", "b", "a", "b", "c"]);
+}
+
+/// **Intended test-behaviour**: Restoring a snapshot puts back all
+/// registrations with their priority-levels, keeping listeners alive
+/// that are only held by the snapshot.
+///
+/// **Test**: We register listeners `1` and `2` on different levels and
+/// take a snapshot, then drop our `Arc` of `2`, remove `1` and register
+/// `3`. After restoring, we expect `1` and `2` in priority-order.
+#[test]
+fn restore_snapshot_with_priorities() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let new_listener = |name: &str| {
+        Arc::new(RwLock::new(EventListener {
+            name: name.to_string(),
+            name_record: Arc::clone(&names_record),
+        }))
+    };
+    let listener_1 = new_listener("1");
+    let listener_2 = new_listener("2");
+    let listener_3 = new_listener("3");
+
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    dispatcher.add_listener(Event::EventType, &listener_2, 2);
+    let handle_1 = dispatcher.add_listener(Event::EventType, &listener_1, 1);
+    let snapshot = dispatcher.snapshot();
+
+    drop(listener_2);
+    assert!(dispatcher.remove_listener(handle_1));
+    dispatcher.add_listener(Event::EventType, &listener_3, 0);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(*names_record.read(), ["3", "2"]);
+
+    names_record.write().clear();
+    dispatcher.restore(&snapshot);
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.read(), ["1", "2"]);
+}
+
+/// **Intended test-behaviour**: Dispatching a single priority-level only
+/// reaches the listeners of exactly that level.
+///
+/// **Test**: Three listeners on two levels are stepped through level by
+/// level, the record-book grows one level at a time.
+#[test]
+fn dispatch_tier_reaches_only_one_level() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let new_listener = |name: &str| {
+        Arc::new(RwLock::new(EventListener {
+            name: name.to_string(),
+            name_record: Arc::clone(&names_record),
+        }))
+    };
+    let listener_1a = new_listener("1a");
+    let listener_1b = new_listener("1b");
+    let listener_2 = new_listener("2");
+
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    dispatcher.add_listener(Event::EventType, &listener_2, 2);
+    dispatcher.add_listener(Event::EventType, &listener_1a, 1);
+    dispatcher.add_listener(Event::EventType, &listener_1b, 1);
+
+    assert_eq!(dispatcher.dispatch_tier(&Event::EventType, &2), 1);
+    assert_eq!(*names_record.read(), ["2"]);
+
+    assert_eq!(dispatcher.dispatch_tier(&Event::EventType, &1), 2);
+    assert_eq!(*names_record.read(), ["2", "1a", "1b"]);
+
+    assert_eq!(dispatcher.dispatch_tier(&Event::EventType, &0), 0);
+    assert_eq!(names_record.read().len(), 3);
+}
+
+/// **Intended test-behaviour**: Dispatching from a priority-level skips all
+/// earlier levels but reaches all later ones.
+///
+/// **Test**: Three listeners on three levels, dispatching from the middle
+/// level records the middle and the last listener.
+#[test]
+fn dispatch_from_tier_skips_earlier_levels() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let new_listener = |name: &str| {
+        Arc::new(RwLock::new(EventListener {
+            name: name.to_string(),
+            name_record: Arc::clone(&names_record),
+        }))
+    };
+    let listener_1 = new_listener("1");
+    let listener_2 = new_listener("2");
+    let listener_3 = new_listener("3");
+
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    dispatcher.add_listener(Event::EventType, &listener_3, 3);
+    dispatcher.add_listener(Event::EventType, &listener_1, 1);
+    dispatcher.add_listener(Event::EventType, &listener_2, 2);
+
+    assert_eq!(dispatcher.dispatch_from_tier(&Event::EventType, &2), 2);
+    assert_eq!(*names_record.read(), ["2", "3"]);
+}
+
+/// **Intended test-behaviour**: A panicking listener neither silences the
+/// listeners after it on its priority-level nor the following levels.
+///
+/// **Test**: A closure on level 1 panics after two recording listeners of
+/// the same level, listeners being called before closures. A third
+/// recording listener sits on level 2. All three record and the panic is
+/// reported by level and calling-position.
+#[test]
+fn dispatch_catching_continues_after_panic() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let new_listener = |name: &str| {
+        Arc::new(RwLock::new(EventListener {
+            name: name.to_string(),
+            name_record: Arc::clone(&names_record),
+        }))
+    };
+    let listener_1a = new_listener("1a");
+    let listener_1b = new_listener("1b");
+    let listener_2 = new_listener("2");
+
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    dispatcher.add_listener(Event::EventType, &listener_1a, 1);
+    dispatcher.add_fn(
+        Event::EventType,
+        Box::new(|_| panic!("misbehaving listener")),
+        1,
+    );
+    dispatcher.add_listener(Event::EventType, &listener_1b, 1);
+    dispatcher.add_listener(Event::EventType, &listener_2, 2);
+
+    assert_eq!(
+        dispatcher.dispatch_event_catching(&Event::EventType),
+        [(1, 2)]
+    );
+    assert_eq!(*names_record.read(), ["1a", "1b", "2"]);
+}
+
+/// **Intended test-behaviour**: A disabled listener is skipped by every
+/// kind of dispatch and keeps its priority-level and position once it is
+/// enabled again.
+///
+/// **Test**: Three listeners on two levels, the first one is disabled for
+/// a regular dispatch and as front of a dispatch, then enabled again.
+#[test]
+fn disabled_listener_keeps_its_position() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let new_listener = |name: &str| {
+        Arc::new(RwLock::new(EventListener {
+            name: name.to_string(),
+            name_record: Arc::clone(&names_record),
+        }))
+    };
+    let overlay = new_listener("overlay");
+    let receiver_a = new_listener("a");
+    let receiver_b = new_listener("b");
+
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    let handle = dispatcher.add_listener(Event::EventType, &overlay, 0);
+    dispatcher.add_listener(Event::EventType, &receiver_a, 0);
+    dispatcher.add_listener(Event::EventType, &receiver_b, 1);
+
+    assert!(dispatcher.set_listener_enabled(handle, false));
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(
+        dispatcher.dispatch_event_with_front(&Event::EventType, handle),
+        2
+    );
+
+    assert!(dispatcher.set_listener_enabled(handle, true));
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(
+        *names_record.read(),
+        ["a", "b", "a", "b", "overlay", "a", "b"]
+    );
+
+    assert!(dispatcher.remove_listener(handle));
+    assert!(!dispatcher.set_listener_enabled(handle, true));
+}
+
+/// **Intended test-behaviour**: Cancelling an event stopping on cancel
+/// skips all remaining listeners, across priority-levels.
+///
+/// **Test**: Listeners on three levels, the second level cancels. Without
+/// stopping on cancel, the third level is reached nonetheless.
+#[test]
+fn cancelable_events_stop_across_levels() {
+    let names_record = Arc::new(RwLock::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, Cancelable<Event>>::default();
+    let key = Cancelable::new(Event::EventType, true);
+
+    for (name, priority) in &[("1", 1), ("2", 2), ("3", 3)] {
+        let names_record = Arc::clone(&names_record);
+
+        dispatcher.add_fn(
+            key.clone(),
+            Box::new(move |event: &Cancelable<Event>| {
+                names_record.write().push(name.to_string());
+
+                if *name == "2" {
+                    event.cancel();
+                }
+
+                None
+            }),
+            *priority,
+        );
+    }
+
+    assert!(dispatcher.dispatch_cancelable(&Cancelable::new(Event::EventType, true)));
+    assert_eq!(*names_record.read(), ["1", "2"]);
+
+    names_record.write().clear();
+    assert!(dispatcher.dispatch_cancelable(&Cancelable::new(Event::EventType, false)));
+    assert_eq!(*names_record.read(), ["1", "2", "3"]);
+}
+
+/// **Intended test-behaviour**: The report lists every listener that
+/// stopped listening during the dispatch, across priority-levels.
+///
+/// **Test**: Over three levels, one closure stops listening, one stops
+/// listening and propagation, the one after is never reached.
+#[test]
+fn report_lists_self_removed_listeners() {
+    let mut dispatcher: PriorityDispatcher<u32, Event> = PriorityDispatcher::default();
+    let once = dispatcher.add_fn(
+        Event::EventType,
+        Box::new(|_| Some(SyncDispatcherRequest::StopListening)),
+        0,
+    );
+    dispatcher.add_fn(Event::EventType, Box::new(|_| None), 0);
+    let last = dispatcher.add_fn(
+        Event::EventType,
+        Box::new(|_| Some(SyncDispatcherRequest::StopListeningAndPropagation)),
+        1,
+    );
+    dispatcher.add_fn(Event::EventType, Box::new(|_| None), 2);
+
+    assert_eq!(
+        dispatcher.dispatch_event_report(&Event::EventType),
+        DispatchReport {
+            invoked: 3,
+            removed: vec![once, last],
+            stopped: true,
+        }
+    );
+    assert_eq!(
+        dispatcher.dispatch_event_report(&Event::EventType),
+        DispatchReport {
+            invoked: 2,
+            removed: vec![],
+            stopped: false,
+        }
+    );
+}
+
+/// **Intended test-behaviour**: The priority-dispatcher calls
+/// `on_subscribe` on registration and `on_unsubscribe` once a
+/// registration is removed or stops listening.
+///
+/// **Test**: A widget registers on two levels, stops listening on its
+/// first dispatch and its remaining registration is removed explicitly.
+#[test]
+fn lifecycle_hooks_fire_on_registration_and_removal() {
+    #[derive(Default)]
+    struct Widget {
+        subscriptions: usize,
+        unsubscriptions: usize,
+    }
+
+    impl Listener<Event> for Widget {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            Some(SyncDispatcherRequest::StopListeningAndPropagation)
+        }
+
+        fn on_subscribe(&mut self) {
+            self.subscriptions += 1;
+        }
+
+        fn on_unsubscribe(&mut self) {
+            self.unsubscriptions += 1;
+        }
+    }
+
+    let widget = Arc::new(RwLock::new(Widget::default()));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    dispatcher.add_listener(Event::EventType, &widget, 1);
+    let remaining = dispatcher.add_listener(Event::EventType, &widget, 2);
+    assert_eq!(widget.read().subscriptions, 2);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(widget.read().unsubscriptions, 1);
+
+    assert!(dispatcher.remove_listener(remaining));
+    assert_eq!(widget.read().unsubscriptions, 2);
+}
+
+/// **Intended test-behaviour**: A redirect with inherited priority
+/// dispatches the refined event from the redirecting priority-level on,
+/// after that level is done, and ends the dispatch of the original event.
+///
+/// **Test**: Level 0 records every event, a level 1 `Fn` refines `Raw`
+/// into `Refined`, level 2 records every event. We expect level 0 to
+/// only see `Raw`, the rest of level 1 to see `Raw` and level 2 to only
+/// see `Refined`. Without inheriting, level 0 sees `Refined` as well.
+#[test]
+fn redirect_inheriting_priority_skips_earlier_levels() {
+    use hey_listen::sync::PriorityDispatcherRequest;
+
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum Input {
+        Raw,
+        Refined,
+    }
+
+    type RecordingFn = Box<dyn Fn(&Input) -> Option<SyncDispatcherRequest> + Send + Sync>;
+
+    fn recording(record: &Arc<RwLock<Vec<(u32, Input)>>>, level: u32) -> RecordingFn {
+        let record = Arc::clone(record);
+
+        Box::new(move |event| {
+            record.write().push((level, event.clone()));
+
+            None
+        })
+    }
+
+    for inherit_priority in [true, false] {
+        let record = Arc::new(RwLock::new(Vec::new()));
+        let mut dispatcher = PriorityDispatcher::<u32, Input>::default();
+
+        for event in [Input::Raw, Input::Refined] {
+            dispatcher.add_fn(event.clone(), recording(&record, 0), 0);
+            dispatcher.add_fn(event, recording(&record, 2), 2);
+        }
+
+        dispatcher.add_redirecting_fn(
+            Input::Raw,
+            Box::new(move |_| {
+                Some(PriorityDispatcherRequest::Redirect {
+                    event: Input::Refined,
+                    inherit_priority,
+                })
+            }),
+            1,
+        );
+        dispatcher.add_fn(Input::Raw, recording(&record, 1), 1);
+
+        dispatcher.dispatch_event(&Input::Raw);
+
+        let expected: &[(u32, Input)] = if inherit_priority {
+            &[(0, Input::Raw), (1, Input::Raw), (2, Input::Refined)]
+        } else {
+            &[
+                (0, Input::Raw),
+                (1, Input::Raw),
+                (0, Input::Refined),
+                (2, Input::Refined),
+            ]
+        };
+        assert_eq!(*record.read(), expected);
+    }
+}
@@ -0,0 +1,1554 @@
+use hey_listen::{
+    rc::{
+        Coalesce, DispatchResult, Dispatcher, EnqueueError, Listener, MutListener, OverflowPolicy,
+        PriorityDispatcher, RcDispatcherRequest, ScheduledDispatcher, SyncDispatcherRequest,
+    },
+    RwLock,
+};
+use std::{
+    cell::{Cell, RefCell},
+    hash::{Hash, Hasher},
+    rc::Rc,
+    time::Duration,
+};
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum Event {
+    EventType,
+    Emitted,
+}
+
+struct EventListener {
+    name: String,
+    name_record: Rc<RwLock<Vec<String>>>,
+}
+
+impl Listener<Event> for EventListener {
+    fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+        self.name_record.write().push(self.name.clone());
+
+        None
+    }
+}
+
+fn new_listener(name: &str, name_record: &Rc<RwLock<Vec<String>>>) -> Rc<RwLock<EventListener>> {
+    Rc::new(RwLock::new(EventListener {
+        name: name.to_string(),
+        name_record: Rc::clone(name_record),
+    }))
+}
+
+/// **Intended test-behaviour**: Removing a listener via its handle drops
+/// exactly that registration and keeps the order of the others.
+///
+/// **Test**: We register four listeners, remove the second one and expect
+/// the remaining three to be dispatched in registration-order.
+/// Removing the same handle again fails.
+#[test]
+fn remove_listener_preserves_order() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let listeners: Vec<_> = ["a", "b", "c", "d"]
+        .iter()
+        .map(|name| new_listener(name, &names_record))
+        .collect();
+
+    let dispatcher = Dispatcher::<Event>::default();
+    let handles: Vec<_> = listeners
+        .iter()
+        .map(|listener| dispatcher.add_listener(Event::EventType, listener))
+        .collect();
+
+    assert!(dispatcher.remove_listener(handles[1]));
+    assert!(!dispatcher.remove_listener(handles[1]));
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.read(), ["a", "c", "d"]);
+}
+
+#[test]
+fn remove_listener_by_priority() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let listener_a = new_listener("a", &names_record);
+    let listener_b = new_listener("b", &names_record);
+
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    let handle_a = dispatcher.add_listener(Event::EventType, &listener_a, 0);
+    dispatcher.add_listener(Event::EventType, &listener_b, 1);
+    let fn_handle = dispatcher.add_fn(Event::EventType, |_| None, 2);
+
+    assert!(dispatcher.remove_listener(handle_a));
+    assert!(dispatcher.remove_listener(fn_handle));
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.read(), ["b"]);
+}
+
+/// **Intended test-behaviour**: Stateful closures fire in priority-order,
+/// no matter their registration-order.
+///
+/// **Test**: The same closure-factory is registered on three priorities,
+/// each closure counts its calls and stops propagation on its second call,
+/// hence the second dispatch stops at the highest priority.
+#[test]
+fn closures_fire_in_priority_order() {
+    let record = Rc::new(RwLock::new(Vec::new()));
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+
+    for priority in &[2, 0, 1] {
+        let record = Rc::clone(&record);
+        let priority = *priority;
+        let mut calls = 0;
+
+        dispatcher.add_fn(
+            Event::EventType,
+            move |_| {
+                calls += 1;
+                record.write().push((priority, calls));
+
+                if calls > 1 {
+                    Some(SyncDispatcherRequest::StopPropagation)
+                } else {
+                    None
+                }
+            },
+            priority,
+        );
+    }
+
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(*record.read(), [(0, 1), (1, 1), (2, 1)]);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(*record.read(), [(0, 1), (1, 1), (2, 1), (0, 2)]);
+}
+
+/// **Intended test-behaviour**: Closures and listeners are dispatched
+/// in registration-order and honour the same requests.
+///
+/// **Test**: We alternate between listeners and closures, one closure
+/// stops listening and another stops propagation, hence the second
+/// dispatch stops earlier and skips the removed closure.
+#[test]
+fn closures_interleave_with_listeners() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let listener_a = new_listener("a", &names_record);
+    let listener_c = new_listener("c", &names_record);
+    let listener_e = new_listener("e", &names_record);
+
+    let dispatcher = Dispatcher::<Event>::default();
+    dispatcher.add_listener(Event::EventType, &listener_a);
+
+    let record = Rc::clone(&names_record);
+    dispatcher.add_fn(Event::EventType, move |_| {
+        record.write().push("b".to_string());
+
+        Some(SyncDispatcherRequest::StopListening)
+    });
+
+    dispatcher.add_listener(Event::EventType, &listener_c);
+
+    let record = Rc::clone(&names_record);
+    let mut calls = 0;
+    dispatcher.add_fn(Event::EventType, move |_| {
+        calls += 1;
+        record.write().push(format!("d{}", calls));
+
+        if calls == 2 {
+            Some(SyncDispatcherRequest::StopPropagation)
+        } else {
+            None
+        }
+    });
+
+    dispatcher.add_listener(Event::EventType, &listener_e);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(
+        *names_record.read(),
+        ["a", "b", "c", "d1", "e", "a", "c", "d2"]
+    );
+}
+
+/// **Intended test-behaviour**: The dispatcher only keeps weak references,
+/// dropping the last `Rc` to a listener ends its registration.
+///
+/// **Test**: We drop one of two listeners, dispatch and expect only the
+/// remaining one to receive the event. As the dropped listener has been
+/// removed during dispatch, its handle is no longer registered.
+#[test]
+fn dropped_listener_is_skipped_and_removed() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let kept_widget = new_listener("kept", &names_record);
+    let dropped_widget = new_listener("dropped", &names_record);
+
+    let dispatcher = Dispatcher::<Event>::default();
+    let dropped_handle = dispatcher.add_listener(Event::EventType, &dropped_widget);
+    dispatcher.add_listener(Event::EventType, &kept_widget);
+
+    drop(dropped_widget);
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.read(), ["kept"]);
+    assert!(!dispatcher.remove_listener(dropped_handle));
+}
+
+/// **Intended test-behaviour**: One-shot listeners run on their first
+/// reached dispatch only, but survive dispatches stopped before them.
+///
+/// **Test**: A closure stops propagation on the first dispatch, so the
+/// one-shot closure and listener behind it are first called on the second
+/// dispatch and removed afterwards.
+#[test]
+fn once_listeners_survive_stopped_dispatch() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let listener = new_listener("listener", &names_record);
+    let dispatcher = Dispatcher::<Event>::default();
+
+    let mut first_dispatch = true;
+    dispatcher.add_fn(Event::EventType, move |_| {
+        if first_dispatch {
+            first_dispatch = false;
+
+            Some(SyncDispatcherRequest::StopPropagation)
+        } else {
+            None
+        }
+    });
+
+    let record = Rc::clone(&names_record);
+    let pending_action = String::from("confirmed");
+    dispatcher.add_fn_once(Event::EventType, move |_| {
+        record.write().push(pending_action);
+
+        None
+    });
+    let listener_handle = dispatcher.add_listener_once(Event::EventType, &listener);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    assert!(names_record.read().is_empty());
+
+    dispatcher.dispatch_event(&Event::EventType);
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.read(), ["confirmed", "listener"]);
+    assert!(!dispatcher.remove_listener(listener_handle));
+}
+
+#[test]
+fn dispatch_result_tells_unrouted_from_ignored() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let listener = new_listener("a", &names_record);
+    let dispatcher = Dispatcher::<Event>::default();
+
+    assert_eq!(
+        dispatcher.dispatch_event_with_result(&Event::EventType),
+        DispatchResult::default()
+    );
+
+    dispatcher.add_listener(Event::EventType, &listener);
+    dispatcher.add_fn(Event::EventType, |_| {
+        Some(SyncDispatcherRequest::StopPropagation)
+    });
+    dispatcher.add_fn(Event::EventType, |_| None);
+
+    assert_eq!(
+        dispatcher.dispatch_event_with_result(&Event::EventType),
+        DispatchResult {
+            invoked: 2,
+            stopped: true,
+        }
+    );
+}
+
+/// **Intended test-behaviour**: Listeners can emit events while being
+/// dispatched to and emitted events reach their listeners after the
+/// current event reached all of its remaining ones.
+///
+/// **Test**: Listener A emits `Emitted` on `EventType`, listener B follows
+/// on `EventType` and listener C listens to `Emitted`.
+/// We expect the order A, B, C without a borrow panic.
+#[test]
+fn emitted_events_follow_remaining_listeners() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let listener_b = new_listener("b", &names_record);
+    let listener_c = new_listener("c", &names_record);
+    let dispatcher = Dispatcher::<Event>::default();
+    let emitter = dispatcher.emitter();
+    let a_record = Rc::clone(&names_record);
+
+    dispatcher.add_fn(Event::EventType, move |_| {
+        a_record.write().push("a".to_string());
+        emitter.emit(Event::Emitted);
+
+        None
+    });
+    dispatcher.add_listener(Event::EventType, &listener_b);
+    dispatcher.add_listener(Event::Emitted, &listener_c);
+
+    assert_eq!(
+        dispatcher.dispatch_event_with_result(&Event::EventType),
+        DispatchResult {
+            invoked: 2,
+            stopped: false,
+        }
+    );
+    assert_eq!(*names_record.read(), ["a", "b", "c"]);
+}
+
+/// **Intended test-behaviour**: Queued events are processed in FIFO-order
+/// and events queued while processing are handled within the same drain,
+/// as long as the cap allows.
+///
+/// **Test**: A closure on `EventType` emits `Emitted` once.
+/// We enqueue two `EventType`s, process at most two events, and then
+/// expect the emitted event to still be queued until the next drain.
+#[test]
+fn process_queue_max_caps_events_queued_while_draining() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let listener = new_listener("emitted", &names_record);
+    let dispatcher = Dispatcher::<Event>::default();
+    let emitter = dispatcher.emitter();
+    let record = Rc::clone(&names_record);
+
+    dispatcher.add_fn(Event::EventType, move |_| {
+        record.write().push("queued".to_string());
+        emitter.emit(Event::Emitted);
+
+        None
+    });
+    dispatcher.add_listener(Event::Emitted, &listener);
+
+    dispatcher.enqueue_event(Event::EventType);
+    dispatcher.enqueue_event(Event::EventType);
+
+    assert_eq!(dispatcher.process_queue_max(2), 2);
+    assert_eq!(*names_record.read(), ["queued", "queued"]);
+    assert_eq!(dispatcher.queued_len(), 2);
+
+    assert_eq!(dispatcher.process_queue_max(1), 1);
+    dispatcher.process_queue();
+    assert_eq!(
+        *names_record.read(),
+        ["queued", "queued", "emitted", "emitted"]
+    );
+
+    dispatcher.enqueue_event(Event::EventType);
+    dispatcher.clear_queue();
+    dispatcher.process_queue();
+    assert_eq!(names_record.read().len(), 4);
+}
+
+/// **Intended test-behaviour**: An immediate dispatch only delivers the
+/// events its listeners queue, events enqueued beforehand wait for the
+/// queue to be processed.
+///
+/// **Test**: A closure on `EventType` emits `Emitted`. We enqueue an
+/// `Emitted` and dispatch `EventType`, expecting only the emitted
+/// `Emitted` to be delivered and the enqueued one to stay queued until
+/// `process_queue`.
+#[test]
+fn dispatch_leaves_enqueued_events_for_process_queue() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let listener = new_listener("emitted", &names_record);
+    let dispatcher = Dispatcher::<Event>::default();
+    let emitter = dispatcher.emitter();
+    let record = Rc::clone(&names_record);
+
+    dispatcher.add_fn(Event::EventType, move |_| {
+        record.write().push("dispatched".to_string());
+        emitter.emit(Event::Emitted);
+
+        None
+    });
+    dispatcher.add_listener(Event::Emitted, &listener);
+
+    dispatcher.enqueue_event(Event::Emitted);
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.read(), ["dispatched", "emitted"]);
+    assert_eq!(dispatcher.queued_len(), 1);
+
+    dispatcher.process_queue();
+    assert_eq!(*names_record.read(), ["dispatched", "emitted", "emitted"]);
+    assert_eq!(dispatcher.queued_len(), 0);
+}
+
+/// **Intended test-behaviour**: A catch-all listener receives every
+/// dispatched event after the event's own listeners and may unsubscribe
+/// itself.
+///
+/// **Test**: A keyed listener stops propagation on `EventType`, a
+/// catch-all listener records all events and stops listening on the
+/// third one. We expect it to see `EventType` after the keyed listener,
+/// an unrouted `Emitted`, and nothing after unsubscribing.
+#[test]
+fn catch_all_listener_observes_every_event() {
+    struct Recorder {
+        name_record: Rc<RwLock<Vec<String>>>,
+    }
+
+    impl Listener<Event> for Recorder {
+        fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> {
+            let mut name_record = self.name_record.write();
+            name_record.push(match event {
+                Event::EventType => "recorded event".to_string(),
+                Event::Emitted => "recorded emitted".to_string(),
+            });
+
+            if name_record.len() >= 4 {
+                Some(SyncDispatcherRequest::StopListening)
+            } else {
+                None
+            }
+        }
+    }
+
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let recorder = Rc::new(RwLock::new(Recorder {
+        name_record: Rc::clone(&names_record),
+    }));
+    let keyed_record = Rc::clone(&names_record);
+    let dispatcher = Dispatcher::<Event>::default();
+
+    dispatcher.add_fn(Event::EventType, move |_| {
+        keyed_record.write().push("keyed".to_string());
+
+        Some(SyncDispatcherRequest::StopPropagation)
+    });
+    dispatcher.add_catch_all_listener(&recorder);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    dispatcher.dispatch_event(&Event::Emitted);
+    dispatcher.dispatch_event(&Event::Emitted);
+    dispatcher.dispatch_event(&Event::Emitted);
+
+    assert_eq!(
+        *names_record.read(),
+        [
+            "keyed",
+            "recorded event",
+            "recorded emitted",
+            "recorded emitted"
+        ]
+    );
+}
+
+/// **Intended test-behaviour**: Owned listeners are called without any
+/// `Rc`, obey stop requests and can be removed via their handle.
+///
+/// **Test**: An owned listener stops listening on its second call, while
+/// a second owned listener is removed via its handle. Both report their
+/// calls through a shared counter, whose only strong reference besides
+/// the test's is held by the listeners themselves.
+#[test]
+fn owned_listeners_obey_requests_and_removal() {
+    use std::cell::Cell;
+
+    struct OwnedListener {
+        calls: Rc<Cell<usize>>,
+        stop_after: usize,
+        own_calls: usize,
+    }
+
+    impl Listener<Event> for OwnedListener {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            self.calls.set(self.calls.get() + 1);
+            self.own_calls += 1;
+
+            if self.own_calls == self.stop_after {
+                Some(SyncDispatcherRequest::StopListening)
+            } else {
+                None
+            }
+        }
+    }
+
+    let calls = Rc::new(Cell::new(0));
+    let dispatcher = Dispatcher::<Event>::default();
+
+    dispatcher.add_owned_listener(
+        Event::EventType,
+        Box::new(OwnedListener {
+            calls: Rc::clone(&calls),
+            stop_after: 2,
+            own_calls: 0,
+        }),
+    );
+    let removable = dispatcher.add_owned_listener(
+        Event::EventType,
+        Box::new(OwnedListener {
+            calls: Rc::clone(&calls),
+            stop_after: usize::MAX,
+            own_calls: 0,
+        }),
+    );
+
+    assert_eq!(
+        dispatcher
+            .dispatch_event_with_result(&Event::EventType)
+            .invoked,
+        2
+    );
+    assert!(dispatcher.remove_listener(removable));
+    assert_eq!(Rc::strong_count(&calls), 2);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(calls.get(), 3);
+    assert_eq!(Rc::strong_count(&calls), 1);
+}
+
+/// **Intended test-behaviour**: A dispatcher shared via `Rc` can be used
+/// by its own listeners: Nested dispatches are queued, nested
+/// registrations and removals are deferred until the current event has
+/// been dispatched, instead of panicking on the borrowed listeners.
+///
+/// **Test**: A closure dispatches `Emitted`, adds a closure and removes
+/// listener `b` while `EventType` is being dispatched.
+/// We expect `b` to still receive `EventType`, `Emitted` to follow
+/// afterwards and the second `EventType` to reach the added closure
+/// but not `b`.
+#[test]
+fn nested_use_of_shared_dispatcher_is_deferred() {
+    use std::cell::Cell;
+
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let listener_b = new_listener("b", &names_record);
+    let emitted_listener = new_listener("emitted", &names_record);
+    let dispatcher = Rc::new(Dispatcher::<Event>::default());
+    let weak_dispatcher = Rc::downgrade(&dispatcher);
+    let handle_b = Rc::new(Cell::new(None));
+    let closure_handle_b = Rc::clone(&handle_b);
+    let record = Rc::clone(&names_record);
+
+    dispatcher.add_fn(Event::EventType, move |_| {
+        record.write().push("a".to_string());
+        let dispatcher = weak_dispatcher.upgrade()?;
+
+        if let Some(handle_b) = closure_handle_b.take() {
+            dispatcher.dispatch_event(&Event::Emitted);
+            assert!(dispatcher.remove_listener(handle_b));
+
+            let added_record = Rc::clone(&record);
+            dispatcher.add_fn(Event::EventType, move |_| {
+                added_record.write().push("added".to_string());
+
+                None
+            });
+        }
+
+        None
+    });
+    handle_b.set(Some(dispatcher.add_listener(Event::EventType, &listener_b)));
+    dispatcher.add_listener(Event::Emitted, &emitted_listener);
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.read(), ["a", "b", "emitted"]);
+    assert_eq!(dispatcher.queued_len(), 0);
+
+    names_record.write().clear();
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.read(), ["a", "added"]);
+}
+
+/// Listener counting its calls and answering each with `request`.
+struct RequestingListener {
+    calls: usize,
+    request: fn() -> Option<RcDispatcherRequest>,
+}
+
+impl Listener<Event> for RequestingListener {
+    fn on_event(&mut self, _event: &Event) -> Option<RcDispatcherRequest> {
+        self.calls += 1;
+
+        (self.request)()
+    }
+}
+
+fn new_requesting_listener(
+    request: fn() -> Option<RcDispatcherRequest>,
+) -> Rc<RwLock<RequestingListener>> {
+    Rc::new(RwLock::new(RequestingListener { calls: 0, request }))
+}
+
+/// **Intended test-behaviour**: `StopListening` removes the listener
+/// without affecting the current dispatch.
+///
+/// **Test**: Listener `a` stops listening, `b` keeps listening.
+/// After two dispatches, we expect `a` to be called once and `b` twice.
+#[test]
+fn stop_listening_on_rc_dispatcher() {
+    let listener_a = new_requesting_listener(|| Some(RcDispatcherRequest::StopListening));
+    let listener_b = new_requesting_listener(|| None);
+    let dispatcher = Dispatcher::<Event>::default();
+    let handle_a = dispatcher.add_listener(Event::EventType, &listener_a);
+    dispatcher.add_listener(Event::EventType, &listener_b);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(listener_a.read().calls, 1);
+    assert_eq!(listener_b.read().calls, 2);
+    assert!(!dispatcher.remove_listener(handle_a));
+}
+
+/// **Intended test-behaviour**: `StopPropagation` prevents later
+/// listeners from receiving the event, but keeps the listener.
+///
+/// **Test**: Listener `a` stops propagation on every dispatch.
+/// After two dispatches, we expect `a` to be called twice and `b` never.
+#[test]
+fn stop_propagation_on_rc_dispatcher() {
+    let listener_a = new_requesting_listener(|| Some(RcDispatcherRequest::StopPropagation));
+    let listener_b = new_requesting_listener(|| None);
+    let dispatcher = Dispatcher::<Event>::default();
+    let handle_a = dispatcher.add_listener(Event::EventType, &listener_a);
+    dispatcher.add_listener(Event::EventType, &listener_b);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    let dispatch_result = dispatcher.dispatch_event_with_result(&Event::EventType);
+
+    assert!(dispatch_result.stopped);
+    assert_eq!(listener_a.read().calls, 2);
+    assert_eq!(listener_b.read().calls, 0);
+    assert!(dispatcher.remove_listener(handle_a));
+}
+
+/// **Intended test-behaviour**: `StopListeningAndPropagation` removes
+/// the listener and prevents later listeners from receiving the event.
+///
+/// **Test**: Listener `a` stops listening and propagation.
+/// We expect the first dispatch to reach only `a`, the second only `b`.
+#[test]
+fn stop_listening_and_propagation_on_rc_dispatcher() {
+    let listener_a =
+        new_requesting_listener(|| Some(RcDispatcherRequest::StopListeningAndPropagation));
+    let listener_b = new_requesting_listener(|| None);
+    let dispatcher = Dispatcher::<Event>::default();
+    dispatcher.add_listener(Event::EventType, &listener_a);
+    dispatcher.add_listener(Event::EventType, &listener_b);
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(listener_a.read().calls, 1);
+    assert_eq!(listener_b.read().calls, 0);
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(listener_a.read().calls, 1);
+    assert_eq!(listener_b.read().calls, 1);
+}
+
+/// **Intended test-behaviour**: Events emitting each other nest up to
+/// the maximal dispatch-depth, deeper events are refused and the depth
+/// starts over with the next outermost dispatch.
+///
+/// **Test**: A closure emits `EventType` whenever it receives one.
+/// With a maximal depth of `2`, we expect three calls per outermost
+/// dispatch and the third emission to be refused each time.
+#[test]
+fn max_dispatch_depth_stops_emission_loops() {
+    use hey_listen::rc::DispatchError;
+    use std::cell::{Cell, RefCell};
+
+    let mut dispatcher = Dispatcher::<Event>::default();
+    dispatcher.set_max_dispatch_depth(2);
+    assert_eq!(dispatcher.max_dispatch_depth(), 2);
+
+    let emitter = dispatcher.emitter();
+    let calls = Rc::new(Cell::new(0));
+    let errors = Rc::new(RefCell::new(Vec::new()));
+    let closure_calls = Rc::clone(&calls);
+    let closure_errors = Rc::clone(&errors);
+
+    dispatcher.add_fn(Event::EventType, move |event| {
+        closure_calls.set(closure_calls.get() + 1);
+
+        if let Err(error) = emitter.try_emit(event.clone()) {
+            closure_errors.borrow_mut().push(error);
+        }
+
+        None
+    });
+
+    assert_eq!(
+        dispatcher.try_dispatch_event(&Event::EventType),
+        Ok(DispatchResult {
+            invoked: 1,
+            stopped: false,
+        })
+    );
+    assert_eq!(calls.get(), 3);
+    assert_eq!(*errors.borrow(), [DispatchError::MaxDepthExceeded]);
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(calls.get(), 6);
+    assert_eq!(errors.borrow().len(), 2);
+    assert_eq!(dispatcher.queued_len(), 0);
+}
+
+/// **Intended test-behaviour**: An event queued via `try_enqueue_event`
+/// beyond the maximal dispatch-depth is handed back instead of dropped.
+///
+/// **Test**: With a maximal depth of `0`, a closure listening to `1`
+/// queues `2`. We expect `2` to be handed back and nothing to be queued.
+#[test]
+fn try_enqueue_event_hands_back_events_beyond_max_depth() {
+    use std::cell::RefCell;
+
+    let mut dispatcher = Dispatcher::<u32>::default();
+    dispatcher.set_max_dispatch_depth(0);
+    let dispatcher = Rc::new(dispatcher);
+    let refused = Rc::new(RefCell::new(Vec::new()));
+    let weak_dispatcher = Rc::downgrade(&dispatcher);
+    let closure_refused = Rc::clone(&refused);
+
+    dispatcher.add_fn(1, move |_| {
+        if let Some(dispatcher) = weak_dispatcher.upgrade() {
+            if let Err(error) = dispatcher.try_enqueue_event(2) {
+                closure_refused.borrow_mut().push(error);
+            }
+        }
+
+        None
+    });
+
+    dispatcher.dispatch_event(&1);
+
+    assert_eq!(*refused.borrow(), [EnqueueError::MaxDepthExceeded(2)]);
+    assert_eq!(dispatcher.queued_len(), 0);
+    assert_eq!(
+        refused.borrow_mut().pop().map(EnqueueError::into_event),
+        Some(2)
+    );
+}
+
+/// **Intended test-behaviour**: Bulk removals drop listeners without
+/// their handles, keeping the order of the remaining ones.
+///
+/// **Test**: We register four listeners on `EventType`, one on `Emitted`
+/// and a catch-all closure. Retaining drops `b` and keeps `a, c, d` in
+/// order, removing all listeners of `Emitted` reports one, and clearing
+/// silences the catch-all closure as well.
+#[test]
+fn bulk_removal_preserves_order() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let listeners: Vec<_> = ["a", "b", "c", "d"]
+        .iter()
+        .map(|name| new_listener(name, &names_record))
+        .collect();
+    let emitted_listener = new_listener("emitted", &names_record);
+
+    let mut dispatcher = Dispatcher::<Event>::default();
+    let handles: Vec<_> = listeners
+        .iter()
+        .map(|listener| dispatcher.add_listener(Event::EventType, listener))
+        .collect();
+    dispatcher.add_listener(Event::Emitted, &emitted_listener);
+    let catch_all = new_listener("catch-all", &names_record);
+    dispatcher.add_catch_all_listener(&catch_all);
+
+    dispatcher.retain_listeners(&Event::EventType, |handle| handle != handles[1]);
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.read(), ["a", "c", "d", "catch-all"]);
+
+    assert_eq!(dispatcher.remove_all_listeners_for(&Event::Emitted), 1);
+    assert_eq!(dispatcher.remove_all_listeners_for(&Event::Emitted), 0);
+    names_record.write().clear();
+    dispatcher.dispatch_event(&Event::Emitted);
+
+    assert_eq!(*names_record.read(), ["catch-all"]);
+
+    dispatcher.clear();
+    names_record.write().clear();
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert!(names_record.read().is_empty());
+    assert!(!dispatcher.remove_listener(handles[0]));
+}
+
+/// **Intended test-behaviour**: Mutable dispatches let listeners write
+/// into the event in registration-order and stop propagation like
+/// immutable dispatches.
+///
+/// **Test**: Three listeners append their entry to a context-menu event,
+/// a `MutListener` between two closures. Afterwards, the first closure
+/// stops propagation, leaving its own entry only.
+#[test]
+fn mutable_dispatch_appends_in_registration_order() {
+    use std::hash::{Hash, Hasher};
+
+    #[derive(Clone, Debug)]
+    struct ContextMenu {
+        entries: Vec<&'static str>,
+    }
+
+    impl Hash for ContextMenu {
+        fn hash<H: Hasher>(&self, _state: &mut H) {}
+    }
+
+    impl PartialEq for ContextMenu {
+        fn eq(&self, _other: &ContextMenu) -> bool {
+            true
+        }
+    }
+
+    impl Eq for ContextMenu {}
+
+    struct Widget;
+
+    impl MutListener<ContextMenu> for Widget {
+        fn on_event_mut(&mut self, event: &mut ContextMenu) -> Option<SyncDispatcherRequest> {
+            event.entries.push("paste");
+
+            None
+        }
+    }
+
+    let mut dispatcher = Dispatcher::<ContextMenu>::default();
+    let key = ContextMenu {
+        entries: Vec::new(),
+    };
+    let widget = Rc::new(RwLock::new(Widget));
+    let mut stop = false;
+
+    dispatcher.add_mut_fn(key.clone(), move |event| {
+        event.entries.push("copy");
+
+        if stop {
+            Some(SyncDispatcherRequest::StopPropagation)
+        } else {
+            stop = true;
+
+            None
+        }
+    });
+    dispatcher.add_mut_listener(key.clone(), &widget);
+    dispatcher.add_mut_fn(key.clone(), |event| {
+        event.entries.push("delete");
+
+        None
+    });
+
+    let mut menu = key.clone();
+    dispatcher.dispatch_event_mut(&mut menu);
+
+    assert_eq!(menu.entries, ["copy", "paste", "delete"]);
+
+    let mut menu = key.clone();
+    dispatcher.dispatch_event_mut(&mut menu);
+
+    assert_eq!(menu.entries, ["copy"]);
+    assert_eq!(dispatcher.remove_all_listeners_for(&key), 3);
+}
+
+/// **Intended test-behaviour**: Registering for several events at once
+/// behaves like one `add_listener` per event, duplicates included.
+///
+/// **Test**: We register a listener for `EventType` twice and `Emitted`
+/// once, expect three distinct handles and the listener to be called twice
+/// on `EventType`. Removing one handle leaves a single call.
+#[test]
+fn add_listener_to_all_registers_duplicates() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let listener = new_listener("widget", &names_record);
+    let dispatcher = Dispatcher::<Event>::default();
+
+    let handles = dispatcher.add_listener_to_all(
+        vec![Event::EventType, Event::Emitted, Event::EventType],
+        &listener,
+    );
+
+    assert_eq!(handles.len(), 3);
+    assert!(handles[0] != handles[2]);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(*names_record.read(), ["widget", "widget"]);
+
+    assert!(dispatcher.remove_listener(handles[2]));
+    names_record.write().clear();
+    dispatcher.dispatch_event(&Event::EventType);
+    dispatcher.dispatch_event(&Event::Emitted);
+
+    assert_eq!(*names_record.read(), ["widget", "widget"]);
+}
+
+/// **Intended test-behaviour**: Listeners are told about every
+/// registration and every removal, no matter how they are removed.
+///
+/// **Test**: A widget counts its subscriptions and unsubscriptions. It is
+/// registered three times and removed explicitly once. It requests to
+/// stop listening on `Emitted`, ending its own and its catch-all
+/// registration, and the last registration gets cleared.
+#[test]
+fn unsubscribe_hook_fires_on_every_removal_path() {
+    #[derive(Default)]
+    struct Widget {
+        subscriptions: usize,
+        unsubscriptions: usize,
+    }
+
+    impl Listener<Event> for Widget {
+        fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> {
+            match event {
+                Event::Emitted => Some(SyncDispatcherRequest::StopListening),
+                Event::EventType => None,
+            }
+        }
+
+        fn on_subscribe(&mut self) {
+            self.subscriptions += 1;
+        }
+
+        fn on_unsubscribe(&mut self) {
+            self.unsubscriptions += 1;
+        }
+    }
+
+    let widget = Rc::new(RwLock::new(Widget::default()));
+    let mut dispatcher = Dispatcher::<Event>::default();
+
+    let removed_explicitly = dispatcher.add_listener(Event::EventType, &widget);
+    dispatcher.add_listener(Event::Emitted, &widget);
+    dispatcher.add_catch_all_listener(&widget);
+    assert_eq!(widget.read().subscriptions, 3);
+
+    assert!(dispatcher.remove_listener(removed_explicitly));
+    assert_eq!(widget.read().unsubscriptions, 1);
+
+    dispatcher.dispatch_event(&Event::Emitted);
+    assert_eq!(widget.read().unsubscriptions, 3);
+
+    dispatcher.add_listener(Event::EventType, &widget);
+    dispatcher.clear();
+    assert_eq!(widget.read().subscriptions, 4);
+    assert_eq!(widget.read().unsubscriptions, 4);
+}
+
+/// **Intended test-behaviour**: Appending a dispatcher moves all of its
+/// registrations, keeping their order behind the existing ones.
+///
+/// **Test**: The application and a plugin register listeners on
+/// `EventType`, the plugin a catch-all listener as well. After appending,
+/// the plugin's listeners follow the application's ones, the plugin is
+/// empty and the plugin's handles work on the application's dispatcher.
+#[test]
+fn append_moves_registrations_in_order() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let app_listener = new_listener("app", &names_record);
+    let plugin_a = new_listener("plugin a", &names_record);
+    let plugin_b = new_listener("plugin b", &names_record);
+    let plugin_catch_all = new_listener("plugin catch-all", &names_record);
+
+    let app: Dispatcher<Event> = vec![(
+        Event::EventType,
+        Rc::clone(&app_listener) as Rc<RwLock<dyn Listener<Event>>>,
+    )]
+    .into_iter()
+    .collect();
+    let mut plugin = Dispatcher::<Event>::default();
+    let plugin_a_handle = plugin.add_listener(Event::EventType, &plugin_a);
+    plugin.add_listener(Event::EventType, &plugin_b);
+    plugin.add_catch_all_listener(&plugin_catch_all);
+
+    app.append(&mut plugin);
+    app.dispatch_event(&Event::EventType);
+
+    assert_eq!(
+        *names_record.read(),
+        ["app", "plugin a", "plugin b", "plugin catch-all"]
+    );
+    assert_eq!(
+        plugin.dispatch_event_with_result(&Event::EventType).invoked,
+        0
+    );
+
+    assert!(app.remove_listener(plugin_a_handle));
+    names_record.write().clear();
+    app.dispatch_event(&Event::EventType);
+
+    assert_eq!(
+        *names_record.read(),
+        ["app", "plugin b", "plugin catch-all"]
+    );
+}
+
+/// **Intended test-behaviour**: Appending while dispatching defers moving
+/// the registrations until the current event has been dispatched.
+///
+/// **Test**: A listener appends a plugin to its own dispatcher while
+/// being dispatched to. The plugin's listener misses the ongoing dispatch
+/// but is called on the next one.
+#[test]
+fn append_while_dispatching_is_deferred() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let plugin_listener = new_listener("plugin", &names_record);
+
+    let app = Rc::new(Dispatcher::<Event>::default());
+    let plugin = Rc::new(RefCell::new(Dispatcher::<Event>::default()));
+    plugin
+        .borrow()
+        .add_listener(Event::EventType, &plugin_listener);
+
+    let weak_app = Rc::downgrade(&app);
+    let appended_plugin = Rc::clone(&plugin);
+    app.add_fn(Event::EventType, move |_| {
+        if let Some(app) = weak_app.upgrade() {
+            app.append(&mut appended_plugin.borrow_mut());
+        }
+
+        Some(SyncDispatcherRequest::StopListening)
+    });
+
+    app.dispatch_event(&Event::EventType);
+    assert!(names_record.read().is_empty());
+    assert!(plugin.borrow().is_empty());
+
+    app.dispatch_event(&Event::EventType);
+    assert_eq!(*names_record.read(), ["plugin"]);
+}
+
+/// **Intended test-behaviour**: Queued events can be taken out of one
+/// dispatcher and put into another one, keeping their order.
+///
+/// **Test**: We queue `EventType` and `Emitted`, take the queue and set it
+/// on a fresh dispatcher replacing its own queued event. Processing it
+/// dispatches both events in their original order.
+#[test]
+fn queue_survives_take_and_set() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let event_type_listener = new_listener("event type", &names_record);
+    let emitted_listener = new_listener("emitted", &names_record);
+
+    let mut saved = Dispatcher::<Event>::default();
+    saved.enqueue_event(Event::EventType);
+    saved.enqueue_event(Event::Emitted);
+
+    let queue = saved.take_queue();
+    assert_eq!(saved.queued_len(), 0);
+
+    let mut loaded = Dispatcher::<Event>::default();
+    loaded.add_listener(Event::EventType, &event_type_listener);
+    loaded.add_listener(Event::Emitted, &emitted_listener);
+    loaded.enqueue_event(Event::Emitted);
+    loaded.set_queue(queue);
+    assert_eq!(loaded.queued_len(), 2);
+
+    loaded.process_queue();
+    assert_eq!(*names_record.read(), ["event type", "emitted"]);
+}
+
+/// **Intended test-behaviour**: Introspection tells which registrations
+/// are left, ignoring dropped listeners.
+///
+/// **Test**: A document window registers for `EventType`, `Emitted` and as
+/// catch-all listener next to a closure on `EventType`. Dropping the
+/// window leaves the closure as the only listener and key.
+#[test]
+fn introspection_reflects_torn_down_window() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let window = new_listener("window", &names_record);
+    let dispatcher = Dispatcher::<Event>::default();
+    assert!(dispatcher.is_empty());
+
+    dispatcher.add_listener_to_all(vec![Event::EventType, Event::Emitted], &window);
+    dispatcher.add_catch_all_listener(&window);
+    dispatcher.add_fn(Event::EventType, |_| None);
+
+    let mut keys: Vec<_> = dispatcher.keys().collect();
+    keys.sort_by_key(|event| *event == Event::Emitted);
+    assert!(keys == [Event::EventType, Event::Emitted]);
+    assert_eq!(dispatcher.listener_count(&Event::EventType), 2);
+    assert_eq!(dispatcher.total_listener_count(), 4);
+
+    drop(window);
+
+    assert!(dispatcher.keys().eq(vec![Event::EventType]));
+    assert_eq!(dispatcher.listener_count(&Event::EventType), 1);
+    assert_eq!(dispatcher.listener_count(&Event::Emitted), 0);
+    assert_eq!(dispatcher.total_listener_count(), 1);
+    assert!(!dispatcher.is_empty());
+}
+
+/// **Intended test-behaviour**: Introspection called by a listener while
+/// dispatching does not panic but falls back to empty results.
+///
+/// **Test**: A closure on `EventType` queries the dispatcher it listens
+/// to. We expect no keys, zero counts and a non-empty dispatcher.
+#[test]
+fn introspection_while_dispatching_does_not_panic() {
+    let dispatcher = Rc::new(Dispatcher::<Event>::default());
+    let weak_dispatcher = Rc::downgrade(&dispatcher);
+    let observed = Rc::new(Cell::new(None));
+    let listener_observed = Rc::clone(&observed);
+
+    dispatcher.add_fn(Event::EventType, move |_| {
+        let dispatcher = weak_dispatcher.upgrade()?;
+
+        listener_observed.set(Some((
+            dispatcher.keys().count(),
+            dispatcher.listener_count(&Event::EventType),
+            dispatcher.total_listener_count(),
+            dispatcher.is_empty(),
+        )));
+
+        None
+    });
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(observed.get(), Some((0, 0, 0, false)));
+    assert_eq!(dispatcher.listener_count(&Event::EventType), 1);
+}
+
+/// **Intended test-behaviour**: Events produced while dispatching a frame
+/// are dispatched in the next frame, one frame delayed.
+///
+/// **Test**: A closure on `EventType` dispatches `Emitted` and queues
+/// `EventType` again. Every frame reaches exactly the events of the
+/// previous one.
+#[test]
+fn swap_and_dispatch_delays_produced_events_by_one_frame() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let emitted_listener = new_listener("emitted", &names_record);
+    let dispatcher = Rc::new(Dispatcher::<Event>::default());
+    let weak_dispatcher = Rc::downgrade(&dispatcher);
+    let record = Rc::clone(&names_record);
+
+    dispatcher.add_fn(Event::EventType, move |_| {
+        let dispatcher = weak_dispatcher.upgrade()?;
+        record.write().push("event type".to_string());
+        dispatcher.dispatch_event(&Event::Emitted);
+        dispatcher.enqueue_event(Event::EventType);
+
+        None
+    });
+    dispatcher.add_listener(Event::Emitted, &emitted_listener);
+
+    dispatcher.enqueue_event(Event::EventType);
+    assert_eq!(dispatcher.swap_and_dispatch(), 1);
+    assert_eq!(*names_record.read(), ["event type"]);
+
+    names_record.write().clear();
+    assert_eq!(dispatcher.swap_and_dispatch(), 2);
+    assert_eq!(*names_record.read(), ["emitted", "event type"]);
+
+    for _ in 0..100 {
+        assert_eq!(dispatcher.swap_and_dispatch(), 2);
+    }
+}
+
+/// **Intended test-behaviour**: Draining dispatches only the events queued
+/// before the call and counts every invoked listener.
+///
+/// **Test**: A closure re-queues its event twice per call, every drain
+/// dispatches exactly the events of the previous one to two listeners.
+#[test]
+fn drain_and_dispatch_counts_invocations_of_one_frame() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let listener = new_listener("listener", &names_record);
+    let dispatcher = Dispatcher::<Event>::default();
+    let emitter = dispatcher.emitter();
+
+    dispatcher.add_fn(Event::EventType, move |_| {
+        emitter.emit(Event::EventType);
+        emitter.emit(Event::EventType);
+
+        None
+    });
+    dispatcher.add_listener(Event::EventType, &listener);
+
+    assert_eq!(dispatcher.drain_and_dispatch(), 0);
+
+    dispatcher.enqueue_event(Event::EventType);
+    assert_eq!(dispatcher.drain_and_dispatch(), 2);
+    assert_eq!(dispatcher.queued_len(), 2);
+
+    assert_eq!(dispatcher.drain_and_dispatch(), 4);
+    assert_eq!(dispatcher.queued_len(), 4);
+    assert_eq!(names_record.read().len(), 3);
+}
+
+const QUEUE_CAPACITY: usize = 4;
+
+/// Creates a bounded dispatcher recording every dispatched number.
+fn bounded_dispatcher(overflow_policy: OverflowPolicy) -> (Dispatcher<u32>, Rc<RwLock<Vec<u32>>>) {
+    let dispatcher = Dispatcher::with_queue_capacity(QUEUE_CAPACITY, overflow_policy);
+    let record = Rc::new(RwLock::new(Vec::new()));
+
+    for number in 0..QUEUE_CAPACITY as u32 + 3 {
+        let record = Rc::clone(&record);
+        dispatcher.add_fn(number, move |number| {
+            record.write().push(*number);
+
+            None
+        });
+    }
+
+    (dispatcher, record)
+}
+
+/// **Intended test-behaviour**: A full queue drops events according to
+/// its overflow-policy, never holding more than its capacity.
+///
+/// **Test**: Capacity + 3 events are queued under every policy, the
+/// surviving events are processed and recorded.
+#[test]
+fn bounded_queue_honours_overflow_policy() {
+    let (dispatcher, record) = bounded_dispatcher(OverflowPolicy::DropNewest);
+    for number in 0..QUEUE_CAPACITY as u32 + 3 {
+        dispatcher.enqueue_event(number);
+    }
+    assert_eq!(dispatcher.queued_len(), QUEUE_CAPACITY);
+    assert_eq!(dispatcher.capacity(), Some(QUEUE_CAPACITY));
+    dispatcher.process_queue();
+    assert_eq!(*record.read(), [0, 1, 2, 3]);
+
+    let (dispatcher, record) = bounded_dispatcher(OverflowPolicy::DropOldest);
+    for number in 0..QUEUE_CAPACITY as u32 + 3 {
+        dispatcher.enqueue_event(number);
+    }
+    assert_eq!(dispatcher.queued_len(), QUEUE_CAPACITY);
+    dispatcher.process_queue();
+    assert_eq!(*record.read(), [3, 4, 5, 6]);
+
+    let (dispatcher, record) = bounded_dispatcher(OverflowPolicy::ReturnError);
+    let refused: Vec<EnqueueError<u32>> = (0..QUEUE_CAPACITY as u32 + 3)
+        .filter_map(|number| dispatcher.try_enqueue_event(number).err())
+        .collect();
+    assert_eq!(
+        refused,
+        [
+            EnqueueError::QueueFull(4),
+            EnqueueError::QueueFull(5),
+            EnqueueError::QueueFull(6)
+        ]
+    );
+    dispatcher.process_queue();
+    assert_eq!(*record.read(), [0, 1, 2, 3]);
+    assert_eq!(dispatcher.try_enqueue_event(4), Ok(()));
+
+    assert_eq!(Dispatcher::<u32>::default().capacity(), None);
+}
+
+/// **Intended test-behaviour**: Scheduled events are dispatched once the
+/// ticked time reaches their deadline, earliest first, unless cancelled.
+///
+/// **Test**: Events are scheduled out of deadline-order, one is cancelled
+/// and the fake clock is advanced step by step.
+#[test]
+fn scheduled_events_dispatch_in_deadline_order() {
+    let (dispatcher, record) = bounded_dispatcher(OverflowPolicy::DropNewest);
+    let mut scheduler = ScheduledDispatcher::new(dispatcher);
+    let seconds = Duration::from_secs;
+
+    scheduler.schedule(3, seconds(5));
+    scheduler.schedule(1, seconds(2));
+    let cancelled = scheduler.schedule(4, seconds(2));
+    scheduler.schedule(2, seconds(2));
+    assert!(scheduler.cancel(cancelled));
+    assert!(!scheduler.cancel(cancelled));
+
+    assert_eq!(scheduler.tick_with_elapsed(seconds(1)), 0);
+    assert_eq!(scheduler.tick_with_elapsed(seconds(1)), 2);
+    assert_eq!(*record.read(), [1, 2]);
+
+    scheduler.schedule(0, seconds(1));
+    assert_eq!(scheduler.scheduled_len(), 2);
+    assert_eq!(scheduler.tick_with_elapsed(seconds(10)), 2);
+    assert_eq!(*record.read(), [1, 2, 0, 3]);
+    assert_eq!(scheduler.elapsed(), seconds(12));
+    assert_eq!(scheduler.scheduled_len(), 0);
+}
+
+/// **Intended test-behaviour**: Repeating events fire once per interval at
+/// a fixed rate until cancelled, late ticks catch up on missed firings up
+/// to the configured limit.
+///
+/// **Test**: A heartbeat is ticked through 10 intervals in uneven steps,
+/// then one late tick skips some firings, cancelling stops it right away.
+#[test]
+fn repeating_events_fire_every_interval_until_cancelled() {
+    let (dispatcher, record) = bounded_dispatcher(OverflowPolicy::DropNewest);
+    let mut scheduler = ScheduledDispatcher::new(dispatcher);
+    let millis = Duration::from_millis;
+
+    let heartbeat = scheduler.schedule_repeating(1, millis(100));
+    let dispatched: usize = [30, 70, 100, 150, 50, 99, 1, 200, 200]
+        .iter()
+        .map(|step| scheduler.tick_with_elapsed(millis(*step)))
+        .sum();
+    assert_eq!(dispatched, 9);
+    assert_eq!(scheduler.tick_with_elapsed(millis(100)), 1);
+    assert_eq!(record.read().len(), 10);
+
+    scheduler.set_max_firings_per_tick(2);
+    assert_eq!(scheduler.tick_with_elapsed(millis(550)), 2);
+    assert_eq!(scheduler.tick_with_elapsed(millis(49)), 0);
+    assert_eq!(scheduler.tick_with_elapsed(millis(1)), 1);
+
+    assert!(scheduler.cancel(heartbeat));
+    assert_eq!(scheduler.tick_with_elapsed(millis(1000)), 0);
+    assert_eq!(scheduler.scheduled_len(), 0);
+}
+
+/// **Intended test-behaviour**: A dispatch is reported as in progress to
+/// listeners only, letting them queue instead of dispatching nested events.
+///
+/// **Test**: A closure on `EventType` checks the dispatcher and queues
+/// `Emitted` while dispatching, which is dispatched by `process_queue`.
+#[test]
+fn is_dispatching_only_within_listeners() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let emitted_listener = new_listener("emitted", &names_record);
+    let dispatcher = Rc::new(Dispatcher::<Event>::default());
+    let weak_dispatcher = Rc::downgrade(&dispatcher);
+    let record = Rc::clone(&names_record);
+
+    dispatcher.add_fn(Event::EventType, move |_| {
+        let dispatcher = weak_dispatcher.upgrade()?;
+
+        if dispatcher.is_dispatching() {
+            record.write().push("queued".to_string());
+            dispatcher.enqueue_event(Event::Emitted);
+        }
+
+        None
+    });
+    dispatcher.add_listener(Event::Emitted, &emitted_listener);
+
+    assert!(!dispatcher.is_dispatching());
+    dispatcher.dispatch_event(&Event::EventType);
+    assert!(!dispatcher.is_dispatching());
+    assert_eq!(*names_record.read(), ["queued", "emitted"]);
+}
+
+/// **Intended test-behaviour**: Filtered registrations of any kind are
+/// skipped without being locked or counted as invoked.
+///
+/// **Test**: A filtered listener and a one-shot closure share a switch,
+/// a catch-all listener only passes `Emitted`. While the switch is off,
+/// the filtered listener stays locked during the dispatch.
+#[test]
+fn filters_skip_listeners_before_locking() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let filtered = new_listener("filtered", &names_record);
+    let catch_all = new_listener("catch-all", &names_record);
+    let open = Rc::new(Cell::new(false));
+    let dispatcher = Dispatcher::<Event>::default();
+
+    let switch = Rc::clone(&open);
+    dispatcher.add_listener_filtered(Event::EventType, &filtered, move |_| switch.get());
+
+    let record = Rc::clone(&names_record);
+    let once = dispatcher.add_fn_once(Event::EventType, move |_| {
+        record.write().push("once".to_string());
+
+        None
+    });
+    let switch = Rc::clone(&open);
+    assert!(dispatcher.set_filter(once, move |_| switch.get()));
+
+    let catch_all_handle = dispatcher.add_catch_all_listener(&catch_all);
+    assert!(dispatcher.set_filter(catch_all_handle, |event| *event == Event::Emitted));
+
+    {
+        let _locked = filtered.write();
+        assert_eq!(
+            dispatcher.dispatch_event_with_result(&Event::EventType),
+            DispatchResult::default()
+        );
+    }
+
+    open.set(true);
+    assert_eq!(
+        dispatcher
+            .dispatch_event_with_result(&Event::EventType)
+            .invoked,
+        2
+    );
+    dispatcher.dispatch_event(&Event::EventType);
+    dispatcher.dispatch_event(&Event::Emitted);
+
+    assert_eq!(
+        *names_record.read(),
+        ["filtered", "once", "filtered", "catch-all"]
+    );
+    assert!(!dispatcher.set_filter(once, |_| true));
+}
+
+/// An event compared and hashed by its `key` only.
+#[derive(Clone)]
+struct Keyed {
+    key: char,
+    value: u32,
+}
+
+impl Hash for Keyed {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
+
+impl PartialEq for Keyed {
+    fn eq(&self, other: &Keyed) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Keyed {}
+
+/// **Intended test-behaviour**: Coalescing keys are queued at most once,
+/// at the position of their first occurrence, other keys queue normally.
+///
+/// **Test**: `a` keeps its last and `b` its first event, `c` does not
+/// coalesce. Events of all keys are interleaved, queued and processed.
+#[test]
+fn coalescing_keeps_one_event_per_key() {
+    let dispatcher = Dispatcher::default();
+    let record = Rc::new(RwLock::new(Vec::new()));
+
+    for key in "abc".chars() {
+        let record = Rc::clone(&record);
+        dispatcher.add_fn(Keyed { key, value: 0 }, move |event: &Keyed| {
+            record.write().push((event.key, event.value));
+
+            None
+        });
+    }
+
+    dispatcher.set_coalescing(Keyed { key: 'a', value: 0 }, Coalesce::KeepLast);
+    dispatcher.set_coalescing(Keyed { key: 'b', value: 0 }, Coalesce::KeepFirst);
+
+    for (key, value) in &[
+        ('c', 1),
+        ('a', 1),
+        ('b', 1),
+        ('a', 2),
+        ('c', 2),
+        ('b', 2),
+        ('a', 3),
+    ] {
+        dispatcher.enqueue_event(Keyed {
+            key: *key,
+            value: *value,
+        });
+    }
+
+    assert_eq!(dispatcher.queued_len(), 4);
+    dispatcher.process_queue();
+    assert_eq!(*record.read(), [('c', 1), ('a', 3), ('b', 1), ('c', 2)]);
+
+    assert_eq!(
+        dispatcher.remove_coalescing(&Keyed { key: 'a', value: 0 }),
+        Some(Coalesce::KeepLast)
+    );
+    dispatcher.enqueue_event(Keyed { key: 'a', value: 4 });
+    dispatcher.enqueue_event(Keyed { key: 'a', value: 5 });
+    assert_eq!(dispatcher.queued_len(), 2);
+}
+
+/// **Intended test-behaviour**: Queued events are processed by descending
+/// priority, in FIFO-order within the same priority, and a full queue
+/// makes room by dropping the oldest event of the lowest priority.
+///
+/// **Test**: Numbers are queued with interleaved priorities into an
+/// unbounded and a bounded queue, the latter overflowing.
+#[test]
+fn queue_orders_by_priority_then_fifo() {
+    let dispatcher = Dispatcher::default();
+    let record = Rc::new(RwLock::new(Vec::new()));
+
+    for number in 0..7 {
+        let record = Rc::clone(&record);
+        dispatcher.add_fn(number, move |number| {
+            record.write().push(*number);
+
+            None
+        });
+    }
+
+    for (number, priority) in &[(0, 0), (1, 5), (2, 0), (3, 9), (4, 5), (5, 9), (6, 0)] {
+        dispatcher.enqueue_event_with_priority(*number, *priority);
+    }
+    dispatcher.process_queue();
+    assert_eq!(*record.read(), [3, 5, 1, 4, 0, 2, 6]);
+
+    let (dispatcher, record) = bounded_dispatcher(OverflowPolicy::DropOldest);
+    dispatcher.enqueue_event(0);
+    dispatcher.enqueue_event_with_priority(1, 1);
+    dispatcher.enqueue_event(2);
+    dispatcher.enqueue_event_with_priority(3, 1);
+    dispatcher.enqueue_event_with_priority(4, 2);
+    dispatcher.enqueue_event(5);
+    assert_eq!(dispatcher.queued_len(), QUEUE_CAPACITY);
+    dispatcher.process_queue();
+    assert_eq!(*record.read(), [4, 1, 3, 5]);
+}
+
+/// **Intended test-behaviour**: A full queue never drops an event of a
+/// higher priority for an incoming one of a lower priority, the incoming
+/// event is dropped instead.
+///
+/// **Test**: A bounded queue is filled with events of priority `9`. An
+/// event without priority is dropped, respectively handed back, while an
+/// event of priority `9` replaces the oldest one.
+#[test]
+fn full_queue_drops_events_below_all_queued_priorities() {
+    let (dispatcher, record) = bounded_dispatcher(OverflowPolicy::DropOldest);
+    for number in 0..QUEUE_CAPACITY as u32 {
+        dispatcher.enqueue_event_with_priority(number, 9);
+    }
+
+    dispatcher.enqueue_event(4);
+    assert_eq!(
+        dispatcher.try_enqueue_event(5),
+        Err(EnqueueError::QueueFull(5))
+    );
+    dispatcher.enqueue_event_with_priority(6, 9);
+    assert_eq!(dispatcher.queued_len(), QUEUE_CAPACITY);
+
+    dispatcher.process_queue();
+    assert_eq!(*record.read(), [1, 2, 3, 6]);
+}
+
+/// **Intended test-behaviour**: Replacing a coalesced event by one of a
+/// higher priority moves it ahead according to that priority, one of a
+/// lower priority keeps the queued event's position and priority.
+///
+/// **Test**: `a` keeps its last event and is queued behind `b` without
+/// priority. A replacement of priority `5` moves it in front of `b`, a
+/// later replacement without priority keeps it there.
+#[test]
+fn coalescing_with_higher_priority_moves_event_ahead() {
+    let dispatcher = Dispatcher::default();
+    let record = Rc::new(RwLock::new(Vec::new()));
+
+    for key in "ab".chars() {
+        let record = Rc::clone(&record);
+        dispatcher.add_fn(Keyed { key, value: 0 }, move |event: &Keyed| {
+            record.write().push((event.key, event.value));
+
+            None
+        });
+    }
+
+    dispatcher.set_coalescing(Keyed { key: 'a', value: 0 }, Coalesce::KeepLast);
+    dispatcher.enqueue_event(Keyed { key: 'b', value: 1 });
+    dispatcher.enqueue_event(Keyed { key: 'a', value: 1 });
+    dispatcher.enqueue_event_with_priority(Keyed { key: 'a', value: 2 }, 5);
+    dispatcher.enqueue_event(Keyed { key: 'a', value: 3 });
+    assert_eq!(dispatcher.queued_len(), 2);
+
+    dispatcher.process_queue();
+    assert_eq!(*record.read(), [('a', 3), ('b', 1)]);
+}
@@ -0,0 +1,117 @@
+use hey_listen::{
+    rc::{Listener, PriorityDispatcher, SyncDispatcherRequest},
+    RwLock,
+};
+use std::rc::Rc;
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum Event {
+    EventType,
+}
+
+struct EventListener {
+    name: String,
+    name_record: Rc<RwLock<Vec<String>>>,
+    request: fn() -> Option<SyncDispatcherRequest>,
+}
+
+impl Listener<Event> for EventListener {
+    fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+        self.name_record.write().push(self.name.clone());
+
+        (self.request)()
+    }
+}
+
+fn new_listener(
+    name: &str,
+    name_record: &Rc<RwLock<Vec<String>>>,
+    request: fn() -> Option<SyncDispatcherRequest>,
+) -> Rc<RwLock<EventListener>> {
+    Rc::new(RwLock::new(EventListener {
+        name: name.to_string(),
+        name_record: Rc::clone(name_record),
+        request,
+    }))
+}
+
+/// **Intended test-behaviour**: Listeners with different priority-level
+/// shall be dispatched in order based on their level, the lower the earlier.
+///
+/// **Test**: We register listeners on three levels out of order and expect
+/// the record-book to list them by level, within a level by registration.
+#[test]
+fn listeners_dispatch_in_correct_order() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let last = new_listener("3", &names_record, || None);
+    let first_a = new_listener("1a", &names_record, || None);
+    let second = new_listener("2", &names_record, || None);
+    let first_b = new_listener("1b", &names_record, || None);
+
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    dispatcher.add_listener(Event::EventType, &last, 3);
+    dispatcher.add_listener(Event::EventType, &first_a, 1);
+    dispatcher.add_listener(Event::EventType, &second, 2);
+    dispatcher.add_listener(Event::EventType, &first_b, 1);
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.read(), ["1a", "1b", "2", "3"]);
+}
+
+/// **Intended test-behaviour**: Stop-requests behave like in the
+/// `sync`-`PriorityDispatcher`, stopping propagation also skips all
+/// following priority-levels.
+///
+/// **Test**: The first level's listener stops listening, the second level's
+/// listener stops propagation, thus the third level is never reached and
+/// the first listener is only called once.
+#[test]
+fn stop_requests_span_priority_levels() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let stop_listening = new_listener("1", &names_record, || {
+        Some(SyncDispatcherRequest::StopListening)
+    });
+    let stop_propagation = new_listener("2", &names_record, || {
+        Some(SyncDispatcherRequest::StopPropagation)
+    });
+    let never_reached = new_listener("3", &names_record, || None);
+
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    dispatcher.add_listener(Event::EventType, &never_reached, 3);
+    dispatcher.add_listener(Event::EventType, &stop_propagation, 2);
+    dispatcher.add_listener(Event::EventType, &stop_listening, 1);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.read(), ["1", "2", "2"]);
+}
+
+/// **Intended test-behaviour**: A filter rejecting an event skips the
+/// listener on its priority-level only, replacing the filter takes effect
+/// on the next dispatch.
+///
+/// **Test**: The first level's listener rejects every event until its
+/// filter is replaced, the second level is dispatched to in any case.
+#[test]
+fn filters_skip_single_priority_level() {
+    let names_record = Rc::new(RwLock::new(Vec::new()));
+    let first = new_listener("1", &names_record, || None);
+    let second = new_listener("2", &names_record, || None);
+
+    let mut dispatcher = PriorityDispatcher::<u32, Event>::default();
+    let handle = dispatcher.add_listener(Event::EventType, &first, 1);
+    dispatcher.add_listener(Event::EventType, &second, 2);
+
+    assert!(dispatcher.set_filter(handle, |_| false));
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(*names_record.read(), ["2"]);
+
+    assert!(dispatcher.set_filter(handle, |_| true));
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(*names_record.read(), ["2", "1", "2"]);
+
+    assert!(dispatcher.remove_listener(handle));
+    assert!(!dispatcher.set_filter(handle, |_| true));
+}
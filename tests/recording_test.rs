@@ -0,0 +1,204 @@
+use hey_listen::{
+    rc::{
+        replay_filtered_into, replay_into, Dispatcher, Emitter, Listener, RecordEntry, Recorder,
+        SyncDispatcherRequest,
+    },
+    RwLock,
+};
+use std::rc::Rc;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Event {
+    Deposit(u32),
+    Withdraw(u32),
+    Overdrawn,
+}
+
+/// Keeps a balance, emitting `Overdrawn` when it drops below zero.
+struct Account {
+    balance: i64,
+    history: Vec<String>,
+    emitter: Emitter<Event>,
+}
+
+impl Listener<Event> for Account {
+    fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> {
+        match event {
+            Event::Deposit(amount) => self.balance += i64::from(*amount),
+            Event::Withdraw(amount) => {
+                self.balance -= i64::from(*amount);
+
+                if self.balance < 0 {
+                    self.emitter.emit(Event::Overdrawn);
+                }
+            }
+            Event::Overdrawn => (),
+        }
+        self.history
+            .push(format!("{:?} -> {}", event, self.balance));
+
+        None
+    }
+}
+
+/// Wires an account listening to all events to `dispatcher`.
+fn open_account(dispatcher: &Dispatcher<Event>) -> Rc<RwLock<Account>> {
+    let account = Rc::new(RwLock::new(Account {
+        balance: 0,
+        history: Vec::new(),
+        emitter: dispatcher.emitter(),
+    }));
+    dispatcher.add_catch_all_listener(&account);
+
+    account
+}
+
+/// **Intended test-behaviour**: A recorded log replayed into fresh
+/// listeners produces the very same side effects, events emitted by
+/// listeners are recorded in dispatch-order and not duplicated.
+///
+/// **Test**: Deposits and withdrawals are dispatched to an account, one
+/// withdrawal emits `Overdrawn`. The log is replayed into a new account.
+#[test]
+fn replayed_log_reproduces_side_effects() {
+    let dispatcher = Dispatcher::<Event>::default();
+    let recorder = Recorder::attach(&dispatcher);
+    let account = open_account(&dispatcher);
+
+    dispatcher.dispatch_event(&Event::Deposit(10));
+    dispatcher.dispatch_event(&Event::Withdraw(15));
+    dispatcher.dispatch_event(&Event::Deposit(20));
+
+    let sequence_numbers: Vec<u64> = recorder
+        .entries()
+        .into_iter()
+        .map(|entry| entry.sequence)
+        .collect();
+    assert_eq!(sequence_numbers, [0, 1, 2, 3]);
+
+    let log = recorder.take_log();
+    assert_eq!(
+        log,
+        [
+            Event::Deposit(10),
+            Event::Withdraw(15),
+            Event::Overdrawn,
+            Event::Deposit(20),
+        ]
+    );
+    assert!(recorder.entries().is_empty());
+
+    let replayed = Dispatcher::<Event>::default();
+    let replayed_account = open_account(&replayed);
+    replayed.enqueue_event(Event::Deposit(1));
+
+    assert_eq!(replay_into(&replayed, &log), 4);
+    assert_eq!(replayed_account.read().history, account.read().history);
+    assert_eq!(replayed_account.read().balance, 15);
+    assert_eq!(replayed.queued_len(), 1);
+}
+
+/// **Intended test-behaviour**: Recorded entries convert into a plain
+/// format and back without losing payloads or sequence numbers.
+///
+/// **Test**: Entries carrying payloads are written as lines of text, parsed
+/// again and compared to the recorded ones.
+#[test]
+fn entries_round_trip_through_plain_text() {
+    let dispatcher = Dispatcher::<Event>::default();
+    let recorder = Recorder::attach(&dispatcher);
+    let _account = open_account(&dispatcher);
+
+    dispatcher.dispatch_event(&Event::Deposit(3));
+    dispatcher.dispatch_event(&Event::Withdraw(7));
+    let entries = recorder.take_entries();
+
+    let text: Vec<String> = entries
+        .iter()
+        .map(|entry| match entry.event {
+            Event::Deposit(amount) => format!("{} deposit {}", entry.sequence, amount),
+            Event::Withdraw(amount) => format!("{} withdraw {}", entry.sequence, amount),
+            Event::Overdrawn => format!("{} overdrawn", entry.sequence),
+        })
+        .collect();
+
+    let parsed: Vec<RecordEntry<Event>> = text
+        .iter()
+        .map(|line| {
+            let fields: Vec<&str> = line.split(' ').collect();
+            let amount = || fields[2].parse().unwrap();
+            let event = match fields[1] {
+                "deposit" => Event::Deposit(amount()),
+                "withdraw" => Event::Withdraw(amount()),
+                _ => Event::Overdrawn,
+            };
+
+            RecordEntry {
+                sequence: fields[0].parse().unwrap(),
+                event,
+            }
+        })
+        .collect();
+
+    assert_eq!(parsed, entries);
+    assert_eq!(parsed.len(), 3);
+}
+
+/// **Intended test-behaviour**: With the `serde`-feature, a log of entries
+/// serialises and deserialises along with its payloads, ready to be
+/// replayed.
+///
+/// **Test**: A session overdrawing an account is recorded, written as
+/// JSON, read back and replayed into a new account.
+#[cfg(feature = "serde")]
+#[test]
+fn entries_round_trip_through_serde() {
+    let dispatcher = Dispatcher::<Event>::default();
+    let recorder = Recorder::attach(&dispatcher);
+    let account = open_account(&dispatcher);
+
+    dispatcher.dispatch_event(&Event::Deposit(3));
+    dispatcher.dispatch_event(&Event::Withdraw(7));
+    let entries = recorder.take_entries();
+
+    let json = serde_json::to_string(&entries).unwrap();
+    let parsed: Vec<RecordEntry<Event>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, entries);
+
+    let log: Vec<Event> = parsed.into_iter().map(|entry| entry.event).collect();
+    let replayed = Dispatcher::<Event>::default();
+    let replayed_account = open_account(&replayed);
+
+    assert_eq!(replay_into(&replayed, &log), 3);
+    assert_eq!(replayed_account.read().history, account.read().history);
+}
+
+/// **Intended test-behaviour**: A filtered replay dispatches only the
+/// matching events of a log, in their original order.
+///
+/// **Test**: Only the deposits of a recorded session are replayed into a
+/// new account.
+#[test]
+fn filtered_replay_dispatches_matching_events_only() {
+    let dispatcher = Dispatcher::<Event>::default();
+    let recorder = Recorder::attach(&dispatcher);
+    let _account = open_account(&dispatcher);
+
+    for event in &[Event::Deposit(5), Event::Withdraw(9), Event::Deposit(2)] {
+        dispatcher.dispatch_event(event);
+    }
+    let log = recorder.take_log();
+    assert_eq!(log.len(), 4);
+
+    let replayed = Dispatcher::<Event>::default();
+    let replayed_account = open_account(&replayed);
+
+    let deposits =
+        replay_filtered_into(&replayed, &log, |event| matches!(event, Event::Deposit(_)));
+    assert_eq!(deposits, 2);
+    assert_eq!(
+        replayed_account.read().history,
+        ["Deposit(5) -> 5", "Deposit(2) -> 7"]
+    );
+}
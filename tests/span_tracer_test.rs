@@ -0,0 +1,319 @@
+#![cfg(feature = "tracing")]
+
+use hey_listen::{
+    sync::{
+        DispatchResult, DispatchTracer, Dispatcher, Listener, ListenerHandle, SpanTracer,
+        SyncDispatcherRequest, VetoReason, UNNAMED_LISTENER,
+    },
+    RwLock,
+};
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Event as TracingEvent, Metadata, Subscriber,
+};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Event {
+    Save,
+    Quit,
+}
+
+struct Audio;
+
+impl Listener<Event> for Audio {
+    fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+        Some(SyncDispatcherRequest::StopPropagation)
+    }
+}
+
+/// Collects the fields of spans and events as `name=value`.
+struct Fields(Vec<String>);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.push(format!("{}={:?}", field.name(), value));
+    }
+}
+
+/// Logs every span, record, event, enter and exit as one line.
+struct LogSubscriber {
+    next_id: AtomicU64,
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+impl LogSubscriber {
+    fn push(&self, kind: &str, fields: Fields) {
+        let mut line = vec![kind.to_string()];
+        line.extend(fields.0);
+        self.log.lock().unwrap().push(line.join(" "));
+    }
+}
+
+impl Subscriber for LogSubscriber {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = Fields(Vec::new());
+        span.record(&mut fields);
+        self.push(span.metadata().name(), fields);
+
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed))
+    }
+
+    fn record(&self, _span: &Id, values: &Record<'_>) {
+        let mut fields = Fields(Vec::new());
+        values.record(&mut fields);
+        self.push("record", fields);
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &TracingEvent<'_>) {
+        let mut fields = Fields(Vec::new());
+        event.record(&mut fields);
+        self.push("event", fields);
+    }
+
+    fn enter(&self, _span: &Id) {
+        self.push("enter", Fields(Vec::new()));
+    }
+
+    fn exit(&self, _span: &Id) {
+        self.push("exit", Fields(Vec::new()));
+    }
+}
+
+/// Runs `dispatch` with a subscriber logging to the returned lines.
+fn trace<F: FnOnce()>(dispatch: F) -> Vec<String> {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let subscriber = LogSubscriber {
+        next_id: AtomicU64::new(1),
+        log: Arc::clone(&log),
+    };
+
+    tracing::subscriber::with_default(subscriber, dispatch);
+
+    let lines = log.lock().unwrap().clone();
+    lines
+}
+
+/// **Intended test-behaviour**: A dispatch is wrapped in a `dispatch`-span
+/// carrying the listener count and the event, every called listener emits
+/// one event within it and the outcome is recorded on the span.
+///
+/// **Test**: A named listener stopping propagation and a closure listen to
+/// `Save`. We expect the span, one event for the listener only and the
+/// recorded outcome before the span is left.
+#[test]
+fn dispatch_is_wrapped_in_a_span() {
+    let audio = Arc::new(RwLock::new(Audio));
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    let handle = dispatcher.add_named_listener(Event::Save, "audio", &audio);
+    dispatcher.add_fn(Event::Save, Box::new(|_| None));
+    dispatcher.set_tracer(SpanTracer::new());
+
+    let lines = trace(|| dispatcher.dispatch_event(&Event::Save));
+
+    assert_eq!(
+        lines,
+        [
+            "dispatch listeners=2".to_string(),
+            "record event=Save".to_string(),
+            "enter".to_string(),
+            format!(
+                "event message=listener invoked handle={:?} name=\"audio\" \
+                 request=Some(StopPropagation)",
+                handle
+            ),
+            "record invoked=1".to_string(),
+            "record stopped=true".to_string(),
+            "exit".to_string(),
+        ]
+    );
+}
+
+/// **Intended test-behaviour**: A tracer created without payload leaves
+/// the event out of the span, unnamed listeners are reported as such.
+///
+/// **Test**: A closure listens to `Save`, the tracer has no payload.
+#[test]
+fn tracer_without_payload_omits_event() {
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    let handle = dispatcher.add_fn(Event::Save, Box::new(|_| None));
+    dispatcher.set_tracer(SpanTracer::without_payload());
+
+    let lines = trace(|| dispatcher.dispatch_event(&Event::Save));
+
+    assert_eq!(
+        lines,
+        [
+            "dispatch listeners=1".to_string(),
+            "enter".to_string(),
+            format!(
+                "event message=listener invoked handle={:?} name=\"<unnamed>\" request=None",
+                handle
+            ),
+            "record invoked=1".to_string(),
+            "record stopped=false".to_string(),
+            "exit".to_string(),
+        ]
+    );
+}
+
+/// **Intended test-behaviour**: Without setting a tracer, every dispatch
+/// is wrapped in a `dispatch`-span leaving out the event, clearing the
+/// tracer stops tracing.
+///
+/// **Test**: A closure listens to `Save` on a fresh dispatcher, which is
+/// dispatched once before and once after clearing the tracer.
+#[test]
+fn dispatch_is_traced_by_default() {
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    dispatcher.add_fn(Event::Save, Box::new(|_| None));
+
+    let lines = trace(|| dispatcher.dispatch_event(&Event::Save));
+
+    assert_eq!(lines[..2], ["dispatch listeners=1", "enter"]);
+    assert_eq!(lines.last().map(String::as_str), Some("exit"));
+
+    dispatcher.clear_tracer();
+
+    assert!(trace(|| dispatcher.dispatch_event(&Event::Save)).is_empty());
+}
+
+struct RecordingTracer(Arc<Mutex<Vec<String>>>);
+
+impl DispatchTracer<Event> for RecordingTracer {
+    fn enter(&self, _event: &Event, listeners: usize) {
+        self.0.lock().unwrap().push(format!("enter {}", listeners));
+    }
+
+    fn invoked(
+        &self,
+        _event: &Event,
+        _handle: ListenerHandle,
+        name: Option<&str>,
+        request: Option<&SyncDispatcherRequest>,
+    ) {
+        let stops = matches!(request, Some(SyncDispatcherRequest::StopPropagation));
+
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("{} {}", name.unwrap_or(UNNAMED_LISTENER), stops));
+    }
+
+    fn exit(&self, _event: &Event, result: DispatchResult) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("exit {} {}", result.invoked, result.stopped));
+    }
+}
+
+/// **Intended test-behaviour**: A tracer observes the start, every
+/// invocation and the end of dispatches, until it is cleared.
+///
+/// **Test**: A named listener stopping propagation and a closure listen
+/// to `Save`, nothing listens to `Quit`. Both events are dispatched once
+/// traced and `Save` once more after clearing the tracer.
+#[test]
+fn tracer_observes_dispatches() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let audio = Arc::new(RwLock::new(Audio));
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    dispatcher.add_fn(Event::Save, Box::new(|_| None));
+    dispatcher.add_named_listener(Event::Save, "audio", &audio);
+
+    dispatcher.set_tracer(RecordingTracer(Arc::clone(&log)));
+    dispatcher.dispatch_event(&Event::Save);
+    dispatcher.dispatch_event(&Event::Quit);
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        [
+            "enter 2",
+            "audio true",
+            "exit 1 true",
+            "enter 0",
+            "exit 0 false",
+        ]
+    );
+
+    dispatcher.clear_tracer();
+    dispatcher.dispatch_event(&Event::Save);
+    assert_eq!(log.lock().unwrap().len(), 5);
+}
+
+/// **Intended test-behaviour**: A dispatch ended by a panicking listener
+/// is still reported as exited, hence later dispatches are traced as
+/// their own.
+///
+/// **Test**: A closure returning and a closure panicking listen to
+/// `Save`, a closure listens to `Quit`. `Save` is dispatched catching
+/// the panic, then `Quit` is dispatched.
+#[test]
+fn panicking_listener_still_exits_dispatch() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    dispatcher.add_fn(Event::Save, Box::new(|_| None));
+    dispatcher.add_fn(Event::Save, Box::new(|_| panic!("misbehaving")));
+    dispatcher.add_fn(Event::Quit, Box::new(|_| None));
+    dispatcher.set_tracer(RecordingTracer(Arc::clone(&log)));
+
+    let dispatch = panic::catch_unwind(AssertUnwindSafe(|| {
+        dispatcher.dispatch_event(&Event::Save);
+    }));
+    assert!(dispatch.is_err());
+
+    dispatcher.dispatch_event(&Event::Quit);
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        [
+            "enter 2",
+            "<unnamed> false",
+            "exit 1 false",
+            "enter 1",
+            "<unnamed> false",
+            "exit 1 false",
+        ]
+    );
+}
+
+/// **Intended test-behaviour**: Vetoed events reach no listener and are
+/// not traced.
+///
+/// **Test**: A validator vetoes `Quit`, both variants are dispatched
+/// validated.
+#[test]
+fn vetoed_events_are_not_traced() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    dispatcher.add_fn(Event::Save, Box::new(|_| None));
+    dispatcher.add_fn(Event::Quit, Box::new(|_| panic!("event was vetoed")));
+    dispatcher.set_tracer(RecordingTracer(Arc::clone(&log)));
+    dispatcher.set_validator(|event| match event {
+        Event::Save => Ok(()),
+        Event::Quit => Err(VetoReason("not permitted".to_string())),
+    });
+
+    assert!(dispatcher.dispatch_event_validated(&Event::Save).is_ok());
+    assert!(dispatcher.dispatch_event_validated(&Event::Quit).is_err());
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        ["enter 1", "<unnamed> false", "exit 1 false"]
+    );
+}
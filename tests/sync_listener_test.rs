@@ -1,8 +1,15 @@
 use hey_listen::{
-    sync::{Dispatcher, Listener, SyncDispatcherRequest},
+    sync::{
+        Cancelable, ChannelForwarder, DispatchResult, Dispatcher, Listener, MiddlewareDecision,
+        SyncDispatcherRequest, ThrottlePolicy, VetoReason, UNNAMED_LISTENER,
+    },
     RwLock,
 };
-use std::{ops::Deref, sync::Arc};
+use std::{
+    ops::Deref,
+    sync::{mpsc::channel, Arc, Mutex},
+    time::{Duration, Instant},
+};
 
 #[derive(Clone, Eq, Hash, PartialEq)]
 enum Event {
@@ -48,9 +55,7 @@ fn dispatch_enum_variant_with_field() {
 
     dispatcher.dispatch_event(&Event::VariantA);
 
-    let enum_field = match *listener.write().deref() {
-        EnumListener::SomeVariant(x) => x,
-    };
+    let EnumListener::SomeVariant(enum_field) = *listener.write().deref();
 
     assert!(enum_field);
 }
@@ -168,6 +173,7 @@ fn register_one_listener_for_one_event_variant_but_dispatch_two_variants() {
     use std::hash::{Hash, Hasher};
     use std::mem::discriminant;
 
+    #[allow(dead_code)]
     #[derive(Clone, Eq)]
     enum Event {
         VariantA(i32),
@@ -308,7 +314,7 @@ fn stop_listening_and_propagation_on_sync_dispatcher() {
 fn stop_listening_on_sync_dispatcher_of_fns() {
     struct EventListener {
         use_counter: usize,
-    };
+    }
 
     let listener = Arc::new(RwLock::new(EventListener { use_counter: 0 }));
 
@@ -348,7 +354,7 @@ fn stop_listening_on_sync_dispatcher_of_fns() {
 fn stop_propagation_on_sync_dispatcher_of_fns() {
     struct EventListener {
         use_counter: usize,
-    };
+    }
 
     let listener = Arc::new(RwLock::new(EventListener { use_counter: 0 }));
 
@@ -388,7 +394,7 @@ fn stop_propagation_on_sync_dispatcher_of_fns() {
 fn stop_propagation_and_listening_on_sync_dispatcher_of_fns() {
     struct EventListener {
         use_counter: usize,
-    };
+    }
 
     let listener = Arc::new(RwLock::new(EventListener { use_counter: 0 }));
 
@@ -430,6 +436,1544 @@ fn stop_propagation_and_listening_on_sync_dispatcher_of_fns() {
 
 #[test]
 fn is_send_and_sync() {
-    fn assert_send<T: Send + Sync>(_: &T) {};
+    fn assert_send<T: Send + Sync>(_: &T) {}
     assert_send(&Dispatcher::<Event>::default());
 }
+
+#[test]
+fn extend_registers_all_pairs() {
+    let listener = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let erased_listener: Arc<RwLock<dyn Listener<Event> + Send + Sync>> = listener.clone();
+
+    let mut dispatcher = Dispatcher::<Event>::default();
+    dispatcher.extend(vec![
+        (Event::VariantA, Arc::clone(&erased_listener)),
+        (Event::VariantB, Arc::clone(&erased_listener)),
+    ]);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert!(listener.read().received_variant_a);
+    assert!(!listener.read().received_variant_b);
+
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert!(listener.read().received_variant_b);
+}
+
+/// **Intended test-behaviour**: Extending only keeps weak references,
+/// listeners owned by nothing but the iterator are gone right away.
+///
+/// **Test**: Extend the dispatcher by a listener created inside the
+/// iterator and dispatch to it.
+#[test]
+fn extend_does_not_own_listeners() {
+    let mut dispatcher = Dispatcher::<Event>::default();
+    dispatcher.extend((0..1).map(|_| {
+        let listener: Arc<RwLock<dyn Listener<Event> + Send + Sync>> =
+            Arc::new(RwLock::new(EventListener {
+                received_variant_a: false,
+                received_variant_b: false,
+            }));
+
+        (Event::VariantA, listener)
+    }));
+
+    assert_eq!(
+        dispatcher
+            .dispatch_event_with_result(&Event::VariantA)
+            .invoked,
+        0
+    );
+}
+
+#[test]
+fn dispatch_event_handles_returns_called_listeners() {
+    let listener = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let dropped_listener = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+
+    let mut dispatcher = Dispatcher::<Event>::default();
+    let listener_handle = dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.add_listener(Event::VariantA, &dropped_listener);
+    let fn_handle = dispatcher.add_fn(
+        Event::VariantA,
+        Box::new(|_| Some(SyncDispatcherRequest::StopListening)),
+    );
+    dispatcher.add_fn(Event::VariantB, Box::new(|_| None));
+    drop(dropped_listener);
+
+    assert_eq!(
+        dispatcher.dispatch_event_handles(&Event::VariantA),
+        [listener_handle, fn_handle]
+    );
+    assert_eq!(
+        dispatcher.dispatch_event_handles(&Event::VariantA),
+        [listener_handle]
+    );
+}
+
+#[test]
+fn dispatch_with_custom_hasher() {
+    use std::{collections::hash_map::DefaultHasher, hash::BuildHasherDefault};
+
+    let listener = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+
+    let mut dispatcher: Dispatcher<Event, BuildHasherDefault<DefaultHasher>> =
+        Dispatcher::with_hasher(BuildHasherDefault::default());
+    dispatcher.add_listener(Event::VariantA, &listener);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert!(listener.read().received_variant_a);
+    assert!(!listener.read().received_variant_b);
+}
+
+#[test]
+fn ordering_is_applied_on_every_dispatch() {
+    use hey_listen::sync::ListenerHandle;
+    use std::collections::HashMap;
+
+    let depths: Arc<RwLock<HashMap<ListenerHandle, i32>>> = Arc::new(RwLock::new(HashMap::new()));
+    let mut dispatcher = Dispatcher::<Event>::default();
+
+    let first = dispatcher.add_fn(Event::VariantA, Box::new(|_| None));
+    let second = dispatcher.add_fn(Event::VariantA, Box::new(|_| None));
+    let third = dispatcher.add_fn(Event::VariantA, Box::new(|_| None));
+
+    assert_eq!(
+        dispatcher.dispatch_event_handles(&Event::VariantA),
+        [first, second, third]
+    );
+
+    let comparator_depths = Arc::clone(&depths);
+    dispatcher.set_ordering(move |a, b| {
+        let depths = comparator_depths.read();
+        depths[&a].cmp(&depths[&b])
+    });
+
+    depths
+        .write()
+        .extend(vec![(first, 2), (second, 0), (third, 1)]);
+    assert_eq!(
+        dispatcher.dispatch_event_handles(&Event::VariantA),
+        [second, third, first]
+    );
+
+    depths
+        .write()
+        .extend(vec![(first, 0), (second, 1), (third, 2)]);
+    assert_eq!(
+        dispatcher.dispatch_event_handles(&Event::VariantA),
+        [first, second, third]
+    );
+}
+
+#[test]
+fn remove_listener_by_handle() {
+    let mut dispatcher = Dispatcher::<Event>::default();
+
+    let first = dispatcher.add_fn(Event::VariantA, Box::new(|_| None));
+    let second = dispatcher.add_fn(Event::VariantA, Box::new(|_| None));
+    let third = dispatcher.add_fn(Event::VariantA, Box::new(|_| None));
+
+    assert!(dispatcher.remove_listener(first));
+    assert!(!dispatcher.remove_listener(first));
+
+    assert_eq!(
+        dispatcher.dispatch_event_handles(&Event::VariantA),
+        [second, third]
+    );
+}
+
+#[test]
+fn panicking_listener_does_not_poison() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    #[derive(Default)]
+    struct PanickingListener {
+        times_dispatched: usize,
+    }
+
+    impl Listener<Event> for PanickingListener {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            self.times_dispatched += 1;
+
+            if self.times_dispatched == 1 {
+                panic!("first dispatch fails");
+            }
+
+            None
+        }
+    }
+
+    let listener = Arc::new(RwLock::new(PanickingListener::default()));
+    let mut dispatcher = Dispatcher::<Event>::default();
+    dispatcher.add_listener(Event::VariantA, &listener);
+
+    let first_dispatch = catch_unwind(AssertUnwindSafe(|| {
+        dispatcher.dispatch_event(&Event::VariantA)
+    }));
+    assert!(first_dispatch.is_err());
+
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert_eq!(listener.try_read().unwrap().times_dispatched, 2);
+}
+
+#[test]
+fn dispatch_result_counts_invoked_listeners() {
+    let mut dispatcher = Dispatcher::<Event>::default();
+    dispatcher.add_fn(Event::VariantA, Box::new(|_| None));
+    dispatcher.add_fn(Event::VariantA, Box::new(|_| None));
+
+    let result = dispatcher.dispatch_event_with_result(&Event::VariantA);
+    assert_eq!(result.invoked, 2);
+    assert!(!result.stopped);
+
+    assert_eq!(
+        dispatcher
+            .dispatch_event_with_result(&Event::VariantB)
+            .invoked,
+        0
+    );
+}
+
+#[test]
+fn is_live_tells_dropped_from_unregistered() {
+    let listener = Arc::new(RwLock::new(EnumListener::SomeVariant(false)));
+    let mut dispatcher = Dispatcher::<Event>::default();
+    let listener_handle = dispatcher.add_listener(Event::VariantA, &listener);
+    let fn_handle = dispatcher.add_fn(Event::VariantB, Box::new(|_| None));
+
+    assert!(dispatcher.is_live(listener_handle));
+    assert!(dispatcher.is_live(fn_handle));
+
+    drop(listener);
+
+    assert!(dispatcher.has_listener(listener_handle));
+    assert!(!dispatcher.is_live(listener_handle));
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert!(!dispatcher.has_listener(listener_handle));
+
+    dispatcher.remove_listener(fn_handle);
+    assert!(!dispatcher.has_listener(fn_handle));
+    assert!(!dispatcher.is_live(fn_handle));
+}
+
+#[test]
+fn pre_dispatch_rewrites_and_swallows_events() {
+    let listener = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let mut dispatcher = Dispatcher::<Event>::default();
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.add_listener(Event::VariantB, &listener);
+
+    dispatcher.set_pre_dispatch(|_| None);
+    dispatcher.set_pre_dispatch(|event| match event {
+        Event::VariantA => Some(Event::VariantB),
+        Event::VariantB => None,
+    });
+
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert!(!listener.read().received_variant_b);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert!(!listener.read().received_variant_a);
+    assert!(listener.read().received_variant_b);
+
+    dispatcher.clear_pre_dispatch();
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert!(listener.read().received_variant_a);
+}
+
+#[test]
+fn cloned_dispatcher_shares_listeners() {
+    struct CountingListener {
+        times_dispatched: usize,
+    }
+
+    impl Listener<Event> for CountingListener {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            self.times_dispatched += 1;
+
+            None
+        }
+    }
+
+    let listener = Arc::new(RwLock::new(CountingListener {
+        times_dispatched: 0,
+    }));
+    let mut dispatcher = Dispatcher::<Event>::default();
+    let handle = dispatcher.add_listener(Event::VariantA, &listener);
+
+    let mut fork = dispatcher.clone();
+    assert_eq!(Arc::strong_count(&listener), 1);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    fork.dispatch_event(&Event::VariantA);
+    assert_eq!(listener.read().times_dispatched, 2);
+
+    let fork_handle = fork.add_fn(Event::VariantA, Box::new(|_| None));
+    assert!(fork.remove_listener(handle));
+    assert!(dispatcher.has_listener(handle));
+    assert!(!dispatcher.has_listener(fork_handle));
+
+    fork.dispatch_event(&Event::VariantA);
+    assert_eq!(listener.read().times_dispatched, 2);
+}
+
+#[test]
+fn feed_event_dispatches_mapped_variants() {
+    enum KeyboardInput {
+        Pressed(char),
+        Released,
+    }
+
+    fn map_input(input: &KeyboardInput) -> Option<Event> {
+        match input {
+            KeyboardInput::Pressed('a') => Some(Event::VariantA),
+            KeyboardInput::Pressed('b') => Some(Event::VariantB),
+            _ => None,
+        }
+    }
+
+    let listener = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let mut dispatcher = Dispatcher::<Event>::default();
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.add_listener(Event::VariantB, &listener);
+
+    assert!(!dispatcher.feed_event(&KeyboardInput::Released, map_input));
+    assert!(!listener.read().received_variant_a);
+
+    assert!(dispatcher.feed_event(&KeyboardInput::Pressed('b'), map_input));
+    assert!(!listener.read().received_variant_a);
+    assert!(listener.read().received_variant_b);
+}
+
+#[test]
+fn iter_listeners_yields_live_listeners_in_order() {
+    let listener_a = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let listener_b = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let dropped = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let mut dispatcher = Dispatcher::<Event>::default();
+    dispatcher.add_listener(Event::VariantA, &listener_a);
+    dispatcher.add_listener(Event::VariantA, &dropped);
+    dispatcher.add_listener(Event::VariantA, &listener_b);
+    drop(dropped);
+
+    let listeners: Vec<_> = dispatcher.iter_listeners(&Event::VariantA).collect();
+    assert_eq!(listeners.len(), 2);
+    assert!(Arc::ptr_eq(
+        &listeners[0],
+        &(Arc::clone(&listener_a) as Arc<RwLock<dyn Listener<Event> + Send + Sync>>)
+    ));
+    assert!(Arc::ptr_eq(
+        &listeners[1],
+        &(Arc::clone(&listener_b) as Arc<RwLock<dyn Listener<Event> + Send + Sync>>)
+    ));
+    assert_eq!(dispatcher.iter_listeners(&Event::VariantB).count(), 0);
+
+    drop(listener_a);
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(dispatcher.iter_listeners(&Event::VariantA).count(), 2);
+}
+
+#[test]
+fn downcast_listener_finds_concrete_type() {
+    use hey_listen::sync::downcast_listener;
+
+    struct OtherListener;
+
+    impl Listener<Event> for OtherListener {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            None
+        }
+    }
+
+    let listener = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let other = Arc::new(RwLock::new(OtherListener));
+    let mut dispatcher = Dispatcher::<Event>::default();
+    dispatcher.add_listener(Event::VariantA, &other);
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    let listeners: Vec<_> = dispatcher.iter_listeners(&Event::VariantA).collect();
+    assert!(downcast_listener::<EventListener, _>(Arc::clone(&listeners[0])).is_none());
+
+    let downcast = downcast_listener::<EventListener, _>(Arc::clone(&listeners[1])).unwrap();
+    assert!(Arc::ptr_eq(&downcast, &listener));
+    assert!(downcast.read().received_variant_a);
+    assert!(downcast_listener::<OtherListener, _>(Arc::clone(&listeners[0])).is_some());
+}
+
+/// **Intended test-behaviour**: Downcasting a listener locked for writing
+/// returns `None` instead of deadlocking, and listeners need not be
+/// `'static`.
+///
+/// **Test**: A listener downcasts itself from within its own `on_event`
+/// and records the result. A listener borrowing a counter is called
+/// directly.
+#[test]
+fn downcast_listener_from_own_on_event_does_not_deadlock() {
+    use hey_listen::sync::downcast_listener;
+    use std::sync::Weak;
+
+    struct SelfInspecting {
+        me: Weak<RwLock<dyn Listener<Event> + Send + Sync>>,
+        found_itself: Option<bool>,
+    }
+
+    impl Listener<Event> for SelfInspecting {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            let me = self.me.upgrade().unwrap();
+            self.found_itself = Some(downcast_listener::<SelfInspecting, _>(me).is_some());
+
+            None
+        }
+    }
+
+    struct Borrowing<'a> {
+        count: &'a mut usize,
+    }
+
+    impl Listener<Event> for Borrowing<'_> {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            *self.count += 1;
+
+            None
+        }
+    }
+
+    let listener = Arc::new(RwLock::new(SelfInspecting {
+        me: Weak::<RwLock<SelfInspecting>>::new(),
+        found_itself: None,
+    }));
+    listener.write().me =
+        Arc::downgrade(&listener) as Weak<RwLock<dyn Listener<Event> + Send + Sync>>;
+    let mut dispatcher = Dispatcher::<Event>::default();
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert_eq!(listener.read().found_itself, Some(false));
+
+    let mut count = 0;
+    Borrowing { count: &mut count }.on_event(&Event::VariantA);
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn duplicate_policy_decides_on_double_registration() {
+    use hey_listen::sync::{DuplicatePolicy, RegisterError};
+
+    let listener = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let mut dispatcher = Dispatcher::<Event>::default();
+
+    let first = dispatcher.add_listener(Event::VariantA, &listener);
+    let second = dispatcher.add_listener(Event::VariantA, &listener);
+    assert!(first != second);
+    assert_eq!(
+        dispatcher
+            .dispatch_event_with_result(&Event::VariantA)
+            .invoked,
+        2
+    );
+
+    dispatcher.set_duplicate_policy(DuplicatePolicy::Ignore);
+    assert_eq!(dispatcher.add_listener(Event::VariantA, &listener), first);
+    assert!(dispatcher.remove_listener(second));
+    assert_eq!(dispatcher.add_listener(Event::VariantA, &listener), first);
+    let other_event = dispatcher.add_listener(Event::VariantB, &listener);
+    assert!(other_event != first);
+
+    dispatcher.set_duplicate_policy(DuplicatePolicy::Error);
+    assert_eq!(
+        dispatcher.try_add_listener(Event::VariantB, &listener),
+        Err(RegisterError::Duplicate(other_event))
+    );
+    assert_eq!(
+        dispatcher
+            .dispatch_event_with_result(&Event::VariantA)
+            .invoked,
+        1
+    );
+}
+
+#[test]
+fn dispatch_events_dedup_calls_shared_listeners_once() {
+    struct CountingListener {
+        received: Vec<Event>,
+    }
+
+    impl Listener<Event> for CountingListener {
+        fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> {
+            self.received.push(event.clone());
+
+            None
+        }
+    }
+
+    let shared = Arc::new(RwLock::new(CountingListener { received: vec![] }));
+    let only_b = Arc::new(RwLock::new(CountingListener { received: vec![] }));
+    let mut dispatcher = Dispatcher::<Event>::default();
+
+    dispatcher.add_listener(Event::VariantA, &shared);
+    dispatcher.add_listener(Event::VariantB, &shared);
+    dispatcher.add_listener(Event::VariantB, &only_b);
+    dispatcher.add_fn(Event::VariantA, Box::new(|_| None));
+    dispatcher.add_fn(Event::VariantB, Box::new(|_| None));
+
+    let batch = [Event::VariantA, Event::VariantB, Event::VariantA];
+    assert_eq!(dispatcher.dispatch_events_dedup(&batch), 4);
+    assert!(shared.read().received == [Event::VariantA]);
+    assert!(only_b.read().received == [Event::VariantB]);
+
+    assert_eq!(dispatcher.dispatch_events_dedup(&batch), 4);
+    assert_eq!(shared.read().received.len(), 2);
+}
+
+#[test]
+fn subscription_receives_events_until_dropped() {
+    use std::sync::mpsc::TryRecvError;
+
+    let mut dispatcher = Dispatcher::<Event>::default();
+    let subscription = dispatcher.subscribe(Event::VariantA);
+    let dropped = dispatcher.subscribe(Event::VariantA);
+    let dropped_handle = dropped.handle();
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert!(subscription.try_recv().unwrap() == Event::VariantA);
+    assert!(subscription.try_recv().err() == Some(TryRecvError::Empty));
+
+    drop(dropped);
+    assert!(dispatcher.has_listener(dropped_handle));
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert!(!dispatcher.has_listener(dropped_handle));
+    assert_eq!(subscription.drain().count(), 2);
+
+    assert!(dispatcher.remove_listener(subscription.handle()));
+    assert!(subscription.recv().is_err());
+}
+
+#[test]
+fn sender_enqueues_events_until_polled() {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        thread,
+    };
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = Dispatcher::<Event>::with_channel_capacity(4);
+    let fn_calls = Arc::clone(&calls);
+    dispatcher.add_fn(
+        Event::VariantA,
+        Box::new(move |_| {
+            fn_calls.fetch_add(1, Ordering::SeqCst);
+
+            None
+        }),
+    );
+
+    assert_eq!(dispatcher.poll(), 0);
+
+    let workers: Vec<_> = (0..2)
+        .map(|_| {
+            let sender = dispatcher.sender();
+
+            thread::spawn(move || {
+                sender.send(Event::VariantA).unwrap();
+                sender.send(Event::VariantB).unwrap();
+            })
+        })
+        .collect();
+
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    assert_eq!(dispatcher.poll(), 4);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    dispatcher.sender().send(Event::VariantA).unwrap();
+    assert_eq!(dispatcher.poll(), 1);
+    assert_eq!(dispatcher.poll(), 0);
+}
+
+#[test]
+fn chain_runs_both_and_merges_requests() {
+    use hey_listen::sync::chain;
+
+    struct Scripted {
+        calls: usize,
+        request: fn() -> Option<SyncDispatcherRequest>,
+    }
+
+    impl Listener<Event> for Scripted {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            self.calls += 1;
+
+            (self.request)()
+        }
+    }
+
+    fn scripted(request: fn() -> Option<SyncDispatcherRequest>) -> Scripted {
+        Scripted { calls: 0, request }
+    }
+
+    let mut chained = chain(
+        scripted(|| Some(SyncDispatcherRequest::StopPropagation)),
+        scripted(|| None),
+    );
+    assert!(matches!(
+        chained.on_event(&Event::VariantA),
+        Some(SyncDispatcherRequest::StopPropagation)
+    ));
+    assert_eq!((chained.first.calls, chained.second.calls), (1, 1));
+
+    let mut chained = chain(
+        scripted(|| Some(SyncDispatcherRequest::StopPropagation)),
+        scripted(|| Some(SyncDispatcherRequest::Handled)),
+    );
+    assert!(matches!(
+        chained.on_event(&Event::VariantA),
+        Some(SyncDispatcherRequest::Handled)
+    ));
+
+    let mut chained = chain(
+        scripted(|| Some(SyncDispatcherRequest::Handled)),
+        scripted(|| Some(SyncDispatcherRequest::StopListening)),
+    );
+    assert!(matches!(
+        chained.on_event(&Event::VariantA),
+        Some(SyncDispatcherRequest::StopListeningAndPropagation)
+    ));
+
+    let listener = Arc::new(RwLock::new(chain(
+        scripted(|| Some(SyncDispatcherRequest::StopListening)),
+        scripted(|| None),
+    )));
+    let mut dispatcher = Dispatcher::<Event>::default();
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    let listener = listener.read();
+    assert_eq!((listener.first.calls, listener.second.calls), (1, 1));
+}
+
+/// Keyed by `key` only, so events carrying different values replace each
+/// other as sticky event.
+#[derive(Clone)]
+struct Setting {
+    key: &'static str,
+    value: u32,
+}
+
+impl std::hash::Hash for Setting {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
+
+impl PartialEq for Setting {
+    fn eq(&self, other: &Setting) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Setting {}
+
+struct SettingListener {
+    values: Vec<u32>,
+    request: Option<fn() -> SyncDispatcherRequest>,
+}
+
+impl Listener<Setting> for SettingListener {
+    fn on_event(&mut self, event: &Setting) -> Option<SyncDispatcherRequest> {
+        self.values.push(event.value);
+
+        self.request.map(|request| request())
+    }
+}
+
+/// **Intended test-behaviour**: Listeners added after a sticky event
+/// receive the most recent one right away and honour returned requests.
+///
+/// **Test**: Two sticky values are dispatched before a listener is added,
+/// a listener stopping to listen is removed after the replay and a cleared
+/// sticky event is not replayed.
+#[test]
+fn sticky_events_are_replayed_to_late_listeners() {
+    let setting = |value| Setting {
+        key: "volume",
+        value,
+    };
+    let mut dispatcher = Dispatcher::<Setting>::default();
+    dispatcher.dispatch_sticky(setting(1));
+    dispatcher.dispatch_sticky(setting(2));
+
+    let late = Arc::new(RwLock::new(SettingListener {
+        values: Vec::new(),
+        request: None,
+    }));
+    dispatcher.add_listener_sticky(setting(0), &late);
+    assert_eq!(late.read().values, [2]);
+
+    let once = Arc::new(RwLock::new(SettingListener {
+        values: Vec::new(),
+        request: Some(|| SyncDispatcherRequest::StopListening),
+    }));
+    dispatcher.add_listener_sticky(setting(0), &once);
+    dispatcher.dispatch_sticky(setting(3));
+    assert_eq!(late.read().values, [2, 3]);
+    assert_eq!(once.read().values, [2]);
+
+    assert_eq!(
+        dispatcher.clear_sticky(&setting(0)).map(|s| s.value),
+        Some(3)
+    );
+    let cleared = Arc::new(RwLock::new(SettingListener {
+        values: Vec::new(),
+        request: None,
+    }));
+    dispatcher.add_listener_sticky(setting(0), &cleared);
+    assert!(cleared.read().values.is_empty());
+}
+
+/// **Intended test-behaviour**: A mapped dispatcher converts events before
+/// forwarding them to the wrapped dispatcher's unchanged listeners.
+///
+/// **Test**: Numbers map to event-variants by parity, zero is dropped.
+#[test]
+fn mapped_dispatcher_converts_and_drops_events() {
+    let listener = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let mut dispatcher = Dispatcher::<Event>::default();
+    dispatcher.add_listener(Event::VariantA, &listener);
+    let handle = dispatcher.add_listener(Event::VariantB, &listener);
+
+    let mut mapped = dispatcher.map(|number: &u32| match number {
+        0 => None,
+        number if number % 2 == 0 => Some(Event::VariantA),
+        _ => Some(Event::VariantB),
+    });
+
+    assert!(!mapped.dispatch_event(&0));
+    assert!(!listener.read().received_variant_a && !listener.read().received_variant_b);
+
+    assert!(mapped.dispatch_event(&4));
+    assert!(listener.read().received_variant_a && !listener.read().received_variant_b);
+
+    assert!(mapped.dispatch_event(&7));
+    assert!(listener.read().received_variant_b);
+    assert!(mapped.into_inner().has_listener(handle));
+}
+
+/// **Intended test-behaviour**: Only the latest event of a debounced burst
+/// is delivered, once its window has elapsed, other keys stay unaffected.
+///
+/// **Test**: A burst of volume-settings is dispatched and flushed before and
+/// after the window, events without debounce-window reach listeners at once.
+#[test]
+fn debounced_events_deliver_latest_after_window() {
+    let setting = |key, value| Setting { key, value };
+    let volume = Arc::new(RwLock::new(SettingListener {
+        values: Vec::new(),
+        request: None,
+    }));
+    let brightness = Arc::new(RwLock::new(SettingListener {
+        values: Vec::new(),
+        request: None,
+    }));
+    let mut dispatcher = Dispatcher::<Setting>::default();
+    dispatcher.add_listener(setting("volume", 0), &volume);
+    dispatcher.add_listener(setting("brightness", 0), &brightness);
+
+    let window = Duration::from_secs(60);
+    dispatcher.set_debounce(setting("volume", 0), window);
+
+    for value in 1..=3 {
+        dispatcher.dispatch_event(&setting("volume", value));
+        dispatcher.dispatch_event(&setting("brightness", value));
+    }
+    assert!(volume.read().values.is_empty());
+    assert_eq!(brightness.read().values, [1, 2, 3]);
+
+    assert_eq!(dispatcher.flush_debounced(Instant::now()), 0);
+    assert_eq!(dispatcher.flush_debounced(Instant::now() + window), 1);
+    assert_eq!(volume.read().values, [3]);
+    assert_eq!(dispatcher.flush_debounced(Instant::now() + window), 0);
+
+    dispatcher.dispatch_event(&setting("volume", 4));
+    assert_eq!(
+        dispatcher
+            .remove_debounce(&setting("volume", 0))
+            .map(|s| s.value),
+        Some(4)
+    );
+    dispatcher.dispatch_event(&setting("volume", 5));
+    assert_eq!(volume.read().values, [3, 5]);
+}
+
+/// **Intended test-behaviour**: Throttled events are delivered at most once
+/// per interval, suppressed ones dropped or the latest delivered once the
+/// interval has passed, other keys stay unaffected.
+///
+/// **Test**: A fake clock advances in 5ms-steps while volume-settings are
+/// dispatched, throttled by 16ms with and without trailing delivery.
+#[test]
+fn throttled_events_are_rate_limited() {
+    let setting = |key, value| Setting { key, value };
+    let start = Instant::now();
+    let fake_now = Arc::new(Mutex::new(start));
+    let clock = Arc::clone(&fake_now);
+    let millis = Duration::from_millis;
+
+    let volume = Arc::new(RwLock::new(SettingListener {
+        values: Vec::new(),
+        request: None,
+    }));
+    let brightness = Arc::new(RwLock::new(SettingListener {
+        values: Vec::new(),
+        request: None,
+    }));
+    let mut dispatcher = Dispatcher::<Setting>::default();
+    dispatcher.set_clock(move || *clock.lock().unwrap());
+    dispatcher.add_listener(setting("volume", 0), &volume);
+    dispatcher.add_listener(setting("brightness", 0), &brightness);
+    dispatcher.set_throttle(setting("volume", 0), millis(16), ThrottlePolicy::Drop);
+
+    for value in 0..8 {
+        *fake_now.lock().unwrap() = start + millis(5 * value as u64);
+        dispatcher.dispatch_event(&setting("volume", value));
+        dispatcher.dispatch_event(&setting("brightness", value));
+    }
+    assert_eq!(volume.read().values, [0, 4]);
+    assert_eq!(brightness.read().values.len(), 8);
+    assert_eq!(dispatcher.flush_throttled(start + millis(100)), 0);
+
+    dispatcher.set_throttle(setting("volume", 0), millis(16), ThrottlePolicy::Trailing);
+    volume.write().values.clear();
+
+    for value in 8..11 {
+        *fake_now.lock().unwrap() = start + millis(5 * value as u64);
+        dispatcher.dispatch_event(&setting("volume", value));
+    }
+    assert_eq!(volume.read().values, [8]);
+
+    assert_eq!(dispatcher.flush_throttled(start + millis(50)), 0);
+    assert_eq!(dispatcher.flush_throttled(start + millis(56)), 1);
+    assert_eq!(volume.read().values, [8, 10]);
+    assert_eq!(dispatcher.flush_throttled(start + millis(100)), 0);
+}
+
+/// **Intended test-behaviour**: Closures are listeners themselves and can
+/// be registered and chained like any other listener.
+///
+/// **Test**: A counting closure is registered via `add_listener` along
+/// with a struct-listener and stops listening on its second call.
+#[test]
+fn closures_are_listeners() {
+    let mut calls = 0;
+    let closure = Arc::new(RwLock::new(move |_event: &Event| {
+        calls += 1;
+
+        if calls == 2 {
+            Some(SyncDispatcherRequest::StopListening)
+        } else {
+            None
+        }
+    }));
+    let listener = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+
+    let mut dispatcher = Dispatcher::<Event>::default();
+    let handle = dispatcher.add_listener(Event::VariantA, &closure);
+    dispatcher.add_listener(Event::VariantA, &listener);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert!(!dispatcher.has_listener(handle));
+    assert!(listener.read().received_variant_a);
+}
+
+/// **Intended test-behaviour**: Forwarded events reach the target's
+/// listeners unchanged, forwarding in a cycle panics instead of
+/// deadlocking.
+///
+/// **Test**: A subsystem forwards volume-settings to a global dispatcher,
+/// which forwards them back once the cycle is closed. Dropping the
+/// subsystem ends the global dispatcher's forwarding.
+#[test]
+fn forwarding_to_another_dispatcher() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let setting = |value| Setting {
+        key: "volume",
+        value,
+    };
+    let global = Arc::new(RwLock::new(Dispatcher::<Setting>::default()));
+    let subsystem = Arc::new(RwLock::new(Dispatcher::<Setting>::default()));
+    let listener = Arc::new(RwLock::new(SettingListener {
+        values: Vec::new(),
+        request: None,
+    }));
+    global.write().add_listener(setting(0), &listener);
+    subsystem.write().add_forwarding_target(setting(0), &global);
+
+    subsystem.write().dispatch_event(&setting(7));
+    subsystem.write().dispatch_event(&Setting {
+        key: "brightness",
+        value: 3,
+    });
+    assert_eq!(listener.read().values, [7]);
+
+    let backwards = global.write().add_forwarding_target(setting(0), &subsystem);
+    let cycle = catch_unwind(AssertUnwindSafe(|| {
+        subsystem.write().dispatch_event(&setting(8))
+    }));
+    assert!(cycle.is_err());
+    assert_eq!(listener.read().values, [7, 8]);
+
+    drop(subsystem);
+    global.write().dispatch_event(&setting(9));
+    assert!(!global.read().has_listener(backwards));
+    assert_eq!(listener.read().values, [7, 8, 9]);
+}
+
+/// **Intended test-behaviour**: Disabled listeners stay registered but are
+/// neither called nor counted, until they are enabled again.
+///
+/// **Test**: A debug overlay is toggled off and on repeatedly between two
+/// other closures, its calls are recorded in calling-order.
+#[test]
+fn toggled_listener_keeps_its_position() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = Dispatcher::<Event>::default();
+    let mut handles = Vec::new();
+
+    for name in &["scene", "overlay", "hud"] {
+        let record = Arc::clone(&record);
+
+        handles.push(dispatcher.add_fn(
+            Event::VariantA,
+            Box::new(move |_| {
+                record.lock().unwrap().push(*name);
+
+                None
+            }),
+        ));
+    }
+    let overlay = handles[1];
+
+    for enabled in &[false, true, false, true] {
+        assert!(dispatcher.set_listener_enabled(overlay, *enabled));
+        assert_eq!(
+            dispatcher
+                .dispatch_event_with_result(&Event::VariantA)
+                .invoked,
+            if *enabled { 3 } else { 2 }
+        );
+    }
+    assert!(dispatcher.has_listener(overlay));
+    assert_eq!(
+        *record.lock().unwrap(),
+        ["scene", "hud", "scene", "overlay", "hud", "scene", "hud", "scene", "overlay", "hud"]
+    );
+
+    assert!(dispatcher.remove_listener(overlay));
+    assert!(!dispatcher.set_listener_enabled(overlay, true));
+}
+
+/// **Intended test-behaviour**: A listener of another event-type receives
+/// projected events only and its requests reach the dispatcher.
+///
+/// **Test**: An audio-listener is registered for volume-settings, odd
+/// values project to `None`. It stops listening after two sounds.
+#[test]
+fn mapped_listener_receives_projected_events() {
+    #[derive(Clone, Eq, Hash, PartialEq)]
+    enum AudioEvent {
+        Play(u32),
+    }
+
+    struct AudioListener {
+        played: Vec<u32>,
+    }
+
+    impl Listener<AudioEvent> for AudioListener {
+        fn on_event(&mut self, event: &AudioEvent) -> Option<SyncDispatcherRequest> {
+            let AudioEvent::Play(sound) = event;
+            self.played.push(*sound);
+
+            if self.played.len() == 2 {
+                Some(SyncDispatcherRequest::StopListening)
+            } else {
+                None
+            }
+        }
+    }
+
+    let setting = |value| Setting {
+        key: "volume",
+        value,
+    };
+    let audio = Arc::new(RwLock::new(AudioListener { played: Vec::new() }));
+    let mut dispatcher = Dispatcher::<Setting>::default();
+    let handle = dispatcher.add_mapped_listener(setting(0), &audio, |setting| {
+        if setting.value % 2 == 0 {
+            Some(AudioEvent::Play(setting.value))
+        } else {
+            None
+        }
+    });
+
+    dispatcher.dispatch_event(&setting(1));
+    assert!(audio.read().played.is_empty());
+
+    for value in 2..7 {
+        dispatcher.dispatch_event(&setting(value));
+    }
+    assert_eq!(audio.read().played, [2, 4]);
+    assert!(!dispatcher.has_listener(handle));
+}
+
+/// **Intended test-behaviour**: A cancelled event reports its cancellation
+/// and, if it stops on cancel, reaches no further listener.
+///
+/// **Test**: The second of three closures cancels the event, the third one
+/// records whether it sees the cancellation. Dispatched once stopping on
+/// cancel and once informing all listeners.
+#[test]
+fn cancelable_events_stop_or_inform_remaining_listeners() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = Dispatcher::<Cancelable<Event>>::default();
+    let key = Cancelable::new(Event::VariantA, true);
+
+    let first = Arc::clone(&record);
+    dispatcher.add_fn(
+        key.clone(),
+        Box::new(move |event| {
+            first.lock().unwrap().push(("first", event.is_cancelled()));
+
+            None
+        }),
+    );
+    dispatcher.add_fn(
+        key.clone(),
+        Box::new(|event| {
+            event.cancel();
+
+            None
+        }),
+    );
+    let last = Arc::clone(&record);
+    dispatcher.add_fn(
+        key,
+        Box::new(move |event| {
+            last.lock().unwrap().push(("last", event.is_cancelled()));
+
+            None
+        }),
+    );
+
+    assert!(dispatcher.dispatch_cancelable(&Cancelable::new(Event::VariantA, true)));
+    assert_eq!(*record.lock().unwrap(), [("first", false)]);
+
+    record.lock().unwrap().clear();
+    assert!(dispatcher.dispatch_cancelable(&Cancelable::new(Event::VariantA, false)));
+    assert_eq!(*record.lock().unwrap(), [("first", false), ("last", true)]);
+
+    assert!(!dispatcher.dispatch_cancelable(&Cancelable::new(Event::VariantB, true)));
+}
+
+/// **Intended test-behaviour**: Named listeners are listed by their name in
+/// calling-order, unnamed ones by the placeholder, removed ones not at all.
+///
+/// **Test**: Two named listeners and a closure listen to one event, the
+/// first named listener is removed afterwards.
+#[test]
+fn named_listeners_are_listed_in_calling_order() {
+    let audio = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let hud = Arc::new(RwLock::new(EnumListener::SomeVariant(false)));
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+
+    dispatcher.add_fn(Event::VariantA, Box::new(|_| None));
+    let audio_handle = dispatcher.add_named_listener(Event::VariantA, "audio", &audio);
+    dispatcher.add_named_listener(Event::VariantA, String::from("hud"), &hud);
+
+    assert_eq!(
+        dispatcher.listener_names(&Event::VariantA),
+        ["audio", "hud", UNNAMED_LISTENER]
+    );
+    assert_eq!(dispatcher.listener_name(audio_handle), Some("audio"));
+    assert!(dispatcher.listener_names(&Event::VariantB).is_empty());
+
+    assert!(dispatcher.remove_listener(audio_handle));
+    assert_eq!(dispatcher.listener_name(audio_handle), None);
+    assert_eq!(
+        dispatcher.listener_names(&Event::VariantA),
+        ["hud", UNNAMED_LISTENER]
+    );
+}
+
+/// **Intended test-behaviour**: Restoring a snapshot carries the names over
+/// to the renumbered handles, while resetting forgets them.
+///
+/// **Test**: A named listener is snapshotted, replaced by another named
+/// listener and restored. Its old handle no longer has a name, the new
+/// handles of the restored registrations do.
+#[test]
+fn restore_renames_renumbered_listeners() {
+    let audio = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let hud = Arc::new(RwLock::new(EnumListener::SomeVariant(false)));
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+
+    let audio_handle = dispatcher.add_named_listener(Event::VariantA, "audio", &audio);
+    let known_good = dispatcher.snapshot();
+    let hud_handle = dispatcher.add_named_listener(Event::VariantA, "hud", &hud);
+
+    dispatcher.restore(&known_good);
+    assert_eq!(dispatcher.listener_name(audio_handle), None);
+    assert_eq!(dispatcher.listener_name(hud_handle), None);
+    assert_eq!(dispatcher.listener_names(&Event::VariantA), ["audio"]);
+
+    let restored_handle = dispatcher.dispatch_event_handles(&Event::VariantA)[0];
+    assert_eq!(dispatcher.listener_name(restored_handle), Some("audio"));
+
+    dispatcher.reset();
+    assert_eq!(dispatcher.listener_name(restored_handle), None);
+    assert!(dispatcher.listener_names(&Event::VariantA).is_empty());
+}
+
+/// **Intended test-behaviour**: Channel-forwarders send every event into
+/// their channel and unregister themselves once the receiver is gone.
+///
+/// **Test**: Two events are forwarded and received, the receiver is
+/// dropped and the next dispatch removes the forwarder.
+#[test]
+fn channel_forwarder_stops_once_receiver_is_dropped() {
+    let (sender, receiver) = channel();
+    let forwarder = Arc::new(RwLock::new(ChannelForwarder::new(sender)));
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    let handle = dispatcher.add_listener(Event::VariantA, &forwarder);
+    dispatcher.add_listener(Event::VariantB, &forwarder);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert!(receiver.try_recv() == Ok(Event::VariantA));
+    assert!(receiver.try_recv() == Ok(Event::VariantB));
+
+    drop(receiver);
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert!(!dispatcher.has_listener(handle));
+    assert_eq!(dispatcher.iter_listeners(&Event::VariantB).count(), 1);
+}
+
+/// **Intended test-behaviour**: Channel-listeners sending `Arc`s hand out
+/// shared events and are removed once their receiver is gone, whether
+/// owned by the dispatcher or registered as forwarder.
+///
+/// **Test**: An owned and a registered forwarder into `Arc`-channels
+/// receive one event each, then both receivers are dropped.
+#[test]
+fn arc_channel_listeners_share_events() {
+    let (owned_sender, owned_receiver) = channel();
+    let (sender, receiver) = channel::<Arc<Event>>();
+    let forwarder = Arc::new(RwLock::new(ChannelForwarder::new(sender)));
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    let owned = dispatcher.add_arc_channel_listener(Event::VariantA, owned_sender);
+    let registered = dispatcher.add_listener(Event::VariantA, &forwarder);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert!(*owned_receiver.try_recv().unwrap() == Event::VariantA);
+    assert!(*receiver.try_recv().unwrap() == Event::VariantA);
+
+    drop(owned_receiver);
+    drop(receiver);
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert!(!dispatcher.has_listener(owned));
+    assert!(!dispatcher.has_listener(registered));
+}
+
+/// **Intended test-behaviour**: Dispatching an event nobody listens to
+/// is silently ignored.
+///
+/// **Test**: Only `VariantA` has a listener, `VariantB` is dispatched
+/// by every dispatch-method, before and after `VariantA` has been.
+#[test]
+fn unregistered_events_are_ignored() {
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    dispatcher.add_fn(Event::VariantA, Box::new(|_| None));
+
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert_eq!(
+        dispatcher.dispatch_event_with_result(&Event::VariantB),
+        DispatchResult::default()
+    );
+    assert!(dispatcher
+        .dispatch_event_handles(&Event::VariantB)
+        .is_empty());
+
+    assert_eq!(
+        dispatcher
+            .dispatch_event_with_result(&Event::VariantA)
+            .invoked,
+        1
+    );
+    assert!(dispatcher.listener_names(&Event::VariantB).is_empty());
+}
+
+/// **Intended test-behaviour**: The history keeps the last events passed
+/// to the dispatcher, including those nobody listens to.
+///
+/// **Test**: Dispatch four events into a history of two, of which only
+/// one variant has a listener, then clear the history and dispatch again.
+#[test]
+fn history_keeps_the_most_recent_events() {
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    dispatcher.add_fn(Event::VariantA, Box::new(|_| None));
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(dispatcher.history().count(), 0);
+
+    dispatcher.enable_history(2);
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    dispatcher.dispatch_event(&Event::VariantB);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert!(dispatcher.history().eq(&[Event::VariantB, Event::VariantA]));
+
+    dispatcher.clear_history();
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert!(dispatcher.history().eq(&[Event::VariantB]));
+}
+
+/// **Intended test-behaviour**: A sampled listener receives the first and
+/// then every n-th dispatch, while other listeners receive all of them.
+///
+/// **Test**: Register one listener sampled every third dispatch and one
+/// plain listener, dispatch seven times and compare their counters.
+#[test]
+fn sampled_listener_receives_every_nth_dispatch() {
+    #[derive(Default)]
+    struct Counter {
+        received: usize,
+    }
+
+    impl Listener<Event> for Counter {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            self.received += 1;
+
+            None
+        }
+    }
+
+    let sampled = Arc::new(RwLock::new(Counter::default()));
+    let plain = Arc::new(RwLock::new(Counter::default()));
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+
+    dispatcher.add_sampled_listener(Event::VariantA, 3, &sampled);
+    dispatcher.add_listener(Event::VariantA, &plain);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(sampled.read().received, 1);
+
+    for _ in 0..6 {
+        dispatcher.dispatch_event(&Event::VariantA);
+    }
+
+    assert_eq!(sampled.read().received, 3);
+    assert_eq!(plain.read().received, 7);
+}
+
+/// **Intended test-behaviour**: Middleware runs in installation-order,
+/// each one seeing the event replaced by the previous one, and dropped
+/// events reach neither later middleware nor any listener.
+///
+/// **Test**: The first middleware replaces `VariantA` with `VariantB`,
+/// the second records what it sees, the third drops `VariantB`.
+#[test]
+fn middleware_replaces_and_drops_in_order() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&seen);
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+
+    dispatcher.add_fn(Event::VariantA, Box::new(|_| panic!("event was replaced")));
+    dispatcher.add_fn(Event::VariantB, Box::new(|_| None));
+
+    dispatcher.add_middleware(|event| match event {
+        Event::VariantA => MiddlewareDecision::ReplaceWith(Event::VariantB),
+        Event::VariantB => MiddlewareDecision::Continue,
+    });
+    dispatcher.add_middleware(move |event| {
+        recorded.lock().unwrap().push(event.clone());
+
+        MiddlewareDecision::Continue
+    });
+
+    assert_eq!(
+        dispatcher
+            .dispatch_event_with_result(&Event::VariantA)
+            .invoked,
+        1
+    );
+    assert!(*seen.lock().unwrap() == [Event::VariantB]);
+
+    dispatcher.add_middleware(|_| MiddlewareDecision::Drop);
+    dispatcher.add_middleware(|_| panic!("event was dropped"));
+
+    assert_eq!(
+        dispatcher
+            .dispatch_event_with_result(&Event::VariantB)
+            .invoked,
+        0
+    );
+
+    dispatcher.clear_middleware();
+
+    assert_eq!(
+        dispatcher
+            .dispatch_event_with_result(&Event::VariantB)
+            .invoked,
+        1
+    );
+}
+
+/// **Intended test-behaviour**: Resetting removes all listeners and
+/// pending events but keeps settings and senders working.
+///
+/// **Test**: Register a listener, a sticky event, a debounce, a history
+/// and middleware, send an event, reset and dispatch again.
+#[test]
+fn reset_empties_the_dispatcher_but_keeps_settings() {
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+    let sender = dispatcher.sender();
+
+    dispatcher.enable_history(4);
+    dispatcher.add_middleware(|event| match event {
+        Event::VariantA => MiddlewareDecision::ReplaceWith(Event::VariantB),
+        Event::VariantB => MiddlewareDecision::Continue,
+    });
+    dispatcher.add_fn(Event::VariantB, Box::new(|_| None));
+    dispatcher.dispatch_sticky(Event::VariantB);
+    dispatcher.set_debounce(Event::VariantA, Duration::from_secs(60));
+    sender.send(Event::VariantB).unwrap();
+
+    dispatcher.reset();
+
+    assert_eq!(dispatcher.history().count(), 0);
+    assert_eq!(dispatcher.poll(), 0);
+    assert_eq!(
+        dispatcher
+            .dispatch_event_with_result(&Event::VariantB)
+            .invoked,
+        0
+    );
+
+    dispatcher.add_fn(Event::VariantB, Box::new(|_| None));
+    sender.send(Event::VariantA).unwrap();
+
+    assert_eq!(dispatcher.poll(), 1);
+    assert_eq!(
+        dispatcher
+            .dispatch_event_with_result(&Event::VariantA)
+            .invoked,
+        1
+    );
+    assert_eq!(dispatcher.history().count(), 3);
+}
+
+/// **Intended test-behaviour**: A vetoed event reaches no listener and
+/// its reason is returned to the caller.
+///
+/// **Test**: A validator vetoes `VariantA`. Dispatch both variants
+/// validated and `VariantA` unvalidated.
+#[test]
+fn validator_vetoes_before_listeners() {
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+
+    dispatcher.add_fn(Event::VariantA, Box::new(|_| panic!("event was vetoed")));
+    dispatcher.add_fn(Event::VariantB, Box::new(|_| None));
+    dispatcher.set_validator(|event| match event {
+        Event::VariantA => Err(VetoReason("not permitted".to_string())),
+        Event::VariantB => Ok(()),
+    });
+
+    assert_eq!(
+        dispatcher.dispatch_event_validated(&Event::VariantA),
+        Err(VetoReason("not permitted".to_string()))
+    );
+    assert_eq!(
+        dispatcher
+            .dispatch_event_validated(&Event::VariantB)
+            .map(|result| result.invoked),
+        Ok(1)
+    );
+    assert_eq!(
+        dispatcher
+            .dispatch_event_with_result(&Event::VariantA)
+            .invoked,
+        0
+    );
+}
+
+/// **Intended test-behaviour**: A listener stopping propagation skips
+/// the closures of the event as well and the dispatch reports it stopped.
+///
+/// **Test**: Register a listener stopping propagation and a closure that
+/// must not be called for one event, then dispatch it.
+#[test]
+fn listener_stopping_propagation_skips_closures() {
+    struct Stopper;
+
+    impl Listener<Event> for Stopper {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            Some(SyncDispatcherRequest::StopPropagation)
+        }
+    }
+
+    let stopper = Arc::new(RwLock::new(Stopper));
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+
+    dispatcher.add_listener(Event::VariantA, &stopper);
+    dispatcher.add_fn(Event::VariantA, Box::new(|_| panic!("propagation stopped")));
+
+    assert_eq!(
+        dispatcher.dispatch_event_with_result(&Event::VariantA),
+        DispatchResult {
+            invoked: 1,
+            stopped: true,
+        }
+    );
+}
+
+/// **Intended test-behaviour**: Listeners stopping to listen are removed
+/// without changing the order of the remaining listeners.
+///
+/// **Test**: Four closures record their number, the first one stops
+/// listening. The next dispatch calls the others in registration-order.
+#[test]
+fn stop_listening_keeps_registration_order() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+
+    for number in 0..4 {
+        let record = Arc::clone(&record);
+        dispatcher.add_fn(
+            Event::VariantA,
+            Box::new(move |_| {
+                record.lock().unwrap().push(number);
+
+                if number == 0 {
+                    Some(SyncDispatcherRequest::StopListening)
+                } else {
+                    None
+                }
+            }),
+        );
+    }
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    record.lock().unwrap().clear();
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert_eq!(*record.lock().unwrap(), [1, 2, 3]);
+}
+
+/// **Intended test-behaviour**: The sync dispatcher calls `on_subscribe`
+/// on every registration and `on_unsubscribe` on every removal path.
+///
+/// **Test**: A widget registers for both variants and via `extend`, one
+/// registration is removed explicitly, one stops listening on `VariantB`
+/// and the last one is dropped by `reset`.
+#[test]
+fn lifecycle_hooks_fire_on_every_registration_and_removal() {
+    #[derive(Default)]
+    struct Widget {
+        subscriptions: usize,
+        unsubscriptions: usize,
+    }
+
+    impl Listener<Event> for Widget {
+        fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> {
+            match event {
+                Event::VariantA => None,
+                Event::VariantB => Some(SyncDispatcherRequest::StopListening),
+            }
+        }
+
+        fn on_subscribe(&mut self) {
+            self.subscriptions += 1;
+        }
+
+        fn on_unsubscribe(&mut self) {
+            self.unsubscriptions += 1;
+        }
+    }
+
+    let widget = Arc::new(RwLock::new(Widget::default()));
+    let mut dispatcher: Dispatcher<Event> = Dispatcher::default();
+
+    let removed_explicitly = dispatcher.add_listener(Event::VariantA, &widget);
+    dispatcher.add_listener(Event::VariantB, &widget);
+    dispatcher.extend(vec![(
+        Event::VariantA,
+        Arc::clone(&widget) as Arc<RwLock<dyn Listener<Event> + Send + Sync>>,
+    )]);
+    assert_eq!(widget.read().subscriptions, 3);
+
+    assert!(dispatcher.remove_listener(removed_explicitly));
+    assert_eq!(widget.read().unsubscriptions, 1);
+
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert_eq!(widget.read().unsubscriptions, 2);
+
+    dispatcher.reset();
+    assert_eq!(widget.read().unsubscriptions, 3);
+    assert_eq!(widget.read().subscriptions, 3);
+}
@@ -0,0 +1,139 @@
+use hey_listen::sync::{SyncDispatcherRequest, TopicDispatcher, TopicError};
+use std::sync::{Arc, Mutex};
+
+type Record = Arc<Mutex<Vec<String>>>;
+
+/// Subscribes to `pattern`, recording it along with every dispatched topic.
+fn subscribe(
+    dispatcher: &mut TopicDispatcher<()>,
+    record: &Record,
+    pattern: &str,
+    request: Option<fn() -> SyncDispatcherRequest>,
+) {
+    let record = Arc::clone(record);
+    let name = pattern.to_string();
+
+    dispatcher
+        .add_fn(
+            pattern,
+            Box::new(move |topic, _| {
+                record
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} <- {}", name, topic));
+
+                request.map(|request| request())
+            }),
+        )
+        .unwrap();
+}
+
+fn take(record: &Record) -> Vec<String> {
+    record.lock().unwrap().drain(..).collect()
+}
+
+/// **Intended test-behaviour**: Every subscription whose pattern matches
+/// the topic is called once, in registration-order across all patterns.
+///
+/// **Test**: Overlapping exact, single- and multi-segment patterns are
+/// subscribed, topics matching several, one and none of them are
+/// dispatched.
+#[test]
+fn overlapping_wildcards_match_in_registration_order() {
+    let record = Record::default();
+    let mut dispatcher = TopicDispatcher::default();
+
+    subscribe(&mut dispatcher, &record, "entity/#", None);
+    subscribe(&mut dispatcher, &record, "entity/42/damaged", None);
+    subscribe(&mut dispatcher, &record, "entity/*/damaged", None);
+    subscribe(&mut dispatcher, &record, "entity/+/+", None);
+    subscribe(&mut dispatcher, &record, "#", None);
+
+    assert_eq!(
+        dispatcher.dispatch_event("entity/42/damaged", &()).invoked,
+        5
+    );
+    assert_eq!(
+        take(&record),
+        [
+            "entity/# <- entity/42/damaged",
+            "entity/42/damaged <- entity/42/damaged",
+            "entity/*/damaged <- entity/42/damaged",
+            "entity/+/+ <- entity/42/damaged",
+            "# <- entity/42/damaged",
+        ]
+    );
+
+    dispatcher.dispatch_event("entity/7/healed", &());
+    assert_eq!(
+        take(&record),
+        [
+            "entity/# <- entity/7/healed",
+            "entity/+/+ <- entity/7/healed",
+            "# <- entity/7/healed",
+        ]
+    );
+
+    dispatcher.dispatch_event("entity", &());
+    assert_eq!(take(&record), ["entity/# <- entity", "# <- entity"]);
+
+    dispatcher.dispatch_event("ui/button", &());
+    assert_eq!(take(&record), ["# <- ui/button"]);
+}
+
+/// **Intended test-behaviour**: Topics not matching a pattern do not reach
+/// its subscription.
+///
+/// **Test**: Single-segment wildcards require exactly one segment, exact
+/// segments require equality.
+#[test]
+fn non_matching_topics_are_not_delivered() {
+    let record = Record::default();
+    let mut dispatcher = TopicDispatcher::default();
+
+    subscribe(&mut dispatcher, &record, "entity/*/damaged", None);
+    subscribe(&mut dispatcher, &record, "ui/#", None);
+
+    for topic in &[
+        "entity/damaged",
+        "entity/1/2/damaged",
+        "entity/1/healed",
+        "uix/button",
+        "",
+    ] {
+        assert_eq!(dispatcher.dispatch_event(topic, &()).invoked, 0);
+    }
+
+    assert!(take(&record).is_empty());
+}
+
+#[test]
+fn requests_and_invalid_patterns() {
+    let record = Record::default();
+    let mut dispatcher = TopicDispatcher::default();
+
+    assert_eq!(
+        dispatcher.add_fn("ui/#/clicked", Box::new(|_, _| None)),
+        Err(TopicError::InvalidPattern("ui/#/clicked".to_string()))
+    );
+
+    subscribe(
+        &mut dispatcher,
+        &record,
+        "ui/*",
+        Some(|| SyncDispatcherRequest::StopListeningAndPropagation),
+    );
+    subscribe(&mut dispatcher, &record, "ui/#", None);
+
+    assert!(dispatcher.dispatch_event("ui/button", &()).stopped);
+    assert_eq!(take(&record), ["ui/* <- ui/button"]);
+
+    assert!(!dispatcher.dispatch_event("ui/button", &()).stopped);
+    assert_eq!(take(&record), ["ui/# <- ui/button"]);
+
+    let handle = dispatcher
+        .add_fn("ui/button", Box::new(|_, _| None))
+        .unwrap();
+    assert!(dispatcher.remove_listener(handle));
+    assert!(!dispatcher.remove_listener(handle));
+}
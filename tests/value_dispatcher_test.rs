@@ -0,0 +1,99 @@
+use hey_listen::{
+    sync::{ValueDispatcher, ValueListener},
+    RwLock,
+};
+use std::sync::Arc;
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum Event {
+    Hit,
+    Miss,
+}
+
+struct DamageListener {
+    damage: u32,
+}
+
+impl ValueListener<Event, u32> for DamageListener {
+    fn on_event(&mut self, _event: &Event) -> u32 {
+        self.damage
+    }
+}
+
+#[test]
+fn fold_sums_all_listeners() {
+    let sword = Arc::new(RwLock::new(DamageListener { damage: 5 }));
+    let dropped_axe = Arc::new(RwLock::new(DamageListener { damage: 100 }));
+    let mut dispatcher = ValueDispatcher::<Event, u32>::default();
+
+    dispatcher.add_listener(Event::Hit, &sword);
+    dispatcher.add_listener(Event::Hit, &dropped_axe);
+    let bonus = dispatcher.add_fn(Event::Hit, Box::new(|_| 3));
+    drop(dropped_axe);
+
+    assert_eq!(
+        dispatcher.dispatch_fold(&Event::Hit, 0, |sum, damage| sum + damage),
+        8
+    );
+    assert_eq!(
+        dispatcher.dispatch_fold(&Event::Miss, 1, |sum, damage| sum + damage),
+        1
+    );
+
+    assert!(dispatcher.remove_listener(bonus));
+    assert_eq!(
+        dispatcher.dispatch_fold(&Event::Hit, Vec::new(), |mut damages, damage| {
+            damages.push(*damage);
+            damages
+        }),
+        [5]
+    );
+}
+
+struct Settings {
+    width: Option<u32>,
+    asked: usize,
+}
+
+impl ValueListener<Event, Option<u32>> for Settings {
+    fn on_event(&mut self, _event: &Event) -> Option<u32> {
+        self.asked += 1;
+
+        self.width
+    }
+}
+
+/// **Intended test-behaviour**: A query is answered by the earliest
+/// registration returning a value, later ones are not asked.
+///
+/// **Test**: A silent listener is asked before an answering one, the
+/// closure after them is never reached. Nobody answers an unknown query.
+#[test]
+fn query_returns_first_answer() {
+    let silent = Arc::new(RwLock::new(Settings {
+        width: None,
+        asked: 0,
+    }));
+    let answering = Arc::new(RwLock::new(Settings {
+        width: Some(1920),
+        asked: 0,
+    }));
+    let mut dispatcher = ValueDispatcher::<Event, Option<u32>>::default();
+
+    dispatcher.add_listener(Event::Hit, &silent);
+    dispatcher.add_listener(Event::Hit, &answering);
+    dispatcher.add_fn(Event::Hit, Box::new(|_| panic!("already answered")));
+    dispatcher.add_fn(Event::Miss, Box::new(|_| None));
+
+    assert_eq!(dispatcher.dispatch_query(&Event::Hit), Some(1920));
+    assert_eq!(silent.read().asked, 1);
+    assert_eq!(answering.read().asked, 1);
+
+    assert_eq!(dispatcher.dispatch_query(&Event::Miss), None);
+}
+
+#[test]
+fn is_send_and_sync() {
+    fn assert_send<T: Send + Sync>(_: &T) {}
+    assert_send(&ValueDispatcher::<Event, u32>::default());
+}